@@ -1,11 +1,14 @@
-use crate::configs::StarshipRootConfig;
+use crate::configs::{symbol_presets, StarshipRootConfig};
 use crate::utils;
 use ansi_term::{Color, Style};
+use serde::Serialize;
 
 use std::clone::Clone;
 use std::collections::HashMap;
 use std::marker::Sized;
 
+use once_cell::sync::Lazy;
+use regex::{Captures, Regex};
 use std::env;
 use toml::Value;
 
@@ -48,6 +51,11 @@ where
     fn load_config(&self, config: &'a Value) -> Self {
         Self::from_config(config).unwrap_or_else(|| self.clone())
     }
+
+    /// Return the keys of a toml table that don't correspond to any field of the config.
+    fn unknown_keys(_config: &Value) -> Vec<String> {
+        Vec::new()
+    }
 }
 
 // TODO: Add logging to default implementations
@@ -154,7 +162,7 @@ where
 
 /// A wrapper around `Vec<T>` that implements `ModuleConfig`, and either
 /// accepts a value of type `T` or a list of values of type `T`.
-#[derive(Clone, Default)]
+#[derive(Clone, Default, Serialize)]
 pub struct VecOr<T>(pub Vec<T>);
 
 impl<'a, T> ModuleConfig<'a> for VecOr<T>
@@ -182,22 +190,66 @@ pub struct StarshipConfig {
 }
 
 impl StarshipConfig {
-    /// Initialize the Config struct
-    pub fn initialize() -> Self {
-        if let Some(file_data) = Self::config_from_file() {
-            StarshipConfig {
-                config: Some(file_data),
+    /// Initialize the Config struct, reading it from `config_path` if one is given instead of
+    /// `$STARSHIP_CONFIG` or the default location
+    pub fn initialize(config_path: Option<&str>) -> Self {
+        let config = Self::config_from_file(config_path)
+            .unwrap_or_else(|| Value::Table(toml::value::Table::new()));
+        Self::from_value(config)
+    }
+
+    /// Create the Config struct from a parsed configuration, expanding the environment
+    /// variables in its strings and resolving its `symbol_preset`
+    pub fn from_value(mut config: Value) -> Self {
+        let keep_unset = config
+            .get("keep_unset_env_vars")
+            .and_then(Value::as_bool)
+            .unwrap_or(true);
+        interpolate_env_vars(&mut config, keep_unset, &|name| env::var(name).ok());
+        Self::apply_symbol_preset(&mut config);
+        StarshipConfig {
+            config: Some(config),
+        }
+    }
+
+    /// Set the symbols of the configured `symbol_preset` for the modules that don't have a
+    /// `symbol` of their own
+    fn apply_symbol_preset(config: &mut Value) {
+        let preset_name = match config.get("symbol_preset").and_then(Value::as_str) {
+            Some(preset_name) if !preset_name.is_empty() => preset_name.to_owned(),
+            _ => return,
+        };
+        let preset = match symbol_presets::get_symbol_preset(&preset_name) {
+            Some(preset) => preset,
+            None => {
+                log::warn!("Unknown symbol_preset \"{}\"", preset_name);
+                return;
             }
-        } else {
-            StarshipConfig {
-                config: Some(Value::Table(toml::value::Table::new())),
+        };
+
+        let table = match config.as_table_mut() {
+            Some(table) => table,
+            None => return,
+        };
+        for (module, symbol) in preset {
+            let module_config = table
+                .entry(module.to_string())
+                .or_insert_with(|| Value::Table(toml::value::Table::new()));
+            if let Some(module_config) = module_config.as_table_mut() {
+                if !module_config.contains_key("symbol") {
+                    module_config.insert("symbol".to_owned(), Value::String(symbol.to_string()));
+                }
             }
         }
     }
 
     /// Create a config from a starship configuration file
-    fn config_from_file() -> Option<Value> {
-        let file_path = if let Ok(path) = env::var("STARSHIP_CONFIG") {
+    fn config_from_file(config_path: Option<&str>) -> Option<Value> {
+        let file_path = if let Some(path) = config_path {
+            // Use the path passed with `--config` over any other location
+            log::debug!("Config path passed as an argument: \n{}", path);
+            path.to_owned()
+        } else if let Ok(path) = env::var("STARSHIP_CONFIG") {
             // Use $STARSHIP_CONFIG as the config path if available
             log::debug!("STARSHIP_CONFIG is set: \n{}", &path);
             path
@@ -315,6 +367,22 @@ impl StarshipConfig {
         self.get_config(&["custom"])?.as_table()
     }
 
+    /// Get the subset of the table for a named env_var module (`[env_var.NAME]`)
+    pub fn get_env_var_module_config(&self, module_name: &str) -> Option<&Value> {
+        self.get_config(&["env_var", module_name])
+    }
+
+    /// Get the named env_var modules, i.e. the subtables of the `env_var` table, if any
+    pub fn get_env_var_modules(&self) -> Option<Vec<(&String, &Value)>> {
+        let env_var_modules = self
+            .get_config(&["env_var"])?
+            .as_table()?
+            .iter()
+            .filter(|(_, config)| config.is_table())
+            .collect();
+        Some(env_var_modules)
+    }
+
     pub fn get_root_config(&self) -> StarshipRootConfig {
         if let Some(root_config) = &self.config {
             StarshipRootConfig::load(root_config)
@@ -324,6 +392,40 @@ impl StarshipConfig {
     }
 }
 
+/// Replace the `${env:VAR}` tokens in all the strings of a config with the value of the
+/// environment variable `VAR`, as returned by `lookup`.
+///
+/// Tokens of unset variables are left untouched if `keep_unset` is true, and removed otherwise.
+/// The `env:` prefix keeps these tokens apart from the `${name}` variables of format strings,
+/// such as `${symbol}` or the `${raw}` of `version_format`, which are never touched.
+fn interpolate_env_vars(
+    config: &mut Value,
+    keep_unset: bool,
+    lookup: &dyn Fn(&str) -> Option<String>,
+) {
+    match config {
+        Value::String(string) if string.contains("${") => {
+            *string = ENV_VAR_TOKEN
+                .replace_all(string, |caps: &Captures| match lookup(&caps[1]) {
+                    Some(value) => value,
+                    None if keep_unset => caps[0].to_owned(),
+                    None => String::new(),
+                })
+                .into_owned();
+        }
+        Value::Array(array) => array
+            .iter_mut()
+            .for_each(|value| interpolate_env_vars(value, keep_unset, lookup)),
+        Value::Table(table) => table
+            .iter_mut()
+            .for_each(|(_, value)| interpolate_env_vars(value, keep_unset, lookup)),
+        _ => {}
+    }
+}
+
+static ENV_VAR_TOKEN: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\$\{env:([A-Za-z_][A-Za-z0-9_]*)\}").unwrap());
+
 /** Parse a style string which represents an ansi style. Valid tokens in the style
  string include the following:
  - 'fg:<color>'    (specifies that the color read should be a foreground color)
@@ -355,6 +457,10 @@ pub fn parse_style_string(style_string: &str) -> Option<ansi_term::Style> {
                     "bold" => Some(style.bold()),
                     "italic" => Some(style.italic()),
                     "dimmed" => Some(style.dimmed()),
+                    "blink" => Some(style.blink()),
+                    "inverted" => Some(style.reverse()),
+                    "hidden" => Some(style.hidden()),
+                    "strikethrough" => Some(style.strikethrough()),
                     "none" => None,
 
                     // Try to see if this token parses as a valid color string
@@ -372,25 +478,31 @@ pub fn parse_style_string(style_string: &str) -> Option<ansi_term::Style> {
 
 /** Parse a string that represents a color setting, returning None if this fails
  There are three valid color formats:
-  - #RRGGBB      (a hash followed by an RGB hex)
+  - #RRGGBB      (a hash followed by an RGB hex, or #RGB for short)
   - u8           (a number from 0-255, representing an ANSI color)
   - colstring    (one of the 16 predefined color strings)
 */
 fn parse_color_string(color_string: &str) -> Option<ansi_term::Color> {
     // Parse RGB hex values
     log::trace!("Parsing color_string: {}", color_string);
-    if color_string.starts_with('#') {
+    if let Some(hex) = color_string.strip_prefix('#') {
         log::trace!(
             "Attempting to read hexadecimal color string: {}",
             color_string
         );
-        if color_string.len() != 7 {
+        if !hex.is_ascii() || (hex.len() != 6 && hex.len() != 3) {
             log::debug!("Could not parse hexadecimal string: {}", color_string);
             return None;
         }
-        let r: u8 = u8::from_str_radix(&color_string[1..3], 16).ok()?;
-        let g: u8 = u8::from_str_radix(&color_string[3..5], 16).ok()?;
-        let b: u8 = u8::from_str_radix(&color_string[5..7], 16).ok()?;
+        // Each digit of the short form is doubled, i.e. "#f80" is "#ff8800"
+        let width = hex.len() / 3;
+        let channel = |n: usize| -> Option<u8> {
+            let value = u8::from_str_radix(&hex[n * width..(n + 1) * width], 16).ok()?;
+            Some(if width == 1 { value * 0x11 } else { value })
+        };
+        let r: u8 = channel(0)?;
+        let g: u8 = channel(1)?;
+        let b: u8 = channel(2)?;
         log::trace!("Read RGB color string: {},{},{}", r, g, b);
         return Some(Color::RGB(r, g, b));
     }
@@ -409,7 +521,7 @@ fn parse_color_string(color_string: &str) -> Option<ansi_term::Color> {
         "green" => Some(Color::Green),
         "yellow" => Some(Color::Yellow),
         "blue" => Some(Color::Blue),
-        "purple" => Some(Color::Purple),
+        "purple" | "magenta" => Some(Color::Purple),
         "cyan" => Some(Color::Cyan),
         "white" => Some(Color::White),
         "bright-black" => Some(Color::Fixed(8)), // "bright-black" is dark grey
@@ -417,7 +529,7 @@ fn parse_color_string(color_string: &str) -> Option<ansi_term::Color> {
         "bright-green" => Some(Color::Fixed(10)),
         "bright-yellow" => Some(Color::Fixed(11)),
         "bright-blue" => Some(Color::Fixed(12)),
-        "bright-purple" => Some(Color::Fixed(13)),
+        "bright-purple" | "bright-magenta" => Some(Color::Fixed(13)),
         "bright-cyan" => Some(Color::Fixed(14)),
         "bright-white" => Some(Color::Fixed(15)),
         _ => None,
@@ -436,6 +548,49 @@ mod tests {
     use super::*;
     use starship_module_config_derive::ModuleConfig;
 
+    #[test]
+    fn test_interpolate_env_vars() {
+        let lookup = |name: &str| match name {
+            "MY_SYMBOL" => Some("🚀".to_owned()),
+            "symbol" => Some("clash".to_owned()),
+            _ => None,
+        };
+        let mut config = toml::toml! {
+            format = "${custom.foo}$all"
+            [rust]
+            format = "${symbol}"
+            version_format = "v${major}.${minor}"
+            symbol = "${env:MY_SYMBOL} "
+            style = "${env:UNSET_VAR}bold red"
+            some_array = ["${env:MY_SYMBOL}", "${env:UNSET_VAR}"]
+        };
+
+        let mut kept = config.clone();
+        interpolate_env_vars(&mut kept, true, &lookup);
+        let expected = toml::toml! {
+            format = "${custom.foo}$all"
+            [rust]
+            format = "${symbol}"
+            version_format = "v${major}.${minor}"
+            symbol = "🚀 "
+            style = "${env:UNSET_VAR}bold red"
+            some_array = ["🚀", "${env:UNSET_VAR}"]
+        };
+        assert_eq!(kept, expected);
+
+        interpolate_env_vars(&mut config, false, &lookup);
+        let expected = toml::toml! {
+            format = "${custom.foo}$all"
+            [rust]
+            format = "${symbol}"
+            version_format = "v${major}.${minor}"
+            symbol = "🚀 "
+            style = "bold red"
+            some_array = ["🚀", ""]
+        };
+        assert_eq!(config, expected);
+    }
+
     #[test]
     fn test_load_config() {
         #[derive(Clone, ModuleConfig)]
@@ -462,6 +617,23 @@ mod tests {
         assert_eq!(rust_config.some_array, vec!["A"]);
     }
 
+    #[test]
+    fn test_unknown_keys() {
+        #[derive(Clone, ModuleConfig)]
+        struct TestConfig<'a> {
+            pub symbol: &'a str,
+            pub disabled: bool,
+        }
+
+        let config = toml::toml! {
+            symbl = "T "
+            disabled = true
+        };
+
+        assert_eq!(TestConfig::unknown_keys(&config), vec!["symbl"]);
+        assert!(TestConfig::unknown_keys(&toml::toml! { symbol = "T " }).is_empty());
+    }
+
     #[test]
     fn test_load_nested_config() {
         #[derive(Clone, ModuleConfig)]
@@ -589,6 +761,18 @@ mod tests {
         assert_eq!(<i64>::from_config(&config).unwrap(), 42);
     }
 
+    #[test]
+    fn test_from_usize() {
+        let config = Value::Integer(42);
+        assert_eq!(<usize>::from_config(&config).unwrap(), 42);
+
+        let config = Value::Integer(0);
+        assert_eq!(<usize>::from_config(&config), None);
+
+        let config = Value::Integer(-1);
+        assert_eq!(<usize>::from_config(&config), None);
+    }
+
     #[test]
     fn test_from_style() {
         let config = Value::from("red bold");
@@ -611,6 +795,15 @@ mod tests {
             <Style>::from_config(&config).unwrap(),
             Color::RGB(0xA1, 0x2B, 0xCD).into()
         );
+
+        let config = Value::from("#f80");
+        assert_eq!(
+            <Style>::from_config(&config).unwrap(),
+            Color::RGB(0xFF, 0x88, 0x00).into()
+        );
+
+        let config = Value::from("#f8");
+        assert_eq!(<Style>::from_config(&config), None);
     }
 
     #[test]
@@ -664,6 +857,34 @@ mod tests {
         assert!(<Style>::from_config(&config).is_none());
     }
 
+    #[test]
+    fn table_get_styles_combined() {
+        let config = Value::from("bold fg:#ff8800 bg:blue underline");
+        let combined_style = <Style>::from_config(&config).unwrap();
+        assert_eq!(
+            combined_style,
+            Style::new()
+                .bold()
+                .underline()
+                .fg(Color::RGB(0xFF, 0x88, 0x00))
+                .on(Color::Blue)
+        );
+
+        let config =
+            Value::from("blink inverted hidden strikethrough fg:magenta bg:bright-magenta");
+        let modifier_style = <Style>::from_config(&config).unwrap();
+        assert_eq!(
+            modifier_style,
+            Style::new()
+                .blink()
+                .reverse()
+                .hidden()
+                .strikethrough()
+                .fg(Color::Purple)
+                .on(Color::Fixed(13))
+        );
+    }
+
     #[test]
     fn table_get_styles_ordered() {
         // Test a background style with inverted order (also test hex + ANSI)
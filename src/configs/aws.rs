@@ -1,14 +1,16 @@
 use crate::config::{ModuleConfig, RootModuleConfig};
+use serde::Serialize;
 use starship_module_config_derive::ModuleConfig;
 use std::collections::HashMap;
 
-#[derive(Clone, ModuleConfig)]
+#[derive(Clone, ModuleConfig, Serialize)]
 pub struct AwsConfig<'a> {
     pub format: &'a str,
     pub symbol: &'a str,
     pub style: &'a str,
     pub disabled: bool,
     pub region_aliases: HashMap<String, &'a str>,
+    pub truncation_length: Option<usize>,
 }
 
 impl<'a> RootModuleConfig<'a> for AwsConfig<'a> {
@@ -19,6 +21,7 @@ impl<'a> RootModuleConfig<'a> for AwsConfig<'a> {
             style: "bold yellow",
             disabled: false,
             region_aliases: HashMap::new(),
+            truncation_length: None,
         }
     }
 }
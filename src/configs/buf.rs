@@ -0,0 +1,23 @@
+use crate::config::{ModuleConfig, RootModuleConfig};
+
+use serde::Serialize;
+use starship_module_config_derive::ModuleConfig;
+
+#[derive(Clone, ModuleConfig, Serialize)]
+pub struct BufConfig<'a> {
+    pub format: &'a str,
+    pub symbol: &'a str,
+    pub style: &'a str,
+    pub disabled: bool,
+}
+
+impl<'a> RootModuleConfig<'a> for BufConfig<'a> {
+    fn new() -> Self {
+        BufConfig {
+            format: "via [$symbol$version]($style) ",
+            symbol: "🦬 ",
+            style: "bold blue",
+            disabled: false,
+        }
+    }
+}
@@ -1,8 +1,9 @@
 use crate::config::{ModuleConfig, RootModuleConfig};
 
+use serde::Serialize;
 use starship_module_config_derive::ModuleConfig;
 
-#[derive(Clone, ModuleConfig)]
+#[derive(Clone, ModuleConfig, Serialize)]
 pub struct CMakeConfig<'a> {
     pub format: &'a str,
     pub symbol: &'a str,
@@ -1,10 +1,11 @@
 use crate::config::{ModuleConfig, RootModuleConfig};
 
+use serde::Serialize;
 use starship_module_config_derive::ModuleConfig;
 
-#[derive(Clone, ModuleConfig)]
+#[derive(Clone, ModuleConfig, Serialize)]
 pub struct CondaConfig<'a> {
-    pub truncation_length: usize,
+    pub truncation_length: i64,
     pub format: &'a str,
     pub symbol: &'a str,
     pub style: &'a str,
@@ -1,17 +1,18 @@
 use crate::config::{ModuleConfig, RootModuleConfig, VecOr};
 
+use serde::Serialize;
 use starship_module_config_derive::ModuleConfig;
 
-#[derive(Clone, Default, PartialEq)]
+#[derive(Clone, Default, PartialEq, Serialize)]
 pub struct Files<'a>(pub Vec<&'a str>);
 
-#[derive(Clone, Default, PartialEq)]
+#[derive(Clone, Default, PartialEq, Serialize)]
 pub struct Extensions<'a>(pub Vec<&'a str>);
 
-#[derive(Clone, Default, PartialEq)]
+#[derive(Clone, Default, PartialEq, Serialize)]
 pub struct Directories<'a>(pub Vec<&'a str>);
 
-#[derive(Clone, ModuleConfig)]
+#[derive(Clone, ModuleConfig, Serialize)]
 pub struct CustomConfig<'a> {
     pub format: &'a str,
     pub symbol: &'a str,
@@ -1,9 +1,10 @@
 use crate::config::{ModuleConfig, RootModuleConfig};
 use std::collections::HashMap;
 
+use serde::Serialize;
 use starship_module_config_derive::ModuleConfig;
 
-#[derive(Clone, ModuleConfig)]
+#[derive(Clone, ModuleConfig, Serialize)]
 pub struct DirectoryConfig<'a> {
     pub truncation_length: i64,
     pub truncate_to_repo: bool,
@@ -13,8 +14,10 @@ pub struct DirectoryConfig<'a> {
     pub format: &'a str,
     pub style: &'a str,
     pub disabled: bool,
-    pub read_only_symbol: &'a str,
-    pub read_only_symbol_style: &'a str,
+    pub read_only: &'a str,
+    pub read_only_style: &'a str,
+    pub truncation_symbol: &'a str,
+    pub home_symbol: &'a str,
 }
 
 impl<'a> RootModuleConfig<'a> for DirectoryConfig<'a> {
@@ -28,8 +31,10 @@ impl<'a> RootModuleConfig<'a> for DirectoryConfig<'a> {
             format: "[$path]($style)[$read_only]($read_only_style) ",
             style: "cyan bold",
             disabled: false,
-            read_only_symbol: "🔒",
-            read_only_symbol_style: "red",
+            read_only: "🔒",
+            read_only_style: "red",
+            truncation_symbol: "",
+            home_symbol: "~",
         }
     }
 }
@@ -0,0 +1,27 @@
+use crate::config::{ModuleConfig, RootModuleConfig};
+
+use serde::Serialize;
+use starship_module_config_derive::ModuleConfig;
+
+#[derive(Clone, ModuleConfig, Serialize)]
+pub struct DirenvConfig<'a> {
+    pub format: &'a str,
+    pub symbol: &'a str,
+    pub style: &'a str,
+    pub loaded_symbol: &'a str,
+    pub blocked_symbol: &'a str,
+    pub disabled: bool,
+}
+
+impl<'a> RootModuleConfig<'a> for DirenvConfig<'a> {
+    fn new() -> Self {
+        DirenvConfig {
+            format: "[$symbol$state]($style) ",
+            symbol: "direnv ",
+            style: "bold bright-yellow",
+            loaded_symbol: "✓",
+            blocked_symbol: "✗",
+            disabled: true,
+        }
+    }
+}
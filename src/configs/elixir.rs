@@ -1,12 +1,14 @@
 use crate::config::{ModuleConfig, RootModuleConfig};
 
+use serde::Serialize;
 use starship_module_config_derive::ModuleConfig;
 
-#[derive(Clone, ModuleConfig)]
+#[derive(Clone, ModuleConfig, Serialize)]
 pub struct ElixirConfig<'a> {
     pub format: &'a str,
     pub symbol: &'a str,
     pub style: &'a str,
+    pub otp_style: &'a str,
     pub disabled: bool,
 }
 
@@ -16,6 +18,7 @@ impl<'a> RootModuleConfig<'a> for ElixirConfig<'a> {
             format: "via [$symbol$version \\(OTP $otp_version\\)]($style) ",
             symbol: "💧 ",
             style: "bold purple",
+            otp_style: "bold purple",
             disabled: false,
         }
     }
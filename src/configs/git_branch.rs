@@ -1,25 +1,32 @@
 use crate::config::{ModuleConfig, RootModuleConfig};
 
+use serde::Serialize;
 use starship_module_config_derive::ModuleConfig;
 
-#[derive(Clone, ModuleConfig)]
+#[derive(Clone, ModuleConfig, Serialize)]
 pub struct GitBranchConfig<'a> {
     pub format: &'a str,
     pub symbol: &'a str,
     pub style: &'a str,
     pub truncation_length: i64,
     pub truncation_symbol: &'a str,
+    pub upstream_gone: &'a str,
+    pub show_remote: bool,
+    pub hide_matching: bool,
     pub disabled: bool,
 }
 
 impl<'a> RootModuleConfig<'a> for GitBranchConfig<'a> {
     fn new() -> Self {
         GitBranchConfig {
-            format: "on [$symbol$branch]($style) ",
+            format: "on [$symbol$branch$upstream_gone]($style) ",
             symbol: " ",
             style: "bold purple",
             truncation_length: std::i64::MAX,
             truncation_symbol: "…",
+            upstream_gone: " (gone)",
+            show_remote: false,
+            hide_matching: true,
             disabled: false,
         }
     }
@@ -0,0 +1,25 @@
+use crate::config::{ModuleConfig, RootModuleConfig};
+
+use serde::Serialize;
+use starship_module_config_derive::ModuleConfig;
+
+#[derive(Clone, ModuleConfig, Serialize)]
+pub struct GitMetricsConfig<'a> {
+    pub added_style: &'a str,
+    pub deleted_style: &'a str,
+    pub format: &'a str,
+    pub max_files: i64,
+    pub disabled: bool,
+}
+
+impl<'a> RootModuleConfig<'a> for GitMetricsConfig<'a> {
+    fn new() -> Self {
+        GitMetricsConfig {
+            added_style: "bold green",
+            deleted_style: "bold red",
+            format: "([+$added]($added_style) )([-$deleted]($deleted_style) )",
+            max_files: 10_000,
+            disabled: true,
+        }
+    }
+}
@@ -1,8 +1,9 @@
 use crate::config::{ModuleConfig, RootModuleConfig};
 
+use serde::Serialize;
 use starship_module_config_derive::ModuleConfig;
 
-#[derive(Clone, ModuleConfig)]
+#[derive(Clone, ModuleConfig, Serialize)]
 pub struct GitStatusConfig<'a> {
     pub format: &'a str,
     pub style: &'a str,
@@ -13,9 +14,12 @@ pub struct GitStatusConfig<'a> {
     pub conflicted: &'a str,
     pub deleted: &'a str,
     pub renamed: &'a str,
+    pub copied: &'a str,
     pub modified: &'a str,
     pub staged: &'a str,
     pub untracked: &'a str,
+    pub max_files: usize,
+    pub ignore_submodules: bool,
     pub disabled: bool,
 }
 
@@ -31,9 +35,12 @@ impl<'a> RootModuleConfig<'a> for GitStatusConfig<'a> {
             conflicted: "=",
             deleted: "✘",
             renamed: "»",
+            copied: "⧉",
             modified: "!",
             staged: "+",
             untracked: "?",
+            max_files: 0,
+            ignore_submodules: false,
             disabled: false,
         }
     }
@@ -1,12 +1,14 @@
 use crate::config::{ModuleConfig, RootModuleConfig};
 
+use serde::Serialize;
 use starship_module_config_derive::ModuleConfig;
 
-#[derive(Clone, ModuleConfig)]
+#[derive(Clone, ModuleConfig, Serialize)]
 pub struct GoConfig<'a> {
     pub format: &'a str,
     pub symbol: &'a str,
     pub style: &'a str,
+    pub version_from_gomod: bool,
     pub disabled: bool,
 }
 
@@ -16,6 +18,7 @@ impl<'a> RootModuleConfig<'a> for GoConfig<'a> {
             format: "via [$symbol$version]($style) ",
             symbol: "🐹 ",
             style: "bold cyan",
+            version_from_gomod: false,
             disabled: false,
         }
     }
@@ -0,0 +1,15 @@
+use crate::config::{ModuleConfig, RootModuleConfig};
+
+use serde::Serialize;
+use starship_module_config_derive::ModuleConfig;
+
+#[derive(Clone, ModuleConfig, Serialize)]
+pub struct LineBreakConfig {
+    pub disabled: bool,
+}
+
+impl<'a> RootModuleConfig<'a> for LineBreakConfig {
+    fn new() -> Self {
+        LineBreakConfig { disabled: false }
+    }
+}
@@ -1,5 +1,10 @@
+use crate::config::{ModuleConfig, RootModuleConfig};
+use crate::module::ALL_MODULES;
+use toml::Value;
+
 pub mod aws;
 pub mod battery;
+pub mod buf;
 pub mod character;
 pub mod cmake;
 pub mod cmd_duration;
@@ -7,7 +12,9 @@ pub mod conda;
 pub mod crystal;
 pub mod custom;
 pub mod dart;
+pub mod deno;
 pub mod directory;
+pub mod direnv;
 pub mod docker_context;
 pub mod dotnet;
 pub mod elixir;
@@ -17,34 +24,259 @@ pub mod erlang;
 pub mod gcloud;
 pub mod git_branch;
 pub mod git_commit;
+pub mod git_metrics;
 pub mod git_state;
 pub mod git_status;
 pub mod go;
+pub mod gradle;
+pub mod haskell;
 pub mod helm;
 pub mod hg_branch;
 pub mod hostname;
 pub mod java;
 pub mod jobs;
 pub mod julia;
+pub mod kotlin;
 pub mod kubernetes;
+pub mod line_break;
+pub mod lua;
 pub mod memory_usage;
 pub mod nim;
 pub mod nix_shell;
 pub mod nodejs;
 pub mod ocaml;
+pub mod openstack;
 pub mod package;
 pub mod perl;
 pub mod php;
+pub mod pulumi;
 pub mod purescript;
 pub mod python;
+pub mod r;
 pub mod ruby;
 pub mod rust;
+pub mod scala;
+pub mod shlvl;
 pub mod singularity;
 mod starship_root;
+pub mod status;
 pub mod swift;
+pub mod symbol_presets;
 pub mod terraform;
 pub mod time;
 pub mod username;
+pub mod vagrant;
+pub mod vlang;
 pub mod zig;
 
 pub use starship_root::*;
+
+/// Keys that have been renamed, with their new names. Starship still reads them, but
+/// `starship config --check` warns about them.
+pub const RENAMED_KEYS: &[(&str, &str)] = &[
+    ("directory.read_only_symbol", "directory.read_only"),
+    (
+        "directory.read_only_symbol_style",
+        "directory.read_only_style",
+    ),
+];
+
+/// Return the renamed keys that are used in the given config, with their new names
+pub fn renamed_config_keys(config: &Value) -> Vec<(&'static str, &'static str)> {
+    RENAMED_KEYS
+        .iter()
+        .filter(|(old_key, _)| {
+            old_key
+                .split('.')
+                .try_fold(config, |value, key| value.get(key))
+                .is_some()
+        })
+        .copied()
+        .collect()
+}
+
+/// Return the dotted paths of all keys in the given config that aren't recognized by starship
+pub fn unknown_config_keys(config: &Value) -> Vec<String> {
+    let table = match config.as_table() {
+        Some(table) => table,
+        None => return Vec::new(),
+    };
+
+    let mut unknown_keys: Vec<String> = StarshipRootConfig::unknown_keys(config)
+        .into_iter()
+        .filter(|key| key != "custom" && !ALL_MODULES.contains(&key.as_str()))
+        .collect();
+
+    for (name, module_config) in table {
+        let keys = match name.as_str() {
+            // Named env_var modules live in subtables of `[env_var]`
+            "env_var" => env_var_unknown_keys(module_config),
+            "custom" => module_config
+                .as_table()
+                .into_iter()
+                .flatten()
+                .flat_map(|(custom_name, custom_config)| {
+                    custom::CustomConfig::unknown_keys(custom_config)
+                        .into_iter()
+                        .map(move |key| format!("{}.{}", custom_name, key))
+                })
+                .collect(),
+            _ => module_unknown_keys(name, module_config),
+        };
+        unknown_keys.extend(keys.into_iter().map(|key| format!("{}.{}", name, key)));
+    }
+
+    unknown_keys.retain(|key| !RENAMED_KEYS.iter().any(|(old_key, _)| old_key == key));
+    unknown_keys
+}
+
+/// Return the configuration starship uses: the options of `config` merged with the defaults of
+/// every module
+pub fn effective_config(config: Option<&Value>) -> Value {
+    let mut table = match Value::try_from(StarshipRootConfig::try_load(config)) {
+        Ok(Value::Table(table)) => table,
+        _ => toml::value::Table::new(),
+    };
+
+    let module_config = |name: &str| config.and_then(|config| config.get(name));
+    for &module in ALL_MODULES {
+        if let Some(value) = module_effective_config(module, module_config(module)) {
+            table.insert(module.to_string(), value);
+        }
+    }
+
+    // Named env_var modules and custom modules only exist if they are configured
+    if let Some(Value::Table(env_var)) = table.get_mut("env_var") {
+        for (name, env_var_config) in module_config("env_var")
+            .and_then(Value::as_table)
+            .into_iter()
+            .flatten()
+        {
+            if env_var_config.is_table() {
+                let loaded = env_var::EnvVarConfig::load(env_var_config);
+                if let Ok(value) = Value::try_from(loaded) {
+                    env_var.insert(name.clone(), value);
+                }
+            }
+        }
+    }
+
+    let custom: toml::value::Table = module_config("custom")
+        .and_then(Value::as_table)
+        .into_iter()
+        .flatten()
+        .filter_map(|(name, custom_config)| {
+            let loaded = custom::CustomConfig::load(custom_config);
+            Some((name.clone(), Value::try_from(loaded).ok()?))
+        })
+        .collect();
+    if !custom.is_empty() {
+        table.insert("custom".to_string(), Value::Table(custom));
+    }
+
+    Value::Table(table)
+}
+
+fn env_var_unknown_keys(config: &Value) -> Vec<String> {
+    let mut unknown_keys: Vec<String> = env_var::EnvVarConfig::unknown_keys(config)
+        .into_iter()
+        .filter(|key| !config[key.as_str()].is_table())
+        .collect();
+
+    for (name, env_var_config) in config.as_table().into_iter().flatten() {
+        if env_var_config.is_table() {
+            unknown_keys.extend(
+                env_var::EnvVarConfig::unknown_keys(env_var_config)
+                    .into_iter()
+                    .map(|key| format!("{}.{}", name, key)),
+            );
+        }
+    }
+
+    unknown_keys
+}
+
+/// Maps the name of every module to the type of its config
+macro_rules! module_configs {
+    ($($name:literal => $config:ty,)*) => {
+        fn module_unknown_keys(module: &str, config: &Value) -> Vec<String> {
+            match module {
+                $($name => <$config>::unknown_keys(config),)*
+                _ => Vec::new(),
+            }
+        }
+
+        fn module_effective_config(module: &str, config: Option<&Value>) -> Option<Value> {
+            match module {
+                $($name => Value::try_from(<$config>::try_load(config)).ok(),)*
+                _ => None,
+            }
+        }
+    };
+}
+
+module_configs! {
+    "aws" => aws::AwsConfig,
+    "battery" => battery::BatteryConfig,
+    "buf" => buf::BufConfig,
+    "character" => character::CharacterConfig,
+    "cmake" => cmake::CMakeConfig,
+    "cmd_duration" => cmd_duration::CmdDurationConfig,
+    "conda" => conda::CondaConfig,
+    "crystal" => crystal::CrystalConfig,
+    "dart" => dart::DartConfig,
+    "deno" => deno::DenoConfig,
+    "direnv" => direnv::DirenvConfig,
+    "directory" => directory::DirectoryConfig,
+    "docker_context" => docker_context::DockerContextConfig,
+    "dotnet" => dotnet::DotnetConfig,
+    "elixir" => elixir::ElixirConfig,
+    "elm" => elm::ElmConfig,
+    "env_var" => env_var::EnvVarConfig,
+    "erlang" => erlang::ErlangConfig,
+    "gcloud" => gcloud::GcloudConfig,
+    "git_branch" => git_branch::GitBranchConfig,
+    "git_commit" => git_commit::GitCommitConfig,
+    "git_metrics" => git_metrics::GitMetricsConfig,
+    "git_state" => git_state::GitStateConfig,
+    "git_status" => git_status::GitStatusConfig,
+    "golang" => go::GoConfig,
+    "gradle" => gradle::GradleConfig,
+    "haskell" => haskell::HaskellConfig,
+    "helm" => helm::HelmConfig,
+    "hg_branch" => hg_branch::HgBranchConfig,
+    "hostname" => hostname::HostnameConfig,
+    "java" => java::JavaConfig,
+    "jobs" => jobs::JobsConfig,
+    "julia" => julia::JuliaConfig,
+    "kotlin" => kotlin::KotlinConfig,
+    "kubernetes" => kubernetes::KubernetesConfig,
+    "line_break" => line_break::LineBreakConfig,
+    "lua" => lua::LuaConfig,
+    "memory_usage" => memory_usage::MemoryConfig,
+    "nim" => nim::NimConfig,
+    "nix_shell" => nix_shell::NixShellConfig,
+    "nodejs" => nodejs::NodejsConfig,
+    "ocaml" => ocaml::OCamlConfig,
+    "openstack" => openstack::OpenStackConfig,
+    "package" => package::PackageConfig,
+    "perl" => perl::PerlConfig,
+    "php" => php::PhpConfig,
+    "pulumi" => pulumi::PulumiConfig,
+    "purescript" => purescript::PureScriptConfig,
+    "python" => python::PythonConfig,
+    "r" => r::RConfig,
+    "ruby" => ruby::RubyConfig,
+    "rust" => rust::RustConfig,
+    "scala" => scala::ScalaConfig,
+    "shlvl" => shlvl::ShLvlConfig,
+    "singularity" => singularity::SingularityConfig,
+    "status" => status::StatusConfig,
+    "swift" => swift::SwiftConfig,
+    "terraform" => terraform::TerraformConfig,
+    "time" => time::TimeConfig,
+    "username" => username::UsernameConfig,
+    "vagrant" => vagrant::VagrantConfig,
+    "vlang" => vlang::VLangConfig,
+    "zig" => zig::ZigConfig,
+}
@@ -1,12 +1,15 @@
 use crate::config::{ModuleConfig, RootModuleConfig};
 
+use serde::Serialize;
 use starship_module_config_derive::ModuleConfig;
 
-#[derive(Clone, ModuleConfig)]
+#[derive(Clone, ModuleConfig, Serialize)]
 pub struct NodejsConfig<'a> {
     pub format: &'a str,
     pub symbol: &'a str,
     pub style: &'a str,
+    pub read_version_files: bool,
+    pub show_engine: bool,
     pub disabled: bool,
 }
 
@@ -16,6 +19,8 @@ impl<'a> RootModuleConfig<'a> for NodejsConfig<'a> {
             format: "via [$symbol$version]($style) ",
             symbol: "⬢ ",
             style: "bold green",
+            read_version_files: true,
+            show_engine: false,
             disabled: false,
         }
     }
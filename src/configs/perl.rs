@@ -1,12 +1,21 @@
 use crate::config::{ModuleConfig, RootModuleConfig};
 
+use serde::Serialize;
 use starship_module_config_derive::ModuleConfig;
 
-#[derive(Clone, ModuleConfig)]
+#[derive(Clone, ModuleConfig, Serialize)]
 pub struct PerlConfig<'a> {
     pub symbol: &'a str,
     pub style: &'a str,
+    pub symbol_style: &'a str,
+    pub version_style: &'a str,
     pub format: &'a str,
+    pub prefix: &'a str,
+    pub suffix: &'a str,
+    pub version_format: &'a str,
+    pub detect_extensions: Vec<&'a str>,
+    pub detect_files: Vec<&'a str>,
+    pub detect_folders: Vec<&'a str>,
     pub disabled: bool,
 }
 
@@ -15,7 +24,23 @@ impl<'a> RootModuleConfig<'a> for PerlConfig<'a> {
         PerlConfig {
             symbol: "🐪 ",
             style: "149 bold",
-            format: "via [$symbol$version]($style) ",
+            symbol_style: "",
+            version_style: "",
+            format: "$prefix[$symbol]($symbol_style)[$version]($version_style)$suffix",
+            prefix: "via ",
+            suffix: " ",
+            version_format: "v${raw}",
+            detect_extensions: vec!["pl", "pm", "pod"],
+            detect_files: vec![
+                "Makefile.PL",
+                "Build.PL",
+                "cpanfile",
+                "cpanfile.snapshot",
+                "META.json",
+                "META.yml",
+                ".perl-version",
+            ],
+            detect_folders: vec![],
             disabled: false,
         }
     }
@@ -0,0 +1,23 @@
+use crate::config::{ModuleConfig, RootModuleConfig};
+
+use serde::Serialize;
+use starship_module_config_derive::ModuleConfig;
+
+#[derive(Clone, ModuleConfig, Serialize)]
+pub struct PulumiConfig<'a> {
+    pub format: &'a str,
+    pub symbol: &'a str,
+    pub style: &'a str,
+    pub disabled: bool,
+}
+
+impl<'a> RootModuleConfig<'a> for PulumiConfig<'a> {
+    fn new() -> Self {
+        PulumiConfig {
+            format: "via [$symbol($username@)$stack]($style) ",
+            symbol: "🧊 ",
+            style: "bold 5",
+            disabled: false,
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use crate::config::{ModuleConfig, RootModuleConfig};
+
+use serde::Serialize;
+use starship_module_config_derive::ModuleConfig;
+
+#[derive(Clone, ModuleConfig, Serialize)]
+pub struct ScalaConfig<'a> {
+    pub format: &'a str,
+    pub symbol: &'a str,
+    pub style: &'a str,
+    pub disabled: bool,
+}
+
+impl<'a> RootModuleConfig<'a> for ScalaConfig<'a> {
+    fn new() -> Self {
+        ScalaConfig {
+            format: "via [$symbol$version]($style) ",
+            symbol: "🆂 ",
+            style: "red dimmed",
+            disabled: false,
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use crate::config::{ModuleConfig, RootModuleConfig};
+
+use serde::Serialize;
+use starship_module_config_derive::ModuleConfig;
+
+#[derive(Clone, ModuleConfig, Serialize)]
+pub struct ShLvlConfig<'a> {
+    pub threshold: i64,
+    pub format: &'a str,
+    pub symbol: &'a str,
+    pub repeat: bool,
+    pub repeat_limit: usize,
+    pub style: &'a str,
+    pub disabled: bool,
+}
+
+impl<'a> RootModuleConfig<'a> for ShLvlConfig<'a> {
+    fn new() -> Self {
+        ShLvlConfig {
+            threshold: 2,
+            format: "[$symbol$shlvl]($style) ",
+            symbol: "↕️ ",
+            repeat: false,
+            repeat_limit: 10,
+            style: "bold yellow",
+            disabled: true,
+        }
+    }
+}
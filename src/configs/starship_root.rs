@@ -1,11 +1,19 @@
 use crate::config::{ModuleConfig, RootModuleConfig};
 
+use serde::Serialize;
 use starship_module_config_derive::ModuleConfig;
 
-#[derive(Clone, ModuleConfig)]
+#[derive(Clone, ModuleConfig, Serialize)]
 pub struct StarshipRootConfig<'a> {
     pub format: &'a str,
+    pub right_format: &'a str,
+    pub add_newline: bool,
+    pub continuation_prompt: &'a str,
     pub scan_timeout: u64,
+    pub scan_parent_depth: usize,
+    pub command_timeout: u64,
+    pub symbol_preset: &'a str,
+    pub keep_unset_env_vars: bool,
 }
 
 // List of default prompt order
@@ -14,46 +22,61 @@ pub struct StarshipRootConfig<'a> {
 pub const PROMPT_ORDER: &[&str] = &[
     "username",
     "hostname",
+    "shlvl",
     "singularity",
     "kubernetes",
     "directory",
     "git_branch",
     "git_commit",
     "git_state",
+    "git_metrics",
     "git_status",
     "hg_branch",
     "docker_context",
     "package",
     // ↓ Toolchain version modules ↓
     // (Let's keep these sorted alphabetically)
+    "buf",
     "cmake",
     "dart",
+    "deno",
     "dotnet",
     "elixir",
     "elm",
     "erlang",
     "golang",
+    "gradle",
+    "haskell",
     "helm",
     "java",
     "julia",
+    "kotlin",
+    "lua",
     "nim",
     "nodejs",
     "ocaml",
     "perl",
     "php",
+    "pulumi",
     "purescript",
     "python",
+    "r",
     "ruby",
     "rust",
+    "scala",
     "swift",
     "terraform",
+    "vagrant",
+    "vlang",
     "zig",
     // ↑ Toolchain version modules ↑
     "nix_shell",
     "conda",
     "memory_usage",
     "aws",
+    "openstack",
     "gcloud",
+    "direnv",
     "env_var",
     "crystal",
     "cmd_duration",
@@ -63,14 +86,22 @@ pub const PROMPT_ORDER: &[&str] = &[
     #[cfg(feature = "battery")]
     "battery",
     "time",
+    "status",
     "character",
 ];
 
 impl<'a> RootModuleConfig<'a> for StarshipRootConfig<'a> {
     fn new() -> Self {
         StarshipRootConfig {
-            format: "\n$all",
+            format: "$all",
+            right_format: "",
+            add_newline: true,
+            continuation_prompt: "[∙](bright-black) ",
             scan_timeout: 30,
+            scan_parent_depth: 0,
+            command_timeout: 500,
+            symbol_preset: "",
+            keep_unset_env_vars: true,
         }
     }
 }
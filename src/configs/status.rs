@@ -0,0 +1,29 @@
+use crate::config::{ModuleConfig, RootModuleConfig};
+
+use serde::Serialize;
+use starship_module_config_derive::ModuleConfig;
+
+#[derive(Clone, ModuleConfig, Serialize)]
+pub struct StatusConfig<'a> {
+    pub format: &'a str,
+    pub symbol: &'a str,
+    pub success_symbol: &'a str,
+    pub style: &'a str,
+    pub map_symbol: bool,
+    pub always: bool,
+    pub disabled: bool,
+}
+
+impl<'a> RootModuleConfig<'a> for StatusConfig<'a> {
+    fn new() -> Self {
+        StatusConfig {
+            format: "[$symbol$status]($style) ",
+            symbol: "✖",
+            success_symbol: "",
+            style: "bold red",
+            map_symbol: false,
+            always: false,
+            disabled: true,
+        }
+    }
+}
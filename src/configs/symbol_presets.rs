@@ -0,0 +1,97 @@
+//! Default module symbols for terminals that render glyphs differently.
+//!
+//! The root `symbol_preset` option picks one of these tables. Its symbols replace the default
+//! `symbol` of every listed module, unless the user configured a `symbol` for that module.
+
+/// Symbols from the Nerd Fonts glyph set
+const NERD_FONT: &[(&str, &str)] = &[
+    ("aws", "\u{f270} "),
+    ("conda", "\u{f10c} "),
+    ("dart", "\u{e798} "),
+    ("docker_context", "\u{e7b0} "),
+    ("elixir", "\u{e62d} "),
+    ("elm", "\u{e62c} "),
+    ("golang", "\u{e627} "),
+    ("gradle", "\u{e660} "),
+    ("haskell", "\u{e777} "),
+    ("java", "\u{e738} "),
+    ("julia", "\u{e624} "),
+    ("lua", "\u{e620} "),
+    ("memory_usage", "\u{f2db} "),
+    ("nix_shell", "\u{f313} "),
+    ("nodejs", "\u{e718} "),
+    ("ocaml", "\u{e67a} "),
+    ("package", "\u{f487} "),
+    ("perl", "\u{e769} "),
+    ("php", "\u{e73d} "),
+    ("python", "\u{e73c} "),
+    ("r", "\u{fcd2} "),
+    ("ruby", "\u{e739} "),
+    ("rust", "\u{e7a8} "),
+    ("scala", "\u{e737} "),
+    ("swift", "\u{e755} "),
+];
+
+/// Symbols that only use standard unicode, without glyphs from the private use area
+const UNICODE: &[(&str, &str)] = &[("git_branch", "⎇ "), ("hg_branch", "⎇ ")];
+
+/// Plain text symbols for terminals without unicode or emoji support
+const PLAIN: &[(&str, &str)] = &[
+    ("aws", "aws "),
+    ("buf", "buf "),
+    ("cmake", "cmake "),
+    ("conda", "conda "),
+    ("crystal", "cr "),
+    ("dart", "dart "),
+    ("deno", "deno "),
+    ("docker_context", "docker "),
+    ("dotnet", ".NET "),
+    ("elixir", "exs "),
+    ("elm", "elm "),
+    ("erlang", "erl "),
+    ("gcloud", "gcp "),
+    ("git_branch", "git "),
+    ("golang", "go "),
+    ("gradle", "gradle "),
+    ("haskell", "hs "),
+    ("helm", "helm "),
+    ("hg_branch", "hg "),
+    ("java", "java "),
+    ("jobs", "*"),
+    ("julia", "jl "),
+    ("kotlin", "kt "),
+    ("kubernetes", "kube "),
+    ("lua", "lua "),
+    ("memory_usage", "memory "),
+    ("nim", "nim "),
+    ("nix_shell", "nix "),
+    ("nodejs", "nodejs "),
+    ("ocaml", "ml "),
+    ("openstack", "openstack "),
+    ("package", "pkg "),
+    ("perl", "pl "),
+    ("php", "php "),
+    ("pulumi", "pulumi "),
+    ("purescript", "purs "),
+    ("python", "py "),
+    ("r", "R "),
+    ("ruby", "rb "),
+    ("rust", "rs "),
+    ("scala", "scala "),
+    ("shlvl", "shlvl "),
+    ("status", "x"),
+    ("swift", "swift "),
+    ("terraform", "terraform "),
+    ("vagrant", "vagrant "),
+    ("zig", "zig "),
+];
+
+/// Return the symbols of the preset with the given name, as `(module, symbol)` pairs
+pub fn get_symbol_preset(name: &str) -> Option<&'static [(&'static str, &'static str)]> {
+    match name {
+        "nerd-font" => Some(NERD_FONT),
+        "unicode" => Some(UNICODE),
+        "plain" => Some(PLAIN),
+        _ => None,
+    }
+}
@@ -0,0 +1,25 @@
+use crate::config::{ModuleConfig, RootModuleConfig};
+
+use serde::Serialize;
+use starship_module_config_derive::ModuleConfig;
+
+#[derive(Clone, ModuleConfig, Serialize)]
+pub struct VLangConfig<'a> {
+    pub format: &'a str,
+    pub symbol: &'a str,
+    pub style: &'a str,
+    pub only_with_vmod: bool,
+    pub disabled: bool,
+}
+
+impl<'a> RootModuleConfig<'a> for VLangConfig<'a> {
+    fn new() -> Self {
+        VLangConfig {
+            format: "via [$symbol$version]($style) ",
+            symbol: "V ",
+            style: "blue bold",
+            only_with_vmod: false,
+            disabled: false,
+        }
+    }
+}
@@ -4,6 +4,8 @@ use std::io::ErrorKind;
 use std::process;
 use std::process::Command;
 
+use crate::configs;
+
 use starship::config::StarshipConfig;
 use std::fs::File;
 use std::io::Write;
@@ -12,8 +14,8 @@ use toml::Value;
 
 const STD_EDITOR: &str = "vi";
 
-pub fn update_configuration(name: &str, value: &str) {
-    let config_path = get_config_path();
+pub fn update_configuration(name: &str, value: &str, config_file: Option<&str>) {
+    let config_path = get_config_path(config_file);
 
     let keys: Vec<&str> = name.split('.').collect();
     if keys.len() != 2 {
@@ -21,7 +23,7 @@ pub fn update_configuration(name: &str, value: &str) {
         process::exit(1);
     }
 
-    let starship_config = StarshipConfig::initialize();
+    let starship_config = StarshipConfig::initialize(config_file);
     let mut config = starship_config
         .config
         .expect("Failed to load starship config");
@@ -59,8 +61,8 @@ pub fn update_configuration(name: &str, value: &str) {
     }
 }
 
-pub fn edit_configuration() {
-    let config_path = get_config_path();
+pub fn edit_configuration(config_file: Option<&str>) {
+    let config_path = get_config_path(config_file);
     let editor_cmd = get_editor();
 
     let mut cmd_iter = editor_cmd
@@ -90,6 +92,41 @@ pub fn edit_configuration() {
     };
 }
 
+/// Print the configuration in use, with the defaults of every module filled in, as TOML
+pub fn print_configuration(config_file: Option<&str>) {
+    let config = StarshipConfig::initialize(config_file).config;
+    let effective_config = configs::effective_config(config.as_ref());
+
+    let config_str = toml::to_string_pretty(&effective_config)
+        .expect("Failed to serialize the config to string");
+    println!("{}", config_str);
+}
+
+/// Report the keys in the config file that starship doesn't recognize, e.g. typos like `symbl`.
+/// Exits with a non-zero status if any were found.
+pub fn check_configuration(config_file: Option<&str>) {
+    let config = match StarshipConfig::initialize(config_file).config {
+        Some(config) => config,
+        None => return,
+    };
+
+    for (old_key, new_key) in configs::renamed_config_keys(&config) {
+        eprintln!(
+            "[WARN] Config key `{}` is deprecated, use `{}` instead",
+            old_key, new_key
+        );
+    }
+
+    let unknown_keys = configs::unknown_config_keys(&config);
+    for key in &unknown_keys {
+        eprintln!("[WARN] Unknown config key `{}`", key);
+    }
+
+    if !unknown_keys.is_empty() {
+        process::exit(1);
+    }
+}
+
 fn get_editor() -> OsString {
     get_editor_internal(env::var_os("VISUAL"), env::var_os("EDITOR"))
 }
@@ -106,7 +143,13 @@ fn get_editor_internal(visual: Option<OsString>, editor: Option<OsString>) -> Os
     STD_EDITOR.into()
 }
 
-fn get_config_path() -> OsString {
+/// Return the path of the config file: `config_file` if given, else `$STARSHIP_CONFIG` or
+/// `~/.config/starship.toml`
+fn get_config_path(config_file: Option<&str>) -> OsString {
+    if let Some(config_file) = config_file {
+        return config_file.into();
+    }
+
     let config_path = env::var_os("STARSHIP_CONFIG").unwrap_or_else(|| "".into());
     if config_path.is_empty() {
         dirs_next::home_dir()
@@ -2,6 +2,7 @@ use crate::config::StarshipConfig;
 use crate::module::Module;
 
 use crate::modules;
+use crate::utils::{CommandOutput, CommandRunner, SystemCommandRunner};
 use clap::ArgMatches;
 use git2::{ErrorCode::UnbornBranch, Repository, RepositoryState};
 use once_cell::sync::OnceCell;
@@ -10,7 +11,10 @@ use std::env;
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::string::String;
-use std::time::{Duration, SystemTime};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+type CommandCache = HashMap<(String, Vec<String>, bool), Option<CommandOutput>>;
 
 /// Context contains data or common methods that may be used by multiple modules.
 /// The data contained within Context will be relevant to this particular rendering
@@ -22,17 +26,52 @@ pub struct Context<'a> {
     /// The current working directory that starship is being called in.
     pub current_dir: PathBuf,
 
-    /// A struct containing directory contents in a lookup-optimised format.
-    dir_contents: OnceCell<DirContents>,
+    /// A struct containing directory contents in a lookup-optimised format, or the kind of
+    /// error scanning the directory failed with, so that a slow scan is only attempted once.
+    dir_contents: OnceCell<Result<DirContents, std::io::ErrorKind>>,
+
+    /// The contents of the parent directories that are scanned when nothing matches in
+    /// `current_dir`, nearest first.
+    parent_dir_contents: OnceCell<Vec<DirContents>>,
+
+    /// When the directory scans of this render must be done by, set by the first scan from
+    /// `scan_timeout` and shared by all of them
+    scan_deadline: OnceCell<Instant>,
 
     /// Properties to provide to modules.
     pub properties: HashMap<&'a str, String>,
 
+    /// The exit code of the previous command (`--status`), `"0"` if it wasn't given
+    pub status_code: String,
+
+    /// The number of running background jobs (`--jobs`), `0` if it wasn't given
+    pub jobs: i64,
+
+    /// How long the previous command ran for in milliseconds (`--cmd-duration`), if given
+    pub cmd_duration: Option<u128>,
+
     /// Private field to store Git information for modules who need it
     repo: OnceCell<Repo>,
 
     /// The shell the user is assumed to be running
     pub shell: Shell,
+
+    /// Which prompt is being rendered
+    pub target: Target,
+
+    /// The maximum amount of time that an executed command may run for
+    pub cmd_timeout: Duration,
+
+    /// The environment variables to use instead of those of the process, set by tests
+    /// so that they neither depend on nor change the environment of the test runner
+    env: Option<HashMap<String, String>>,
+
+    /// Runs the commands executed by modules, replaced by tests to stub their outputs
+    cmd_runner: Box<dyn CommandRunner>,
+
+    /// Outputs of the commands that have already been executed during this render,
+    /// keyed on the command and its arguments
+    cmd_cache: Mutex<CommandCache>,
 }
 
 impl<'a> Context<'a> {
@@ -58,7 +97,7 @@ impl<'a> Context<'a> {
     where
         T: Into<PathBuf>,
     {
-        let config = StarshipConfig::initialize();
+        let config = StarshipConfig::initialize(arguments.value_of("config"));
 
         // Unwrap the clap arguments into a simple hashtable
         // we only care about single arguments at this point, there isn't a
@@ -70,21 +109,86 @@ impl<'a> Context<'a> {
             .map(|(a, b)| (*a, b.vals.first().cloned().unwrap().into_string().unwrap()))
             .collect();
 
+        let status_code = properties
+            .get("status_code")
+            .cloned()
+            .unwrap_or_else(|| "0".to_owned());
+        let jobs = properties
+            .get("jobs")
+            .and_then(|jobs| jobs.trim().parse().ok())
+            .unwrap_or(0);
+        let cmd_duration = properties
+            .get("cmd_duration")
+            .and_then(|cmd_duration| cmd_duration.trim().parse().ok());
+
         // TODO: Currently gets the physical directory. Get the logical directory.
         let current_dir = Context::expand_tilde(dir.into());
 
         let shell = Context::get_shell();
 
+        let target = if arguments.is_present("right") {
+            Target::Right
+        } else if arguments.is_present("continuation") {
+            Target::Continuation
+        } else {
+            Target::Main
+        };
+
+        let cmd_timeout = Duration::from_millis(config.get_root_config().command_timeout);
+
         Context {
             config,
             properties,
+            status_code,
+            jobs,
+            cmd_duration,
             current_dir,
             dir_contents: OnceCell::new(),
+            parent_dir_contents: OnceCell::new(),
+            scan_deadline: OnceCell::new(),
             repo: OnceCell::new(),
             shell,
+            target,
+            cmd_timeout,
+            env: None,
+            cmd_runner: Box::new(SystemCommandRunner),
+            cmd_cache: Mutex::new(HashMap::new()),
         }
     }
 
+    /// Whether the prompt should be rendered without any styling, as requested
+    /// by a non-empty `NO_COLOR` environment variable (see https://no-color.org)
+    pub fn no_color(&self) -> bool {
+        self.get_env("NO_COLOR")
+            .is_some_and(|value| !value.is_empty())
+    }
+
+    /// Gets the value of an environment variable, if it's set to valid unicode
+    pub fn get_env<K: AsRef<str>>(&self, key: K) -> Option<String> {
+        match &self.env {
+            Some(env) => env.get(key.as_ref()).cloned(),
+            None => env::var(key.as_ref()).ok(),
+        }
+    }
+
+    /// Replaces the environment seen by `get_env` with the given variables,
+    /// leaving the environment of the process untouched
+    #[cfg(test)]
+    pub fn set_env(&mut self, vars: &[(&str, &str)]) {
+        let env = vars
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect();
+        self.env = Some(env);
+    }
+
+    /// Replaces the runner of the commands executed by modules, e.g. with a
+    /// `FakeCommandRunner` that returns canned outputs
+    #[cfg(test)]
+    pub fn set_cmd_runner<R: CommandRunner + 'static>(&mut self, runner: R) {
+        self.cmd_runner = Box::new(runner);
+    }
+
     /// Convert a `~` in a path to the home directory
     fn expand_tilde(dir: PathBuf) -> PathBuf {
         if dir.starts_with("~") {
@@ -124,12 +228,7 @@ impl<'a> Context<'a> {
     // returns a new ScanDir struct with reference to current dir_files of context
     // see ScanDir for methods
     pub fn try_begin_scan(&'a self) -> Option<ScanDir<'a>> {
-        Some(ScanDir {
-            dir_contents: self.dir_contents().ok()?,
-            files: &[],
-            folders: &[],
-            extensions: &[],
-        })
+        Some(ScanDir::new(self.dir_contents().ok()?).set_parents(self.parent_dir_contents()))
     }
 
     /// Will lazily get repo root and branch when a module requests it.
@@ -148,29 +247,143 @@ impl<'a> Context<'a> {
                     .as_ref()
                     .and_then(|repo| repo.workdir().map(Path::to_path_buf));
                 let state = repository.as_ref().map(|repo| repo.state());
+                let path = repository.as_ref().map(|repo| repo.path().to_path_buf());
 
                 Ok(Repo {
                     branch,
                     root,
                     state,
+                    path,
                 })
             })
     }
 
     pub fn dir_contents(&self) -> Result<&DirContents, std::io::Error> {
-        self.dir_contents.get_or_try_init(|| {
-            let timeout = Duration::from_millis(self.config.get_root_config().scan_timeout);
-            DirContents::from_path_with_timeout(&self.current_dir, timeout)
+        self.dir_contents
+            .get_or_init(|| {
+                DirContents::from_path_with_deadline(&self.current_dir, self.scan_deadline())
+                    .map_err(|error| error.kind())
+            })
+            .as_ref()
+            .map_err(|&kind| kind.into())
+    }
+
+    /// The deadline of all the directory scans, `scan_timeout` after the first one started
+    fn scan_deadline(&self) -> Instant {
+        *self.scan_deadline.get_or_init(|| {
+            Instant::now() + Duration::from_millis(self.config.get_root_config().scan_timeout)
         })
     }
 
+    /// The contents of up to `scan_parent_depth` parent directories of `current_dir`, nearest
+    /// first. The walk stops at the root of the git repository `current_dir` belongs to.
+    fn parent_dir_contents(&self) -> &[DirContents] {
+        self.parent_dir_contents.get_or_init(|| {
+            let root_config = self.config.get_root_config();
+            if root_config.scan_parent_depth == 0 {
+                return Vec::new();
+            }
+
+            let deadline = self.scan_deadline();
+            let repo_root = self.get_repo().ok().and_then(|repo| repo.root.as_ref());
+            let mut parents = Vec::new();
+            for dir in self
+                .current_dir
+                .ancestors()
+                .skip(1)
+                .take(root_config.scan_parent_depth)
+            {
+                if let Some(root) = repo_root {
+                    if !dir.starts_with(root) {
+                        break;
+                    }
+                }
+                match DirContents::from_path_with_deadline(&dir.to_path_buf(), deadline) {
+                    Ok(dir_contents) => parents.push(dir_contents),
+                    Err(_) => break,
+                }
+            }
+            parents
+        })
+    }
+
+    /// `current_dir` and its parents, nearest first, for modules looking for a file that
+    /// applies to a whole project. The walk stops at the root of the git repository
+    /// `current_dir` belongs to, or else at the home directory.
+    pub fn current_dir_ancestors(&self) -> Vec<&Path> {
+        let boundary = self
+            .get_repo()
+            .ok()
+            .and_then(|repo| repo.root.clone())
+            .or_else(|| self.get_env("HOME").map(PathBuf::from))
+            .or_else(dirs_next::home_dir)
+            .filter(|boundary| self.current_dir.starts_with(boundary));
+
+        let mut ancestors = Vec::new();
+        for dir in self.current_dir.ancestors() {
+            ancestors.push(dir);
+            if boundary.as_deref() == Some(dir) {
+                break;
+            }
+        }
+        ancestors
+    }
+
+    /// Execute a command and return the output on stdout and stderr if successful.
+    /// The command is killed if it runs for longer than `cmd_timeout`.
+    ///
+    /// The result (including a failed execution) is cached for the lifetime of the context,
+    /// so identical invocations from multiple modules only spawn a single process.
+    pub fn exec_cmd(&self, cmd: &str, args: &[&str]) -> Option<CommandOutput> {
+        self.cached_exec_cmd(cmd, args, false)
+    }
+
+    /// Like `exec_cmd`, but also returns the output of a command that exits with a non-zero
+    /// status. Useful for tools that print their version to stderr and then fail.
+    pub fn exec_cmd_allow_failure(&self, cmd: &str, args: &[&str]) -> Option<CommandOutput> {
+        self.cached_exec_cmd(cmd, args, true)
+    }
+
+    fn cached_exec_cmd(
+        &self,
+        cmd: &str,
+        args: &[&str],
+        allow_failure: bool,
+    ) -> Option<CommandOutput> {
+        let key = (
+            cmd.to_owned(),
+            args.iter()
+                .map(|arg| (*arg).to_owned())
+                .collect::<Vec<String>>(),
+            allow_failure,
+        );
+
+        if let Some(output) = self.cmd_cache.lock().unwrap().get(&key) {
+            log::trace!(
+                "Using cached output of command {:?} with args {:?}",
+                cmd,
+                args
+            );
+            return output.clone();
+        }
+
+        let output = self
+            .cmd_runner
+            .exec_cmd(cmd, args, self.cmd_timeout, allow_failure);
+        self.cmd_cache.lock().unwrap().insert(key, output.clone());
+        output
+    }
+
     fn get_shell() -> Shell {
         let shell = std::env::var("STARSHIP_SHELL").unwrap_or_default();
         match shell.as_str() {
             "bash" => Shell::Bash,
+            "elvish" => Shell::Elvish,
             "fish" => Shell::Fish,
             "ion" => Shell::Ion,
+            "nu" => Shell::Nu,
             "powershell" => Shell::PowerShell,
+            "tcsh" => Shell::Tcsh,
             "zsh" => Shell::Zsh,
             _ => Shell::Unknown,
         }
@@ -195,40 +408,55 @@ impl DirContents {
         Self::from_path_with_timeout(base, Duration::from_secs(30))
     }
 
+    #[cfg(test)]
     fn from_path_with_timeout(base: &PathBuf, timeout: Duration) -> Result<Self, std::io::Error> {
-        let start = SystemTime::now();
+        Self::from_path_with_deadline(base, Instant::now() + timeout)
+    }
+
+    fn from_path_with_deadline(base: &PathBuf, deadline: Instant) -> Result<Self, std::io::Error> {
+        let start = Instant::now();
 
         let mut folders: HashSet<PathBuf> = HashSet::new();
         let mut files: HashSet<PathBuf> = HashSet::new();
         let mut file_names: HashSet<String> = HashSet::new();
         let mut extensions: HashSet<String> = HashSet::new();
 
-        fs::read_dir(base)?
-            .enumerate()
-            .take_while(|(n, _)| {
-                n & 0xFF != 0 // only check SystemTime once every 2^8 entries
-                || SystemTime::now().duration_since(start).unwrap() < timeout
-            })
-            .filter_map(|(_, entry)| entry.ok())
-            .for_each(|entry| {
-                let path = PathBuf::from(entry.path().strip_prefix(base).unwrap());
-                if entry.path().is_dir() {
-                    folders.insert(path);
-                } else {
-                    if !path.to_string_lossy().starts_with('.') {
-                        path.extension()
-                            .map(|ext| extensions.insert(ext.to_string_lossy().to_string()));
-                    }
-                    if let Some(file_name) = path.file_name() {
-                        file_names.insert(file_name.to_string_lossy().to_string());
-                    }
-                    files.insert(path);
+        for (n, entry) in fs::read_dir(base)?.enumerate() {
+            // only check the time once every 2^8 entries
+            if n & 0xFF == 0 && Instant::now() >= deadline {
+                log::warn!(
+                    "Scanning {:?} took longer than the scan_timeout, after {:?}",
+                    base,
+                    start.elapsed()
+                );
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::TimedOut,
+                    "scanning the directory timed out",
+                ));
+            }
+
+            let entry = match entry {
+                Ok(entry) => entry,
+                Err(_) => continue,
+            };
+            let path = PathBuf::from(entry.path().strip_prefix(base).unwrap());
+            if entry.path().is_dir() {
+                folders.insert(path);
+            } else {
+                if !path.to_string_lossy().starts_with('.') {
+                    path.extension()
+                        .map(|ext| extensions.insert(ext.to_string_lossy().to_string()));
                 }
-            });
+                if let Some(file_name) = path.file_name() {
+                    file_names.insert(file_name.to_string_lossy().to_string());
+                }
+                files.insert(path);
+            }
+        }
 
         log::trace!(
             "Building HashSets of directory files, folders and extensions took {:?}",
-            SystemTime::now().duration_since(start).unwrap()
+            start.elapsed()
         );
 
         Ok(DirContents {
@@ -270,6 +498,17 @@ impl DirContents {
     pub fn has_any_extension(&self, exts: &[&str]) -> bool {
         exts.iter().any(|ext| self.has_extension(ext))
     }
+
+    pub fn has_extension_ignore_case(&self, ext: &str) -> bool {
+        let ext = ext.to_lowercase();
+        self.extensions
+            .iter()
+            .any(|found| found.to_lowercase() == ext)
+    }
+
+    pub fn has_any_extension_ignore_case(&self, exts: &[&str]) -> bool {
+        exts.iter().any(|ext| self.has_extension_ignore_case(ext))
+    }
 }
 
 pub struct Repo {
@@ -283,6 +522,19 @@ pub struct Repo {
 
     /// State
     pub state: Option<RepositoryState>,
+
+    /// If `current_dir` is a git repository or is contained within one,
+    /// this is the path to the `.git` directory of that repo.
+    path: Option<PathBuf>,
+}
+
+impl Repo {
+    /// Opens the repo found by `Context::get_repo`, without searching the parents
+    /// of `current_dir` for it again. Every module opens its own handle, so that the
+    /// git modules don't wait for each other while they render in parallel.
+    pub fn open(&self) -> Option<Repository> {
+        Repository::open(self.path.as_ref()?).ok()
+    }
 }
 
 // A struct of Criteria which will be used to verify current PathBuf is
@@ -292,9 +544,24 @@ pub struct ScanDir<'a> {
     files: &'a [&'a str],
     folders: &'a [&'a str],
     extensions: &'a [&'a str],
+    extensions_ignore_case: bool,
+    excluded: &'a [&'a str],
+    parents: &'a [DirContents],
 }
 
 impl<'a> ScanDir<'a> {
+    const fn new(dir_contents: &'a DirContents) -> Self {
+        ScanDir {
+            dir_contents,
+            files: &[],
+            folders: &[],
+            extensions: &[],
+            extensions_ignore_case: false,
+            excluded: &[],
+            parents: &[],
+        }
+    }
+
     pub const fn set_files(mut self, files: &'a [&'a str]) -> Self {
         self.files = files;
         self
@@ -305,17 +572,64 @@ impl<'a> ScanDir<'a> {
         self
     }
 
+    /// Like `set_extensions`, but `Foo.PL` will also match the extension `pl`
+    pub const fn set_extensions_case_insensitive(mut self, extensions: &'a [&'a str]) -> Self {
+        self.extensions = extensions;
+        self.extensions_ignore_case = true;
+        self
+    }
+
+    /// Match if any of `folders` is a directory in the scanned directory
     pub const fn set_folders(mut self, folders: &'a [&'a str]) -> Self {
         self.folders = folders;
         self
     }
 
+    /// Directories to check, nearest first, if nothing matches in the scanned directory
+    const fn set_parents(mut self, parents: &'a [DirContents]) -> Self {
+        self.parents = parents;
+        self
+    }
+
+    /// Never match if any of `excluded` is a file or directory in the scanned directory
+    pub const fn set_excluded(mut self, excluded: &'a [&'a str]) -> Self {
+        self.excluded = excluded;
+        self
+    }
+
     /// based on the current Pathbuf check to see
     /// if any of this criteria match or exist and returning a boolean
+    ///
+    /// The parent directories are checked in turn if the current one doesn't match,
+    /// and the nearest directory with a match or an exclusion decides.
     pub fn is_match(&self) -> bool {
-        self.dir_contents.has_any_extension(self.extensions)
-            || self.dir_contents.has_any_folder(self.folders)
-            || self.dir_contents.has_any_file_name(self.files)
+        std::iter::once(self.dir_contents)
+            .chain(self.parents)
+            .find_map(|dir_contents| self.match_dir(dir_contents))
+            .unwrap_or(false)
+    }
+
+    fn match_dir(&self, dir_contents: &DirContents) -> Option<bool> {
+        if dir_contents.has_any_file_name(self.excluded)
+            || dir_contents.has_any_folder(self.excluded)
+        {
+            return Some(false);
+        }
+
+        let has_extension = if self.extensions_ignore_case {
+            dir_contents.has_any_extension_ignore_case(self.extensions)
+        } else {
+            dir_contents.has_any_extension(self.extensions)
+        };
+
+        if has_extension
+            || dir_contents.has_any_folder(self.folders)
+            || dir_contents.has_any_file_name(self.files)
+        {
+            Some(true)
+        } else {
+            None
+        }
     }
 }
 
@@ -352,13 +666,27 @@ fn get_current_branch(repository: &Repository) -> Option<String> {
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Shell {
     Bash,
+    Elvish,
     Fish,
     Ion,
+    Nu,
     PowerShell,
+    Tcsh,
     Zsh,
     Unknown,
 }
 
+/// Which kind of prompt should be rendered
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Target {
+    /// The main (left) prompt, using `format`
+    Main,
+    /// The right prompt, using `right_format`
+    Right,
+    /// The continuation prompt (PS2), using `continuation_prompt`
+    Continuation,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -375,19 +703,57 @@ mod tests {
         Ok(dir)
     }
 
+    fn prompt_args(args: &[&str]) -> ArgMatches<'static> {
+        clap::App::new("starship")
+            .args(&[
+                clap::Arg::with_name("status_code")
+                    .long("status")
+                    .takes_value(true),
+                clap::Arg::with_name("jobs").long("jobs").takes_value(true),
+                clap::Arg::with_name("cmd_duration")
+                    .long("cmd-duration")
+                    .takes_value(true),
+            ])
+            .get_matches_from(std::iter::once(&"starship").chain(args))
+    }
+
+    #[test]
+    fn test_prompt_args() {
+        let context = Context::new_with_dir(
+            prompt_args(&["--status", "127", "--jobs", "3", "--cmd-duration", "5000"]),
+            "/",
+        );
+        assert_eq!(context.status_code, "127");
+        assert_eq!(context.jobs, 3);
+        assert_eq!(context.cmd_duration, Some(5000));
+    }
+
+    #[test]
+    fn test_prompt_args_defaults() {
+        let context = Context::new_with_dir(prompt_args(&[]), "/");
+        assert_eq!(context.status_code, "0");
+        assert_eq!(context.jobs, 0);
+        assert_eq!(context.cmd_duration, None);
+
+        let context = Context::new_with_dir(
+            prompt_args(&["--jobs", "many", "--cmd-duration", "soon"]),
+            "/",
+        );
+        assert_eq!(context.jobs, 0);
+        assert_eq!(context.cmd_duration, None);
+    }
+
     #[test]
     fn test_scan_dir() -> Result<(), Box<dyn std::error::Error>> {
         let empty = testdir(&[])?;
         let empty_dc = DirContents::from_path(&PathBuf::from(empty.path()))?;
 
         assert_eq!(
-            ScanDir {
-                dir_contents: &empty_dc,
-                files: &["package.json"],
-                extensions: &["js"],
-                folders: &["node_modules"],
-            }
-            .is_match(),
+            ScanDir::new(&empty_dc)
+                .set_files(&["package.json"])
+                .set_extensions(&["js"])
+                .set_folders(&["node_modules"])
+                .is_match(),
             false
         );
         empty.close()?;
@@ -395,13 +761,11 @@ mod tests {
         let rust = testdir(&["README.md", "Cargo.toml", "src/main.rs"])?;
         let rust_dc = DirContents::from_path(&PathBuf::from(rust.path()))?;
         assert_eq!(
-            ScanDir {
-                dir_contents: &rust_dc,
-                files: &["package.json"],
-                extensions: &["js"],
-                folders: &["node_modules"],
-            }
-            .is_match(),
+            ScanDir::new(&rust_dc)
+                .set_files(&["package.json"])
+                .set_extensions(&["js"])
+                .set_folders(&["node_modules"])
+                .is_match(),
             false
         );
         rust.close()?;
@@ -409,13 +773,11 @@ mod tests {
         let java = testdir(&["README.md", "src/com/test/Main.java", "pom.xml"])?;
         let java_dc = DirContents::from_path(&PathBuf::from(java.path()))?;
         assert_eq!(
-            ScanDir {
-                dir_contents: &java_dc,
-                files: &["package.json"],
-                extensions: &["js"],
-                folders: &["node_modules"],
-            }
-            .is_match(),
+            ScanDir::new(&java_dc)
+                .set_files(&["package.json"])
+                .set_extensions(&["js"])
+                .set_folders(&["node_modules"])
+                .is_match(),
             false
         );
         java.close()?;
@@ -423,17 +785,300 @@ mod tests {
         let node = testdir(&["README.md", "node_modules/lodash/main.js", "package.json"])?;
         let node_dc = DirContents::from_path(&PathBuf::from(node.path()))?;
         assert_eq!(
-            ScanDir {
-                dir_contents: &node_dc,
-                files: &["package.json"],
-                extensions: &["js"],
-                folders: &["node_modules"],
-            }
-            .is_match(),
+            ScanDir::new(&node_dc)
+                .set_files(&["package.json"])
+                .set_extensions(&["js"])
+                .set_folders(&["node_modules"])
+                .is_match(),
             true
         );
         node.close()?;
 
         Ok(())
     }
+
+    #[test]
+    fn test_scan_dir_folders() -> Result<(), Box<dyn std::error::Error>> {
+        let terraform = testdir(&["main.tf"])?;
+        fs::create_dir(terraform.path().join(".terraform"))?;
+        let terraform_dc = DirContents::from_path(&PathBuf::from(terraform.path()))?;
+
+        assert!(ScanDir::new(&terraform_dc)
+            .set_folders(&[".terraform"])
+            .is_match());
+        assert!(!ScanDir::new(&terraform_dc)
+            .set_folders(&["node_modules"])
+            .is_match());
+        // A directory isn't a file, even if the name matches
+        assert!(!ScanDir::new(&terraform_dc)
+            .set_files(&[".terraform"])
+            .is_match());
+        terraform.close()?;
+
+        let file = testdir(&[".terraform"])?;
+        let file_dc = DirContents::from_path(&PathBuf::from(file.path()))?;
+
+        assert!(ScanDir::new(&file_dc).set_files(&[".terraform"]).is_match());
+        assert!(!ScanDir::new(&file_dc)
+            .set_folders(&[".terraform"])
+            .is_match());
+        file.close()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_scan_dir_excluded() -> Result<(), Box<dyn std::error::Error>> {
+        let node = testdir(&["package.json", "index.js", "esy.lock/index.json"])?;
+        let node_dc = DirContents::from_path(&PathBuf::from(node.path()))?;
+
+        assert!(ScanDir::new(&node_dc)
+            .set_files(&["package.json"])
+            .set_extensions(&["js"])
+            .is_match());
+        // An excluded folder overrides the matching file and extension
+        assert!(!ScanDir::new(&node_dc)
+            .set_files(&["package.json"])
+            .set_extensions(&["js"])
+            .set_excluded(&["esy.lock"])
+            .is_match());
+        // An excluded file works the same way
+        assert!(!ScanDir::new(&node_dc)
+            .set_extensions(&["js"])
+            .set_excluded(&["package.json"])
+            .is_match());
+        // Exclusions that aren't present don't change anything
+        assert!(ScanDir::new(&node_dc)
+            .set_extensions(&["js"])
+            .set_excluded(&["deno.json"])
+            .is_match());
+        node.close()?;
+
+        Ok(())
+    }
+
+    fn scan_parents_context(dir: &Path, scan_parent_depth: usize) -> Context<'static> {
+        let mut context = Context::new_with_dir(clap::ArgMatches::default(), dir);
+        context.config = StarshipConfig {
+            config: Some(toml::toml! {
+                scan_parent_depth = scan_parent_depth
+            }),
+        };
+        context
+    }
+
+    #[test]
+    fn test_scan_dir_parents() -> Result<(), Box<dyn std::error::Error>> {
+        let rust = testdir(&["Cargo.toml", "src/bin/main.rs"])?;
+        let nested = rust.path().join("src/bin");
+        let is_rust_project = |context: &Context| {
+            context
+                .try_begin_scan()
+                .unwrap()
+                .set_files(&["Cargo.toml"])
+                .is_match()
+        };
+
+        assert!(!is_rust_project(&scan_parents_context(&nested, 0)));
+        assert!(!is_rust_project(&scan_parents_context(&nested, 1)));
+        assert!(is_rust_project(&scan_parents_context(&nested, 2)));
+        assert!(is_rust_project(&scan_parents_context(&nested, 5)));
+
+        // The nearest directory with a match or an exclusion wins
+        fs::File::create(nested.join("esy.lock"))?.sync_all()?;
+        let context = scan_parents_context(&nested, 2);
+        assert!(!context
+            .try_begin_scan()
+            .unwrap()
+            .set_files(&["Cargo.toml"])
+            .set_excluded(&["esy.lock"])
+            .is_match());
+        rust.close()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_scan_dir_parents_stops_at_repo_root() -> Result<(), Box<dyn std::error::Error>> {
+        let dir = testdir(&["Cargo.toml", "repo/src/main.rs"])?;
+        Repository::init(dir.path().join("repo"))?;
+        let nested = dir.path().join("repo/src");
+
+        let context = scan_parents_context(&nested, 2);
+        assert!(!context
+            .try_begin_scan()
+            .unwrap()
+            .set_files(&["Cargo.toml"])
+            .is_match());
+        dir.close()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_scan_dir_extensions_case_insensitive() -> Result<(), Box<dyn std::error::Error>> {
+        let perl = testdir(&["SCRIPT.PL"])?;
+        let perl_dc = DirContents::from_path(&PathBuf::from(perl.path()))?;
+
+        assert!(!ScanDir::new(&perl_dc)
+            .set_extensions(&["pl", "pm"])
+            .is_match());
+        assert!(ScanDir::new(&perl_dc)
+            .set_extensions_case_insensitive(&["pl", "pm"])
+            .is_match());
+        assert!(!ScanDir::new(&perl_dc)
+            .set_extensions_case_insensitive(&["js"])
+            .is_match());
+        perl.close()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_disabled_module_does_no_work() -> Result<(), Box<dyn std::error::Error>> {
+        let dir = testdir(&["any.pl"])?;
+
+        let mut context = Context::new_with_dir(clap::ArgMatches::default(), dir.path());
+        context.config = StarshipConfig {
+            config: Some(toml::toml! {
+                [perl]
+                disabled = true
+            }),
+        };
+        assert!(modules::handle("perl", &context).is_none());
+        assert!(context.dir_contents.get().is_none());
+        assert!(context.cmd_cache.lock().unwrap().is_empty());
+
+        // The same module does scan the directory when it's enabled
+        context.config = StarshipConfig { config: None };
+        assert!(modules::handle("perl", &context).is_some());
+        assert!(context.dir_contents.get().is_some());
+        dir.close()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_scans_share_one_deadline() -> Result<(), Box<dyn std::error::Error>> {
+        let files: Vec<String> = (0..2000).map(|n| format!("file{}.txt", n)).collect();
+        let files: Vec<&str> = files.iter().map(String::as_str).collect();
+        let dir = testdir(&files)?;
+
+        let context = Context::new_with_dir(clap::ArgMatches::default(), dir.path());
+        let deadline = Instant::now();
+        context.scan_deadline.set(deadline).unwrap();
+
+        assert!(context.try_begin_scan().is_none());
+        assert_eq!(
+            context.dir_contents.get().unwrap().as_ref().err(),
+            Some(&std::io::ErrorKind::TimedOut)
+        );
+
+        // The timed out scan is neither retried nor given a new deadline
+        assert!(context.try_begin_scan().is_none());
+        assert_eq!(context.scan_deadline(), deadline);
+        let error = context.dir_contents().err().unwrap();
+        assert_eq!(error.kind(), std::io::ErrorKind::TimedOut);
+        dir.close()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_scan_timeout() -> Result<(), Box<dyn std::error::Error>> {
+        let files: Vec<String> = (0..2000).map(|n| format!("file{}.txt", n)).collect();
+        let files: Vec<&str> = files.iter().map(String::as_str).collect();
+        let dir = testdir(&files)?;
+        let path = PathBuf::from(dir.path());
+
+        let error = DirContents::from_path_with_timeout(&path, Duration::from_millis(0))
+            .err()
+            .unwrap();
+        assert_eq!(error.kind(), std::io::ErrorKind::TimedOut);
+
+        let dir_contents = DirContents::from_path_with_timeout(&path, Duration::from_secs(30))?;
+        assert_eq!(dir_contents.files().count(), 2000);
+        dir.close()?;
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(not(windows))]
+    fn test_exec_cmd_is_cached() -> Result<(), Box<dyn std::error::Error>> {
+        let dir = tempfile::tempdir()?;
+        let counter = dir.path().join("counter");
+        let script = format!("echo x >> {0}; wc -l < {0}", counter.to_string_lossy());
+        let context = Context::new_with_dir(clap::ArgMatches::default(), dir.path());
+
+        let first = context.exec_cmd("/bin/sh", &["-c", &script]);
+        let second = context.exec_cmd("/bin/sh", &["-c", &script]);
+        assert_eq!(first, second);
+        assert_eq!(fs::read_to_string(&counter)?.lines().count(), 1);
+
+        // Different arguments must not share a cache entry
+        let other = context.exec_cmd("/bin/sh", &["-c", &format!("{};", script)]);
+        assert_ne!(first, other);
+        assert_eq!(fs::read_to_string(&counter)?.lines().count(), 2);
+
+        dir.close()?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_repo_is_discovered_once() -> Result<(), Box<dyn std::error::Error>> {
+        let dir = tempfile::tempdir()?;
+        Repository::init(dir.path())?;
+        let sub_dir = dir.path().join("src");
+        fs::create_dir(&sub_dir)?;
+        let context = Context::new_with_dir(clap::ArgMatches::default(), &sub_dir);
+
+        let first = context.get_repo()?;
+        assert!(first.root.is_some());
+        assert!(first.open().is_some());
+
+        // Once discovered, the repo isn't searched for again
+        fs::rename(dir.path().join(".git"), dir.path().join("moved.git"))?;
+        let second = context.get_repo()?;
+        assert!(std::ptr::eq(first, second));
+        assert!(second.root.is_some());
+
+        dir.close()?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_exec_cmd_caches_failures() {
+        let context = Context::new_with_dir(clap::ArgMatches::default(), env::temp_dir());
+        let cmd = "starship_test_command_that_does_not_exist";
+
+        assert_eq!(context.exec_cmd(cmd, &["--version"]), None);
+        let cache = context.cmd_cache.lock().unwrap();
+        let key = (cmd.to_owned(), vec![String::from("--version")], false);
+        assert_eq!(cache.get(&key), Some(&None));
+    }
+
+    #[test]
+    #[cfg(not(windows))]
+    fn test_exec_cmd_allow_failure_keeps_stderr() {
+        let context = Context::new_with_dir(clap::ArgMatches::default(), env::temp_dir());
+        let args = ["-c", "echo 'tool version 1.2.3' >&2; exit 1"];
+
+        assert_eq!(context.exec_cmd("/bin/sh", &args), None);
+        let output = context.exec_cmd_allow_failure("/bin/sh", &args).unwrap();
+        assert_eq!(output.stdout, "");
+        assert_eq!(output.stderr, "tool version 1.2.3\n");
+    }
+
+    #[test]
+    fn test_no_color_reads_context_env() {
+        let mut context = Context::new_with_dir(clap::ArgMatches::default(), env::temp_dir());
+
+        context.set_env(&[("NO_COLOR", "1")]);
+        assert!(context.no_color());
+        context.set_env(&[("NO_COLOR", "")]);
+        assert!(!context.no_color());
+        context.set_env(&[]);
+        assert!(!context.no_color());
+    }
 }
@@ -293,8 +293,8 @@ impl<'a> StringFormatter<'a> {
                             })
                             .unwrap_or_else(|| Ok(Vec::new())),
                         FormatElement::Conditional(format) => {
-                            // Show the conditional format string if all the variables inside are not
-                            // none.
+                            // Show the conditional format string if any of the variables inside is
+                            // neither none nor empty.
                             fn _should_show_elements<'a>(
                                 format_elements: &[FormatElement],
                                 variables: &'a VariableMapType<'a>,
@@ -317,10 +317,15 @@ impl<'a> StringFormatter<'a> {
                                                             &meta_variables,
                                                         )
                                                     }
-                                                    _ => true,
+                                                    VariableValue::Plain(plain_value) => {
+                                                        !plain_value.is_empty()
+                                                    }
+                                                    VariableValue::Styled(segments) => {
+                                                        segments.iter().any(|x| !x.value.is_empty())
+                                                    }
                                                 })
-                                                // The variable is None or Err, or a meta variable
-                                                // that shouldn't show
+                                                // The variable is None, Err or empty, or a meta
+                                                // variable that shouldn't show
                                                 .unwrap_or(false)
                                         })
                                         // Can't find the variable in format string
@@ -443,6 +448,26 @@ mod tests {
         match_next!(result_iter, "text1", None);
     }
 
+    #[test]
+    fn test_text_around_variables() {
+        const FORMAT_STR: &str = "via $symbol$version-beta!";
+
+        let formatter = StringFormatter::new(FORMAT_STR)
+            .unwrap()
+            .map(|variable| match variable {
+                "symbol" => Some(Ok("🐪 ".to_owned())),
+                "version" => Some(Ok("v5.26.1".to_owned())),
+                _ => None,
+            });
+        let result = formatter.parse(None).unwrap();
+        let mut result_iter = result.iter();
+        match_next!(result_iter, "via ", None);
+        match_next!(result_iter, "🐪 ", None);
+        match_next!(result_iter, "v5.26.1", None);
+        match_next!(result_iter, "-beta!", None);
+        assert!(result_iter.next().is_none());
+    }
+
     #[test]
     fn test_variable_in_style() {
         const FORMAT_STR: &str = "[root]($style)";
@@ -481,6 +506,24 @@ mod tests {
         match_next!(result_iter, r#"\[$text](red bold)"#, None);
     }
 
+    #[test]
+    fn test_escaped_dollar() {
+        const FORMAT_STR: &str = r"\$version costs \$5, $version is free";
+
+        let formatter = StringFormatter::new(FORMAT_STR)
+            .unwrap()
+            .map(|variable| match variable {
+                "version" => Some(Ok("v1.0.0".to_owned())),
+                _ => None,
+            });
+        let result = formatter.parse(None).unwrap();
+        let mut result_iter = result.iter();
+        match_next!(result_iter, "$version costs $5, ", None);
+        match_next!(result_iter, "v1.0.0", None);
+        match_next!(result_iter, " is free", None);
+        assert!(result_iter.next().is_none());
+    }
+
     #[test]
     fn test_nested_textgroup() {
         const FORMAT_STR: &str = "outer [middle [inner](blue)](red bold)";
@@ -602,6 +645,46 @@ mod tests {
         match_next!(result_iter, " shouldn't", None);
     }
 
+    #[test]
+    fn test_conditional_with_empty_variables() {
+        const FORMAT_STR: &str = "([⇡$ahead⇣$behind](red)) ($empty_styled)";
+
+        let formatter = StringFormatter::new(FORMAT_STR)
+            .unwrap()
+            .map(|var| match var {
+                "ahead" | "behind" => Some(Ok("")),
+                _ => None,
+            })
+            .map_variables_to_segments(|var| match var {
+                "empty_styled" => Some(Ok(vec![_new_segment("x", "", None)])),
+                _ => None,
+            });
+        let result = formatter.parse(None).unwrap();
+        let mut result_iter = result.iter();
+        match_next!(result_iter, " ", None);
+        assert!(result_iter.next().is_none());
+    }
+
+    #[test]
+    fn test_conditional_with_one_non_empty_variable() {
+        const FORMAT_STR: &str = "([⇡$ahead⇣$behind](red))";
+
+        let formatter = StringFormatter::new(FORMAT_STR)
+            .unwrap()
+            .map(|var| match var {
+                "ahead" => Some(Ok("")),
+                "behind" => Some(Ok("2")),
+                _ => None,
+            });
+        let result = formatter.parse(None).unwrap();
+        let mut result_iter = result.iter();
+        match_next!(result_iter, "⇡", Some(Color::Red.normal()));
+        match_next!(result_iter, "", Some(Color::Red.normal()));
+        match_next!(result_iter, "⇣", Some(Color::Red.normal()));
+        match_next!(result_iter, "2", Some(Color::Red.normal()));
+        assert!(result_iter.next().is_none());
+    }
+
     #[test]
     fn test_nested_conditional() {
         const FORMAT_STR: &str = "($some ($none)) and ($none ($some))";
@@ -0,0 +1,111 @@
+use super::string_formatter::StringFormatterError;
+use super::StringFormatter;
+
+/// Reformats a version number, such as `5.30.1`, with a format string
+///
+/// The format string can use the following variables:
+///     - `raw`: the version as it was given
+///     - `major`, `minor` and `patch`: the first three dot separated parts of the version
+pub struct VersionFormatter<'a> {
+    formatter: StringFormatter<'a>,
+}
+
+impl<'a> VersionFormatter<'a> {
+    /// Creates an instance of a VersionFormatter from a format string
+    pub fn new(format: &'a str) -> Result<Self, StringFormatterError> {
+        StringFormatter::new(format).map(|formatter| VersionFormatter { formatter })
+    }
+
+    /// Formats a version, leaving out the parts it doesn't have
+    pub fn format_version(self, version: &str) -> Result<String, StringFormatterError> {
+        let mut parts = version.split('.');
+        let major = parts.next();
+        let minor = parts.next();
+        let patch = parts.next();
+
+        self.formatter
+            .map(|variable| match variable {
+                "raw" => Some(Ok(version)),
+                "major" => major.map(Ok),
+                "minor" => minor.map(Ok),
+                "patch" => patch.map(Ok),
+                _ => None,
+            })
+            .parse(None)
+            .map(|segments| {
+                segments
+                    .iter()
+                    .map(|segment| segment.value.as_str())
+                    .collect()
+            })
+    }
+
+    /// Formats the version for the module named `module_name`, logging errors in the format
+    pub fn format_module_version(
+        module_name: &str,
+        version: &str,
+        version_format: &str,
+    ) -> Option<String> {
+        match VersionFormatter::new(version_format)
+            .and_then(|formatter| formatter.format_version(version))
+        {
+            Ok(formatted) => Some(formatted),
+            Err(error) => {
+                log::warn!("Error formatting `{}` version:\n{}", module_name, error);
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VERSION: &str = "3.2.1";
+
+    #[test]
+    fn test_format_full_version() {
+        let formatter = VersionFormatter::new("v${major}.${minor}.${patch}").unwrap();
+        assert_eq!(formatter.format_version(VERSION).unwrap(), "v3.2.1");
+    }
+
+    #[test]
+    fn test_format_raw_version() {
+        let formatter = VersionFormatter::new("v${raw}").unwrap();
+        assert_eq!(formatter.format_version(VERSION).unwrap(), "v3.2.1");
+    }
+
+    #[test]
+    fn test_format_major_minor_version() {
+        let formatter = VersionFormatter::new("${major}.${minor}").unwrap();
+        assert_eq!(formatter.format_version(VERSION).unwrap(), "3.2");
+    }
+
+    #[test]
+    fn test_format_major_only_version() {
+        let formatter = VersionFormatter::new("v${major}").unwrap();
+        assert_eq!(formatter.format_version(VERSION).unwrap(), "v3");
+    }
+
+    #[test]
+    fn test_format_missing_parts() {
+        let formatter = VersionFormatter::new("v${major}(.${minor})(.${patch})").unwrap();
+        assert_eq!(formatter.format_version("5").unwrap(), "v5");
+    }
+
+    #[test]
+    fn test_format_four_part_version() {
+        let formatter = VersionFormatter::new("v${major}.${minor}.${patch}").unwrap();
+        assert_eq!(formatter.format_version("5.30.1.2").unwrap(), "v5.30.1");
+    }
+
+    #[test]
+    fn test_format_invalid_format() {
+        assert!(VersionFormatter::new("v${major").is_err());
+        assert_eq!(
+            VersionFormatter::format_module_version("perl", VERSION, "v${major"),
+            None
+        );
+    }
+}
@@ -110,9 +110,28 @@ fi"#,
             Some(script)
         }
         Some("ion") => {
-            let script = format!("eval $({} init ion --print-full-init)", starship);
+            let script = format!("eval $(\"{}\" init ion --print-full-init)", starship);
             Some(script)
         }
+        Some("elvish") => {
+            // `slurp` joins the lines of the script, which `eval` expects as a single string
+            let script = format!(
+                "eval (\"{}\" init elvish --print-full-init | slurp)",
+                starship
+            );
+            Some(script)
+        }
+        Some("tcsh") => {
+            // tcsh joins the lines of a command substitution, the main script separates its
+            // commands with semicolons for that reason
+            let script = format!("eval `(\"{}\" init tcsh --print-full-init)`", starship);
+            Some(script)
+        }
+        Some("nu") => {
+            // Nushell has no way to evaluate the output of a command, so the main script is
+            // printed directly for the user to save and `source`
+            return init_main("nu");
+        }
         None => {
             println!(
                 "Invalid shell name provided: {}\\n\
@@ -126,7 +145,7 @@ fi"#,
         Some(shell_basename) => {
             println!(
                 "printf \"\\n{0} is not yet supported by starship.\\n\
-                 For the time being, we support bash, zsh, fish, and ion.\\n\
+                 For the time being, we support bash, zsh, fish, powershell, ion, elvish, tcsh, and nu.\\n\
                  Please open an issue in the starship repo if you would like to \
                  see support for {0}:\\nhttps://github.com/starship/starship/issues/new\"\\n\\n",
                 shell_basename
@@ -151,6 +170,9 @@ pub fn init_main(shell_name: &str) -> io::Result<()> {
         "fish" => Some(FISH_INIT),
         "powershell" => Some(PWSH_INIT),
         "ion" => Some(ION_INIT),
+        "elvish" => Some(ELVISH_INIT),
+        "tcsh" => Some(TCSH_INIT),
+        "nu" => Some(NU_INIT),
         _ => {
             println!(
                 "printf \"Shell name detection failed on phase two init.\\n\
@@ -178,6 +200,10 @@ JOBS: The argument to `--jobs` is quoted because MacOS's `wc` leaves whitespace
 in the output. We pass it to starship and do the whitespace removal in Rust,
 to avoid the cost of an additional shell fork every shell draw.
 
+TCSH: The script is evaluated as a single line, so it can't contain comments and every
+command has to end with a semicolon. `precmd` runs before each prompt is drawn and sets
+`prompt` from the output of `starship prompt`, substituted with backticks.
+
 Note that the init scripts are not in their final form--they are processed by
 `starship init` prior to emitting the final form. In this processing, some tokens
 are replaced, e.g. `::STARSHIP::` is replaced by the full path to the
@@ -193,3 +219,9 @@ const FISH_INIT: &str = include_str!("starship.fish");
 const PWSH_INIT: &str = include_str!("starship.ps1");
 
 const ION_INIT: &str = include_str!("starship.ion");
+
+const ELVISH_INIT: &str = include_str!("starship.elv");
+
+const TCSH_INIT: &str = include_str!("starship.tcsh");
+
+const NU_INIT: &str = include_str!("starship.nu");
@@ -84,3 +84,6 @@ fi
 # Set up the start time and STARSHIP_SHELL, which controls shell-specific sequences
 STARSHIP_START_TIME=$(::STARSHIP:: time)
 export STARSHIP_SHELL="bash"
+
+# Set up the continuation prompt
+PS2="$(::STARSHIP:: prompt --continuation)"
@@ -3,6 +3,12 @@
 # Starship assumes UTF-8
 [Console]::OutputEncoding = [System.Text.Encoding]::UTF8
 function global:prompt {
+    # Save the status of the last command before anything else overwrites it.
+    # $? is only false for a failed cmdlet, $LASTEXITCODE is the exit code of the last native command.
+    $origDollarQuestion = $global:?
+    $origLastExitCode = $global:LASTEXITCODE
+    $status = if ($origDollarQuestion) { 0 } elseif ($origLastExitCode) { $origLastExitCode } else { 1 }
+
     $out = $null
     # @ makes sure the result is an array even if single or no values are returned
     $jobs = @(Get-Job | Where-Object { $_.State -eq 'Running' }).Count
@@ -13,11 +19,14 @@ function global:prompt {
     if ($lastCmd = Get-History -Count 1) {
         $duration = [math]::Round(($lastCmd.EndExecutionTime - $lastCmd.StartExecutionTime).TotalMilliseconds)
         # & ensures the path is interpreted as something to execute
-        $out = @(&::STARSHIP:: prompt "--path=$current_directory" --status=$lastexitcode --jobs=$jobs --cmd-duration=$duration)
+        $out = @(&::STARSHIP:: prompt "--path=$current_directory" --status=$status --jobs=$jobs --cmd-duration=$duration)
     } else {
-        $out = @(&::STARSHIP:: prompt "--path=$current_directory" --status=$lastexitcode --jobs=$jobs)
+        $out = @(&::STARSHIP:: prompt "--path=$current_directory" --status=$status --jobs=$jobs)
     }
 
+    # Running starship overwrites $LASTEXITCODE, restore it for the user
+    $global:LASTEXITCODE = $origLastExitCode
+
     # Convert stdout (array of lines) to expected return type string
     # `n is an escaped newline
     $out -join "`n"
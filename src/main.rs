@@ -68,6 +68,21 @@ fn main() {
         .help("The number of currently running jobs")
         .takes_value(true);
 
+    let right_arg = Arg::with_name("right")
+        .long("right")
+        .help("Print the right prompt (instead of the standard left prompt)");
+
+    let continuation_arg = Arg::with_name("continuation")
+        .long("continuation")
+        .help("Print the continuation prompt (instead of the standard left prompt)")
+        .conflicts_with("right");
+
+    let config_arg = Arg::with_name("config")
+        .long("config")
+        .value_name("CONFIG")
+        .help("The config file to use instead of $STARSHIP_CONFIG or ~/.config/starship.toml")
+        .takes_value(true);
+
     let init_scripts_arg = Arg::with_name("print_full_init")
         .long("print-full-init")
         .help("Print the main initialization script (as opposed to the init stub)");
@@ -90,11 +105,14 @@ fn main() {
             .subcommand(
                 SubCommand::with_name("prompt")
                     .about("Prints the full starship prompt")
+                    .arg(&right_arg)
+                    .arg(&continuation_arg)
                     .arg(&status_code_arg)
                     .arg(&path_arg)
                     .arg(&cmd_duration_arg)
                     .arg(&keymap_arg)
-                    .arg(&jobs_arg),
+                    .arg(&jobs_arg)
+                    .arg(&config_arg),
             )
             .subcommand(
                 SubCommand::with_name("module")
@@ -115,7 +133,8 @@ fn main() {
                     .arg(&path_arg)
                     .arg(&cmd_duration_arg)
                     .arg(&keymap_arg)
-                    .arg(&jobs_arg),
+                    .arg(&jobs_arg)
+                    .arg(&config_arg),
             )
             .subcommand(
                 SubCommand::with_name("config")
@@ -127,7 +146,19 @@ fn main() {
                             .required(false)
                             .requires("value"),
                     )
-                    .arg(Arg::with_name("value").help("Value to place into that key")),
+                    .arg(Arg::with_name("value").help("Value to place into that key"))
+                    .arg(
+                        Arg::with_name("check")
+                            .long("check")
+                            .help("Report configuration keys that aren't recognized")
+                            .conflicts_with("name"),
+                    )
+                    .arg(&config_arg),
+            )
+            .subcommand(
+                SubCommand::with_name("print-config")
+                    .about("Prints the computed starship configuration, including defaults")
+                    .arg(&config_arg),
             )
             .subcommand(SubCommand::with_name("bug-report").about(
                 "Create a pre-populated GitHub issue with information about your configuration",
@@ -138,7 +169,9 @@ fn main() {
                     .settings(&[AppSettings::Hidden]),
             )
             .subcommand(
-                SubCommand::with_name("explain").about("Explains the currently showing modules"),
+                SubCommand::with_name("explain")
+                    .about("Explains the currently showing modules")
+                    .arg(&config_arg),
             )
             .subcommand(
                 SubCommand::with_name("completions")
@@ -179,14 +212,18 @@ fn main() {
             }
         }
         ("config", Some(sub_m)) => {
-            if let Some(name) = sub_m.value_of("name") {
+            let config_file = sub_m.value_of("config");
+            if sub_m.is_present("check") {
+                configure::check_configuration(config_file)
+            } else if let Some(name) = sub_m.value_of("name") {
                 if let Some(value) = sub_m.value_of("value") {
-                    configure::update_configuration(name, value)
+                    configure::update_configuration(name, value, config_file)
                 }
             } else {
-                configure::edit_configuration()
+                configure::edit_configuration(config_file)
             }
         }
+        ("print-config", Some(sub_m)) => configure::print_configuration(sub_m.value_of("config")),
         ("bug-report", Some(_)) => bug_report::create(),
         ("time", _) => {
             match SystemTime::now()
@@ -11,12 +11,15 @@ pub const ALL_MODULES: &[&str] = &[
     "aws",
     #[cfg(feature = "battery")]
     "battery",
+    "buf",
     "character",
     "cmake",
     "cmd_duration",
     "conda",
     "dart",
+    "deno",
     "directory",
+    "direnv",
     "docker_context",
     "dotnet",
     "elixir",
@@ -26,35 +29,48 @@ pub const ALL_MODULES: &[&str] = &[
     "gcloud",
     "git_branch",
     "git_commit",
+    "git_metrics",
     "git_state",
     "git_status",
     "golang",
+    "gradle",
+    "haskell",
     "helm",
     "hg_branch",
     "hostname",
     "java",
     "jobs",
     "julia",
+    "kotlin",
     "kubernetes",
     "line_break",
+    "lua",
     "memory_usage",
     "nim",
     "nix_shell",
     "nodejs",
     "ocaml",
+    "openstack",
     "package",
     "perl",
+    "pulumi",
     "purescript",
     "python",
+    "r",
     "ruby",
     "crystal",
     "rust",
     "php",
+    "scala",
+    "shlvl",
+    "status",
     "swift",
     "terraform",
     "singularity",
     "time",
     "username",
+    "vagrant",
+    "vlang",
     "zig",
 ];
 
@@ -90,6 +106,13 @@ impl<'a> Module<'a> {
         self.segments = segments;
     }
 
+    /// Remove the styling from all of the module's segments
+    pub fn strip_styles(&mut self) {
+        for segment in &mut self.segments {
+            segment.style = None;
+        }
+    }
+
     /// Get module's name
     pub fn get_name(&self) -> &String {
         &self._name
@@ -126,6 +149,7 @@ impl<'a> Module<'a> {
         match shell {
             Shell::Bash => ansi_strings_modified(ansi_strings, shell),
             Shell::Zsh => ansi_strings_modified(ansi_strings, shell),
+            Shell::Tcsh => ansi_strings_modified(ansi_strings, shell),
             _ => ansi_strings,
         }
     }
@@ -1,4 +1,3 @@
-use std::collections::HashMap;
 use std::env;
 use std::fs::File;
 use std::io::{BufRead, BufReader};
@@ -9,6 +8,7 @@ use super::{Context, Module, RootModuleConfig};
 
 use crate::configs::aws::AwsConfig;
 use crate::formatter::StringFormatter;
+use crate::utils;
 
 type Profile = String;
 type Region = String;
@@ -25,26 +25,30 @@ fn get_aws_region_from_config(aws_profile: Option<&str>) -> Option<Region> {
 
     let file = File::open(&config_location).ok()?;
     let reader = BufReader::new(file);
-    let lines = reader.lines().filter_map(Result::ok);
-
-    let region_line = if let Some(ref aws_profile) = aws_profile {
-        lines
-            .skip_while(|line| line != &format!("[profile {}]", aws_profile))
-            .skip(1)
-            .take_while(|line| !line.starts_with('['))
-            .find(|line| line.starts_with("region"))
-    } else {
-        lines
-            .skip_while(|line| line != "[default]")
-            .skip(1)
-            .take_while(|line| !line.starts_with('['))
-            .find(|line| line.starts_with("region"))
-    }?;
+    let lines = reader
+        .lines()
+        .filter_map(Result::ok)
+        .map(|line| line.trim().to_string());
+
+    // The default profile is stored as `[default]` rather than `[profile default]`
+    let section = match aws_profile {
+        Some(aws_profile) if aws_profile != "default" => format!("[profile {}]", aws_profile),
+        _ => "[default]".to_string(),
+    };
 
-    let region = region_line.split('=').nth(1)?;
-    let region = region.trim();
+    let region = lines
+        .skip_while(|line| line != &section)
+        .skip(1)
+        .take_while(|line| !line.starts_with('['))
+        .find_map(|line| {
+            let mut key_value = line.splitn(2, '=');
+            match key_value.next()?.trim() {
+                "region" => Some(key_value.next()?.trim().to_string()),
+                _ => None,
+            }
+        })?;
 
-    Some(region.to_string())
+    Some(region)
 }
 
 fn get_aws_profile_and_region() -> (Option<Profile>, Option<Region>) {
@@ -63,13 +67,6 @@ fn get_aws_profile_and_region() -> (Option<Profile>, Option<Region>) {
     }
 }
 
-fn alias_region(region: String, aliases: &HashMap<String, &str>) -> String {
-    match aliases.get(&region) {
-        None => region,
-        Some(alias) => (*alias).to_string(),
-    }
-}
-
 pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let mut module = context.new_module("aws");
     let config: AwsConfig = AwsConfig::try_load(module.config);
@@ -79,11 +76,9 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
         return None;
     }
 
-    let mapped_region = if let Some(aws_region) = aws_region {
-        Some(alias_region(aws_region, &config.region_aliases))
-    } else {
-        None
-    };
+    let mapped_region = aws_region.map(|region| {
+        utils::alias_or_truncate(&region, &config.region_aliases, config.truncation_length)
+    });
 
     let parsed = StringFormatter::new(config.format).and_then(|formatter| {
         formatter
@@ -1,5 +1,5 @@
 use super::{Context, Module, RootModuleConfig, Shell};
-use crate::configs::battery::BatteryConfig;
+use crate::configs::battery::{BatteryConfig, BatteryDisplayConfig};
 
 use crate::formatter::StringFormatter;
 
@@ -8,7 +8,7 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     // TODO: Update when v1.0 printing refactor is implemented to only
     // print escapes in a prompt context.
     let percentage_char = match context.shell {
-        Shell::Zsh => "%%", // % is an escape in zsh, see PROMPT in `man zshmisc`
+        Shell::Zsh | Shell::Tcsh => "%%", // % is an escape in zsh and tcsh, see PROMPT in `man zshmisc`
         _ => "%",
     };
 
@@ -18,13 +18,7 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let mut module = context.new_module("battery");
     let config: BatteryConfig = BatteryConfig::try_load(module.config);
 
-    // Parse config under `display`.
-    // Select the first style that match the threshold,
-    // if all thresholds are lower do not display battery module.
-    let display_style = config
-        .display
-        .iter()
-        .find(|display_style| percentage <= display_style.threshold as f32)?;
+    let display_style = select_display_style(&config.display, percentage)?;
 
     // Parse the format string and build the module
     match StringFormatter::new(config.format) {
@@ -74,41 +68,59 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
 fn get_battery_status() -> Option<BatteryStatus> {
     let battery_manager = battery::Manager::new().ok()?;
     let batteries = battery_manager.batteries().ok()?;
-    let battery_contructor = batteries
-        .filter_map(|battery| match battery {
-            Ok(battery) => {
-                log::debug!("Battery found: {:?}", battery);
-                Some(BatteryInfo {
-                    energy: battery.energy().value,
-                    energy_full: battery.energy_full().value,
-                    state: battery.state(),
-                })
-            }
-            Err(e) => {
-                log::debug!("Unable to access battery information:\n{}", &e);
-                None
-            }
-        })
-        .fold(
-            BatteryInfo {
-                energy: 0.0,
-                energy_full: 0.0,
-                state: battery::State::Unknown,
-            },
-            |mut acc, x| {
-                acc.energy += x.energy;
-                acc.energy_full += x.energy_full;
-                acc.state = merge_battery_states(acc.state, x.state);
-                acc
-            },
-        );
+    let batteries = batteries.filter_map(|battery| match battery {
+        Ok(battery) => {
+            log::debug!("Battery found: {:?}", battery);
+            Some(BatteryInfo {
+                energy: battery.energy().value,
+                energy_full: battery.energy_full().value,
+                state: battery.state(),
+            })
+        }
+        Err(e) => {
+            log::debug!("Unable to access battery information:\n{}", &e);
+            None
+        }
+    });
+
+    let battery = merge_batteries(batteries)?;
+    log::debug!("Battery status: {:?}", battery);
+    Some(battery)
+}
+
+/// Parse config under `display`.
+/// Select the first style that match the threshold,
+/// if all thresholds are lower do not display battery module.
+fn select_display_style<'a, 'b>(
+    display: &'b [BatteryDisplayConfig<'a>],
+    percentage: f32,
+) -> Option<&'b BatteryDisplayConfig<'a>> {
+    display
+        .iter()
+        .find(|display_style| percentage <= display_style.threshold as f32)
+}
+
+/// Aggregate the charge of all batteries into a single status, so that laptops
+/// with several batteries report their combined percentage
+fn merge_batteries(batteries: impl Iterator<Item = BatteryInfo>) -> Option<BatteryStatus> {
+    let battery_contructor = batteries.fold(
+        BatteryInfo {
+            energy: 0.0,
+            energy_full: 0.0,
+            state: battery::State::Unknown,
+        },
+        |mut acc, x| {
+            acc.energy += x.energy;
+            acc.energy_full += x.energy_full;
+            acc.state = merge_battery_states(acc.state, x.state);
+            acc
+        },
+    );
     if battery_contructor.energy_full != 0.0 {
-        let battery = BatteryStatus {
+        Some(BatteryStatus {
             percentage: battery_contructor.energy / battery_contructor.energy_full * 100.0,
             state: battery_contructor.state,
-        };
-        log::debug!("Battery status: {:?}", battery);
-        Some(battery)
+        })
     } else {
         None
     }
@@ -147,3 +159,100 @@ struct BatteryStatus {
     percentage: f32,
     state: battery::State,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use battery::State;
+
+    fn display_config() -> Vec<BatteryDisplayConfig<'static>> {
+        vec![
+            BatteryDisplayConfig {
+                threshold: 20,
+                style: "red bold",
+            },
+            BatteryDisplayConfig {
+                threshold: 60,
+                style: "yellow",
+            },
+        ]
+    }
+
+    #[test]
+    fn display_style_below_lowest_threshold() {
+        let display = display_config();
+        let style = select_display_style(&display, 5.0).map(|display| display.style);
+        assert_eq!(style, Some("red bold"));
+    }
+
+    #[test]
+    fn display_style_at_threshold() {
+        let display = display_config();
+        let style = select_display_style(&display, 20.0).map(|display| display.style);
+        assert_eq!(style, Some("red bold"));
+    }
+
+    #[test]
+    fn display_style_between_thresholds() {
+        let display = display_config();
+        let style = select_display_style(&display, 42.0).map(|display| display.style);
+        assert_eq!(style, Some("yellow"));
+    }
+
+    #[test]
+    fn display_style_above_all_thresholds() {
+        let display = display_config();
+        let style = select_display_style(&display, 80.0).map(|display| display.style);
+        assert_eq!(style, None);
+    }
+
+    #[test]
+    fn merge_two_batteries() {
+        let batteries = vec![
+            BatteryInfo {
+                energy: 10.0,
+                energy_full: 40.0,
+                state: State::Discharging,
+            },
+            BatteryInfo {
+                energy: 30.0,
+                energy_full: 40.0,
+                state: State::Full,
+            },
+        ];
+
+        let status = merge_batteries(batteries.into_iter()).unwrap();
+        assert_eq!(status.percentage, 50.0);
+        assert_eq!(status.state, State::Discharging);
+    }
+
+    #[test]
+    fn merge_no_batteries() {
+        let status = merge_batteries(Vec::new().into_iter());
+        assert!(status.is_none());
+    }
+
+    #[test]
+    fn merge_battery_states_priority() {
+        assert_eq!(
+            merge_battery_states(State::Full, State::Charging),
+            State::Charging
+        );
+        assert_eq!(
+            merge_battery_states(State::Full, State::Discharging),
+            State::Discharging
+        );
+        assert_eq!(
+            merge_battery_states(State::Unknown, State::Full),
+            State::Full
+        );
+        assert_eq!(
+            merge_battery_states(State::Empty, State::Unknown),
+            State::Empty
+        );
+        assert_eq!(
+            merge_battery_states(State::Empty, State::Full),
+            State::Unknown
+        );
+    }
+}
@@ -0,0 +1,139 @@
+use super::{Context, Module, RootModuleConfig};
+
+use crate::configs::buf::BufConfig;
+use crate::formatter::StringFormatter;
+
+/// Creates a module with the current buf CLI version
+///
+/// Will display the buf version if any of the following criteria are met:
+///     - Current directory contains a `buf.yaml` or `buf.gen.yaml` file
+///     - Current directory contains a file with the `.proto` extension
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let is_buf_project = context
+        .try_begin_scan()?
+        .set_files(&["buf.yaml", "buf.gen.yaml"])
+        .set_extensions(&["proto"])
+        .is_match();
+
+    if !is_buf_project {
+        return None;
+    }
+
+    let mut module = context.new_module("buf");
+    let config: BufConfig = BufConfig::try_load(module.config);
+
+    let parsed = StringFormatter::new(config.format).and_then(|formatter| {
+        formatter
+            .map_meta(|var, _| match var {
+                "symbol" => Some(config.symbol),
+                _ => None,
+            })
+            .map_style(|variable| match variable {
+                "style" => Some(Ok(config.style)),
+                _ => None,
+            })
+            .map(|variable| match variable {
+                "version" => {
+                    let buf_output = context.exec_cmd("buf", &["--version"])?;
+                    // Versions of buf before 1.0 print their version to stderr
+                    let buf_version = if buf_output.stdout.trim().is_empty() {
+                        buf_output.stderr
+                    } else {
+                        buf_output.stdout
+                    };
+                    parse_buf_version(&buf_version).map(Ok)
+                }
+                _ => None,
+            })
+            .parse(None)
+    });
+
+    module.set_segments(match parsed {
+        Ok(segments) => segments,
+        Err(error) => {
+            log::warn!("Error in module `buf`:\n{}", error);
+            return None;
+        }
+    });
+
+    Some(module)
+}
+
+fn parse_buf_version(buf_version: &str) -> Option<String> {
+    // buf --version output looks like this:
+    // 1.0.0-rc6
+    let version = buf_version.split_whitespace().next()?;
+
+    if !version.starts_with(|c: char| c.is_ascii_digit()) {
+        return None;
+    }
+
+    Some(format!("v{}", version))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::utils::test::render_module;
+    use ansi_term::Color;
+    use std::fs::File;
+    use std::io;
+
+    #[test]
+    fn test_parse_buf_version() {
+        assert_eq!(parse_buf_version("1.0.0\n"), Some("v1.0.0".to_string()));
+        assert_eq!(
+            parse_buf_version("0.56.0-dev\n"),
+            Some("v0.56.0-dev".to_string())
+        );
+        assert_eq!(parse_buf_version("buf: command not found"), None);
+        assert_eq!(parse_buf_version(""), None);
+    }
+
+    #[test]
+    fn folder_without_buf_files() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+
+        let actual = render_module("buf", dir.path(), None);
+
+        let expected = None;
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_buf_yaml() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join("buf.yaml"))?.sync_all()?;
+
+        let actual = render_module("buf", dir.path(), None);
+
+        let expected = Some(format!("via {} ", Color::Blue.bold().paint("🦬 v1.0.0")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_buf_gen_yaml() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join("buf.gen.yaml"))?.sync_all()?;
+
+        let actual = render_module("buf", dir.path(), None);
+
+        let expected = Some(format!("via {} ", Color::Blue.bold().paint("🦬 v1.0.0")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_proto_file() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join("service.proto"))?.sync_all()?;
+
+        let actual = render_module("buf", dir.path(), None);
+
+        let expected = Some(format!("via {} ", Color::Blue.bold().paint("🦬 v1.0.0")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+}
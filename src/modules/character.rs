@@ -22,8 +22,7 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let config: CharacterConfig = CharacterConfig::try_load(module.config);
 
     let props = &context.properties;
-    let exit_code_default = String::from("0");
-    let exit_code = props.get("status_code").unwrap_or(&exit_code_default);
+    let exit_code = &context.status_code;
     let keymap_default = String::from("viins");
     let keymap = props.get("keymap").unwrap_or(&keymap_default);
     let exit_success = exit_code == "0";
@@ -33,8 +32,9 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     // Unfortunately, this is also the name of the non-vi default mode.
     // We do some environment detection in src/init.rs to translate.
     // The result: in non-vi fish, keymap is always reported as "insert"
+    // Any other shell may report vi command mode by passing `--keymap=vicmd`.
     let mode = match (&context.shell, keymap.as_str()) {
-        (Shell::Fish, "default") | (Shell::Zsh, "vicmd") => ShellEditMode::Normal,
+        (Shell::Fish, "default") | (_, "vicmd") => ShellEditMode::Normal,
         _ => ASSUMED_MODE,
     };
 
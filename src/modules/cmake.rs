@@ -2,16 +2,15 @@ use super::{Context, Module, RootModuleConfig};
 
 use crate::configs::cmake::CMakeConfig;
 use crate::formatter::StringFormatter;
-use crate::utils;
 
 /// Creates a module with the current CMake version
 ///
 /// Will display the CMake version if any of the following criteria are met:
-///     - The current directory contains a `CMakeLists.txt` file
+///     - The current directory contains a `CMakeLists.txt` or `CMakeCache.txt` file
 pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let is_cmake_project = context
         .try_begin_scan()?
-        .set_files(&["CMakeLists.txt"])
+        .set_files(&["CMakeLists.txt", "CMakeCache.txt"])
         .is_match();
 
     if !is_cmake_project {
@@ -31,9 +30,9 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
                 _ => None,
             })
             .map(|variable| match variable {
-                "version" => utils::exec_cmd("cmake", &["--version"])
-                    .map(|output| format_cmake_version(&output.stdout))
-                    .flatten()
+                "version" => context
+                    .exec_cmd("cmake", &["--version"])
+                    .and_then(|output| format_cmake_version(&output.stdout))
                     .map(Ok),
                 _ => None,
             })
@@ -43,7 +42,7 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     module.set_segments(match parsed {
         Ok(segments) => segments,
         Err(error) => {
-            log::warn!("Error in module `cmake`: \n{}", error);
+            log::warn!("Error in module `cmake`:\n{}", error);
             return None;
         }
     });
@@ -52,12 +51,20 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
 }
 
 fn format_cmake_version(cmake_version: &str) -> Option<String> {
-    let version = cmake_version.split_whitespace().nth(2)?;
-    Some(format!("v{}", version))
+    // cmake --version output looks like this:
+    // cmake version 3.20.1
+    //
+    // CMake suite maintained and supported by Kitware (kitware.com/cmake).
+    let mut words = cmake_version.lines().next()?.split_whitespace();
+    match (words.next()?, words.next()?, words.next()?) {
+        (_, "version", version) => Some(format!("v{}", version)),
+        _ => None,
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use super::*;
     use crate::modules::utils::test::render_module;
     use ansi_term::Color;
     use std::fs::File;
@@ -81,4 +88,25 @@ mod tests {
         assert_eq!(expected, actual);
         dir.close()
     }
+
+    #[test]
+    fn folder_with_cmake_cache() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join("CMakeCache.txt"))?.sync_all()?;
+        let actual = render_module("cmake", dir.path(), None);
+        let expected = Some(format!("via {} ", Color::Blue.bold().paint("🛆 v3.17.3")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn test_format_cmake_version() {
+        let input = "cmake version 3.20.1\n\nCMake suite maintained and supported by Kitware (kitware.com/cmake).\n";
+        assert_eq!(format_cmake_version(input), Some("v3.20.1".to_string()));
+
+        let input = "cmake3 version 3.17.5\n";
+        assert_eq!(format_cmake_version(input), Some("v3.17.5".to_string()));
+
+        assert_eq!(format_cmake_version("cmake: command not found"), None);
+    }
 }
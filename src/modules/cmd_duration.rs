@@ -11,12 +11,7 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let mut module = context.new_module("cmd_duration");
     let config: CmdDurationConfig = CmdDurationConfig::try_load(module.config);
 
-    let props = &context.properties;
-    let elapsed = props
-        .get("cmd_duration")
-        .unwrap_or(&"invalid_time".into())
-        .parse::<u128>()
-        .ok()?;
+    let elapsed = context.cmd_duration?;
 
     /* TODO: Once error handling is implemented, warn the user if their config
     min time is nonsensical */
@@ -75,7 +70,14 @@ fn render_time(raw_millis: u128, show_millis: bool) -> String {
     if show_millis || raw_millis < 1000 {
         rendered_components.push(render_time_component((&millis, &"ms")));
     }
-    rendered_components.join("")
+
+    let rendered = rendered_components.join("");
+    if rendered.is_empty() {
+        // A duration of zero would otherwise render as an empty string
+        String::from("0ms")
+    } else {
+        rendered
+    }
 }
 
 /// Render a single component of the time string, giving an empty string if component is zero
@@ -110,4 +112,20 @@ mod tests {
     fn test_1d() {
         assert_eq!(render_time(86_400_000 as u128, true), "1d")
     }
+    #[test]
+    fn test_0ms() {
+        assert_eq!(render_time(0, false), "0ms")
+    }
+    #[test]
+    fn test_90500ms() {
+        assert_eq!(render_time(90_500, true), "1m30s500ms")
+    }
+    #[test]
+    fn test_90500ms_without_millis() {
+        assert_eq!(render_time(90_500, false), "1m30s")
+    }
+    #[test]
+    fn test_500ms_without_millis() {
+        assert_eq!(render_time(500, false), "500ms")
+    }
 }
@@ -1,5 +1,3 @@
-use std::env;
-
 use super::{Context, Module, RootModuleConfig};
 
 use super::utils::directory::truncate;
@@ -11,7 +9,9 @@ use crate::formatter::StringFormatter;
 /// Will display the Conda environment iff `$CONDA_DEFAULT_ENV` is set.
 pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     // Reference implementation: https://github.com/denysdovhan/spaceship-prompt/blob/master/sections/conda.zsh
-    let conda_env = env::var("CONDA_DEFAULT_ENV").unwrap_or_else(|_| "".into());
+    let conda_env = context
+        .get_env("CONDA_DEFAULT_ENV")
+        .unwrap_or_else(|| "".into());
     if conda_env.trim().is_empty() {
         return None;
     }
@@ -23,7 +23,12 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
         return None;
     }
 
-    let conda_env = truncate(conda_env, config.truncation_length);
+    // A truncation length of 0 (or less) shows the full environment path
+    let conda_env = if config.truncation_length <= 0 {
+        conda_env
+    } else {
+        truncate(conda_env, config.truncation_length as usize)
+    };
 
     let parsed = StringFormatter::new(config.format).and_then(|formatter| {
         formatter
@@ -52,3 +57,28 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
 
     Some(module)
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::modules::utils::test::render_module_with_env;
+    use ansi_term::Color;
+    use std::io;
+
+    #[test]
+    fn env_set() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let actual = render_module_with_env(
+            "conda",
+            dir.path(),
+            None,
+            &[("CONDA_DEFAULT_ENV", "astronauts")],
+        );
+
+        let expected = Some(format!(
+            "via {} ",
+            Color::Green.bold().paint("🅒 astronauts")
+        ));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+}
@@ -2,7 +2,6 @@ use super::{Context, Module, RootModuleConfig};
 
 use crate::configs::crystal::CrystalConfig;
 use crate::formatter::StringFormatter;
-use crate::utils;
 
 /// Creates a module with the current Crystal version
 ///
@@ -20,7 +19,7 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
         return None;
     }
 
-    let crystal_version = utils::exec_cmd("crystal", &["--version"])?.stdout;
+    let crystal_version = context.exec_cmd("crystal", &["--version"])?.stdout;
 
     let mut module = context.new_module("crystal");
     let config: CrystalConfig = CrystalConfig::try_load(module.config);
@@ -54,11 +53,13 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
 }
 
 fn format_crystal_version(crystal_version: &str) -> Option<String> {
-    let version = crystal_version
-        // split into ["Crystal", "0.35.1", ...]
-        .split_whitespace()
-        // return "0.35.1"
-        .nth(1)?;
+    // split into ["Crystal", "0.35.1", ...]
+    let mut words = crystal_version.split_whitespace();
+    if words.next()? != "Crystal" {
+        return None;
+    }
+    // return "0.35.1"
+    let version = words.next()?;
 
     let mut formatted_version = String::with_capacity(version.len() + 1);
     formatted_version.push('v');
@@ -68,11 +69,24 @@ fn format_crystal_version(crystal_version: &str) -> Option<String> {
 
 #[cfg(test)]
 mod tests {
+    use super::format_crystal_version;
     use crate::modules::utils::test::render_module;
     use ansi_term::Color;
     use std::fs::File;
     use std::io;
 
+    #[test]
+    fn test_format_crystal_version() {
+        let input = "Crystal 1.0.0 [dd40a2442] (2021-03-22)\n\nLLVM: 10.0.0\n";
+        assert_eq!(format_crystal_version(input), Some("v1.0.0".to_string()));
+
+        let input = "Crystal 0.35.1 (2020-06-19)";
+        assert_eq!(format_crystal_version(input), Some("v0.35.1".to_string()));
+
+        assert_eq!(format_crystal_version(""), None);
+        assert_eq!(format_crystal_version("crystal: command not found"), None);
+    }
+
     #[test]
     fn folder_without_crystal_files() -> io::Result<()> {
         let dir = tempfile::tempdir()?;
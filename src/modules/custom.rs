@@ -242,6 +242,12 @@ fn handle_powershell(command: &mut Command, shell: &str, shell_args: &[&str]) {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::StarshipConfig;
+    use crate::context::Shell;
+    use ansi_term::Color;
+    use std::fs::File;
+    use std::io;
+    use std::path::Path;
 
     #[cfg(not(windows))]
     const SHELL: &[&str] = &["/bin/sh"];
@@ -317,4 +323,128 @@ mod tests {
         assert_eq!(exec_command(FAILING_COMMAND, SHELL), None);
         assert_eq!(exec_command(UNKNOWN_COMMAND, SHELL), None);
     }
+
+    fn render_custom_module(name: &str, path: &Path, config: toml::Value) -> Option<String> {
+        let mut context = Context::new_with_dir(clap::ArgMatches::default(), path);
+        context.config = StarshipConfig {
+            config: Some(config),
+        };
+        context.shell = Shell::Unknown;
+
+        module(name, &context).map(|module| module.to_string())
+    }
+
+    #[test]
+    fn file_triggered_module() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join("rocket.toml"))?.sync_all()?;
+
+        let actual = render_custom_module(
+            "test",
+            dir.path(),
+            toml::toml! {
+                [custom.test]
+                command = "echo hello"
+                files = ["rocket.toml"]
+            },
+        );
+
+        let expected = Some(format!("{} ", Color::Green.bold().paint("hello")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn extension_triggered_module() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join("engine.fuel"))?.sync_all()?;
+
+        let actual = render_custom_module(
+            "test",
+            dir.path(),
+            toml::toml! {
+                [custom.test]
+                command = "echo hello"
+                extensions = ["fuel"]
+            },
+        );
+
+        let expected = Some(format!("{} ", Color::Green.bold().paint("hello")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn module_without_matching_file() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+
+        let actual = render_custom_module(
+            "test",
+            dir.path(),
+            toml::toml! {
+                [custom.test]
+                command = "echo hello"
+                files = ["rocket.toml"]
+            },
+        );
+
+        assert_eq!(None, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn when_triggered_module() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+
+        let actual = render_custom_module(
+            "test",
+            dir.path(),
+            toml::toml! {
+                [custom.test]
+                command = "echo hello"
+                when = "echo when"
+                files = ["rocket.toml"]
+            },
+        );
+
+        let expected = Some(format!("{} ", Color::Green.bold().paint("hello")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn when_failing_module() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+
+        let actual = render_custom_module(
+            "test",
+            dir.path(),
+            toml::toml! {
+                [custom.test]
+                command = "echo hello"
+                when = FAILING_COMMAND
+            },
+        );
+
+        assert_eq!(None, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn module_with_empty_output() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+
+        let actual = render_custom_module(
+            "test",
+            dir.path(),
+            toml::toml! {
+                [custom.test]
+                command = "echo"
+                when = "echo when"
+            },
+        );
+
+        assert_eq!(None, actual);
+        dir.close()
+    }
 }
@@ -2,7 +2,6 @@ use super::{Context, Module, RootModuleConfig};
 
 use crate::configs::dart::DartConfig;
 use crate::formatter::StringFormatter;
-use crate::utils;
 
 /// Creates a module with the current Dart version
 ///
@@ -22,7 +21,13 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
         return None;
     }
 
-    let dart_version = utils::exec_cmd("dart", &["--version"])?.stderr;
+    let dart_output = context.exec_cmd("dart", &["--version"])?;
+    // Dart SDKs older than 2.10 print their version to stderr
+    let dart_version = if dart_output.stdout.trim().is_empty() {
+        dart_output.stderr
+    } else {
+        dart_output.stdout
+    };
 
     let mut module = context.new_module("dart");
     let config: DartConfig = DartConfig::try_load(module.config);
@@ -57,7 +62,7 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
 
 fn parse_dart_version(dart_version: &str) -> Option<String> {
     let version = dart_version
-        // split into ["Dart", "VM", "version:", "2.8.4", "(stable)", ...]
+        // split into ["Dart", "VM"/"SDK", "version:", "2.8.4", "(stable)", ...]
         .split_whitespace()
         // return "2.8.4"
         .nth(3)?;
@@ -77,6 +82,10 @@ mod tests {
     fn test_parse_dart_version() {
         let input = "Dart VM version: 2.8.4 (stable)";
         assert_eq!(parse_dart_version(input), Some("v2.8.4".to_string()));
+
+        let input =
+            "Dart SDK version: 2.12.0 (stable) (Thu Feb 25 19:50:53 2021 +0100) on \"linux_x64\"";
+        assert_eq!(parse_dart_version(input), Some("v2.12.0".to_string()));
     }
 
     #[test]
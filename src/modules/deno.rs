@@ -0,0 +1,130 @@
+use super::{Context, Module, RootModuleConfig};
+
+use crate::configs::deno::DenoConfig;
+use crate::formatter::StringFormatter;
+
+/// Creates a module with the current Deno version
+///
+/// Will display the Deno version if any of the following criteria are met:
+///     - Current directory contains a `deno.json`, `deno.jsonc`, `mod.ts`, `deps.ts`
+///       or `import_map.json` file
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let is_deno_project = context
+        .try_begin_scan()?
+        .set_files(&[
+            "deno.json",
+            "deno.jsonc",
+            "mod.ts",
+            "deps.ts",
+            "import_map.json",
+        ])
+        .is_match();
+
+    if !is_deno_project {
+        return None;
+    }
+
+    let mut module = context.new_module("deno");
+    let config: DenoConfig = DenoConfig::try_load(module.config);
+
+    let parsed = StringFormatter::new(config.format).and_then(|formatter| {
+        formatter
+            .map_meta(|var, _| match var {
+                "symbol" => Some(config.symbol),
+                _ => None,
+            })
+            .map_style(|variable| match variable {
+                "style" => Some(Ok(config.style)),
+                _ => None,
+            })
+            .map(|variable| match variable {
+                "version" => {
+                    parse_deno_version(&context.exec_cmd("deno", &["--version"])?.stdout).map(Ok)
+                }
+                _ => None,
+            })
+            .parse(None)
+    });
+
+    module.set_segments(match parsed {
+        Ok(segments) => segments,
+        Err(error) => {
+            log::warn!("Error in module `deno`:\n{}", error);
+            return None;
+        }
+    });
+
+    Some(module)
+}
+
+fn parse_deno_version(deno_version: &str) -> Option<String> {
+    // deno --version output looks like this:
+    // deno 1.8.3 (release, x86_64-unknown-linux-gnu)
+    // v8 9.0.257.3
+    // typescript 4.2.2
+    let version = deno_version
+        .lines()
+        .map(|line| line.split_whitespace())
+        .find_map(|mut words| match words.next() {
+            Some("deno") => words.next(),
+            _ => None,
+        })?;
+
+    Some(format!("v{}", version))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::utils::test::render_module;
+    use ansi_term::Color;
+    use std::fs::File;
+    use std::io;
+
+    #[test]
+    fn test_parse_deno_version() {
+        let input = "\
+deno 1.8.3 (release, x86_64-unknown-linux-gnu)
+v8 9.0.257.3
+typescript 4.2.2\n";
+        assert_eq!(parse_deno_version(input), Some("v1.8.3".to_string()));
+
+        let input = "v8 9.0.257.3\ntypescript 4.2.2\n";
+        assert_eq!(parse_deno_version(input), None);
+    }
+
+    #[test]
+    fn folder_without_deno_files() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+
+        let actual = render_module("deno", dir.path(), None);
+
+        let expected = None;
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_deno_json() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join("deno.json"))?.sync_all()?;
+
+        let actual = render_module("deno", dir.path(), None);
+
+        let expected = Some(format!("via {} ", Color::Green.bold().paint("🦕 v1.8.3")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_deps_ts() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join("deps.ts"))?.sync_all()?;
+
+        let actual = render_module("deno", dir.path(), None);
+
+        let expected = Some(format!("via {} ", Color::Green.bold().paint("🦕 v1.8.3")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+}
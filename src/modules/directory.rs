@@ -6,7 +6,6 @@ use path_slash::PathExt;
 use std::collections::HashMap;
 use std::iter::FromIterator;
 use std::path::{Path, PathBuf};
-use unicode_segmentation::UnicodeSegmentation;
 
 use super::{Context, Module};
 
@@ -14,13 +13,14 @@ use super::utils::directory::truncate;
 use crate::config::RootModuleConfig;
 use crate::configs::directory::DirectoryConfig;
 use crate::formatter::StringFormatter;
+use crate::utils;
 
 /// Creates a module with the current directory
 ///
 /// Will perform path contraction, substitution, and truncation.
 /// **Contraction**
 ///     - Paths beginning with the home directory or with a git repo right
-/// inside the home directory will be contracted to `~`
+/// inside the home directory will be contracted to `home_symbol` (`~` by default)
 ///     - Paths containing a git repo will contract to begin at the repo root
 ///
 /// **Substitution**
@@ -29,10 +29,16 @@ use crate::formatter::StringFormatter;
 /// **Truncation**
 /// Paths will be limited in length to `3` path components by default.
 pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
-    const HOME_SYMBOL: &str = "~";
-
     let mut module = context.new_module("directory");
-    let config: DirectoryConfig = DirectoryConfig::try_load(module.config);
+    let mut config: DirectoryConfig = DirectoryConfig::try_load(module.config);
+    if let Some(table) = module.config.and_then(toml::Value::as_table) {
+        if let Some(symbol) = deprecated_option(table, "read_only_symbol", "read_only") {
+            config.read_only = symbol;
+        }
+        if let Some(style) = deprecated_option(table, "read_only_symbol_style", "read_only_style") {
+            config.read_only_style = style;
+        }
+    }
 
     // Using environment PWD is the standard approach for determining logical path
     // If this is None for any reason, we fall back to reading the os-provided path
@@ -69,39 +75,43 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
             log::debug!("Repo root: {:?}", repo_root);
             // Contract the path to the git repo root
             contract_repo_path(current_dir, repo_root)
-                .unwrap_or_else(|| contract_path(current_dir, &home_dir, HOME_SYMBOL))
+                .unwrap_or_else(|| contract_path(current_dir, &home_dir, config.home_symbol))
         }
         // Contract the path to the home directory
-        _ => contract_path(current_dir, &home_dir, HOME_SYMBOL),
+        _ => contract_path(current_dir, &home_dir, config.home_symbol),
     };
     log::debug!("Dir string: {}", dir_string);
 
     let substituted_dir = substitute_path(dir_string, &config.substitutions);
 
     // Truncate the dir string to the maximum number of path components
-    let truncated_dir_string = truncate(substituted_dir, config.truncation_length as usize);
+    let truncated_dir_string = truncate(substituted_dir.clone(), config.truncation_length as usize);
+    let is_truncated = truncated_dir_string != substituted_dir;
 
     // Substitutions could have changed the prefix, so don't allow them and
     // fish-style path contraction together
     let fish_prefix = if config.fish_style_pwd_dir_length > 0 && config.substitutions.is_empty() {
         // If user is using fish style path, we need to add the segment first
-        let contracted_home_dir = contract_path(&current_dir, &home_dir, HOME_SYMBOL);
+        let contracted_home_dir = contract_path(&current_dir, &home_dir, config.home_symbol);
         to_fish_style(
             config.fish_style_pwd_dir_length as usize,
             contracted_home_dir,
             &truncated_dir_string,
         )
+    } else if is_truncated {
+        // Show that the beginning of the path has been cut off
+        String::from(config.truncation_symbol)
     } else {
         String::from("")
     };
     let final_dir_string = format!("{}{}", fish_prefix, truncated_dir_string);
-    let lock_symbol = String::from(config.read_only_symbol);
+    let lock_symbol = String::from(config.read_only);
 
     let parsed = StringFormatter::new(config.format).and_then(|formatter| {
         formatter
             .map_style(|variable| match variable {
                 "style" => Some(Ok(config.style)),
-                "read_only_style" => Some(Ok(config.read_only_symbol_style)),
+                "read_only_style" => Some(Ok(config.read_only_style)),
                 _ => None,
             })
             .map(|variable| match variable {
@@ -129,6 +139,25 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     Some(module)
 }
 
+/// Return the value of the option `old_name` that has been renamed to `new_name`, unless the
+/// new option is set as well, in which case the old one is silently ignored
+fn deprecated_option<'a>(
+    table: &'a toml::value::Table,
+    old_name: &str,
+    new_name: &str,
+) -> Option<&'a str> {
+    let value = table.get(old_name)?;
+    if table.contains_key(new_name) {
+        return None;
+    }
+    log::warn!(
+        "Option `directory.{}` is deprecated, use `directory.{}` instead",
+        old_name,
+        new_name
+    );
+    value.as_str()
+}
+
 fn is_readonly_dir(path: &Path) -> bool {
     match directory_utils::is_write_allowed(path) {
         Ok(res) => !res,
@@ -222,11 +251,31 @@ fn real_path<P: AsRef<Path>>(path: P) -> PathBuf {
 /// Perform a list of string substitutions on the path
 ///
 /// Given a list of (from, to) pairs, this will perform the string
-/// substitutions, in order, on the path. Any non-pair of strings is ignored.
+/// substitutions on the path in a single pass from its start. Where several
+/// patterns match at the same place, such as `~/Documents` and
+/// `~/Documents/projects`, the longest one is used. The substituted text is
+/// never matched again, so a shorter pattern can't rewrite the result of a
+/// longer one.
 fn substitute_path(dir_string: String, substitutions: &HashMap<String, &str>) -> String {
-    let mut substituted_dir = dir_string;
-    for substitution_pair in substitutions.iter() {
-        substituted_dir = substituted_dir.replace(substitution_pair.0, substitution_pair.1);
+    let mut substituted_dir = String::with_capacity(dir_string.len());
+    let mut rest = dir_string.as_str();
+
+    while let Some(next_char) = rest.chars().next() {
+        let longest_match = substitutions
+            .iter()
+            .filter(|(from, _)| !from.is_empty() && rest.starts_with(from.as_str()))
+            .max_by_key(|(from, _)| from.len());
+
+        match longest_match {
+            Some((from, to)) => {
+                substituted_dir.push_str(to);
+                rest = &rest[from.len()..];
+            }
+            None => {
+                substituted_dir.push(next_char);
+                rest = &rest[next_char.len_utf8()..];
+            }
+        }
     }
     substituted_dir
 }
@@ -254,12 +303,12 @@ fn to_fish_style(pwd_dir_length: usize, dir_string: String, truncated_dir_string
     components
         .into_iter()
         .map(|word| -> String {
-            let chars = UnicodeSegmentation::graphemes(word, true).collect::<Vec<&str>>();
             match word {
                 "" => "".to_string(),
-                _ if chars.len() <= pwd_dir_length => word.to_string(),
-                _ if word.starts_with('.') => chars[..=pwd_dir_length].join(""),
-                _ => chars[..pwd_dir_length].join(""),
+                _ if word.starts_with('.') => {
+                    utils::truncate_graphemes(word, pwd_dir_length + 1, "")
+                }
+                _ => utils::truncate_graphemes(word, pwd_dir_length, ""),
             }
         })
         .collect::<Vec<_>>()
@@ -269,6 +318,8 @@ fn to_fish_style(pwd_dir_length: usize, dir_string: String, truncated_dir_string
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::fs;
+    use std::io;
 
     #[test]
     fn contract_home_directory() {
@@ -288,6 +339,48 @@ mod tests {
         assert_eq!(output, "rocket-controls/src");
     }
 
+    #[test]
+    fn contract_repo_path_nested_directory() -> io::Result<()> {
+        let tmp_dir = tempfile::tempdir()?;
+        let repo_dir = tmp_dir.path().join("rocket-controls");
+        let dir = repo_dir.join("src/meters/fuel-gauge");
+        fs::create_dir_all(&dir)?;
+
+        let output = contract_repo_path(&dir, &repo_dir);
+        assert_eq!(
+            output,
+            Some("rocket-controls/src/meters/fuel-gauge".to_string())
+        );
+
+        let truncated = truncate(output.unwrap(), 2);
+        assert_eq!(truncated, "meters/fuel-gauge");
+        tmp_dir.close()
+    }
+
+    #[test]
+    fn contract_repo_path_repo_root() -> io::Result<()> {
+        let tmp_dir = tempfile::tempdir()?;
+        let repo_dir = tmp_dir.path().join("rocket-controls");
+        fs::create_dir_all(&repo_dir)?;
+
+        let output = contract_repo_path(&repo_dir, &repo_dir);
+        assert_eq!(output, Some("rocket-controls".to_string()));
+        tmp_dir.close()
+    }
+
+    #[test]
+    fn contract_repo_path_outside_repo() -> io::Result<()> {
+        let tmp_dir = tempfile::tempdir()?;
+        let repo_dir = tmp_dir.path().join("rocket-controls");
+        let dir = tmp_dir.path().join("elsewhere");
+        fs::create_dir_all(&repo_dir)?;
+        fs::create_dir_all(&dir)?;
+
+        let output = contract_repo_path(&dir, &repo_dir);
+        assert_eq!(output, None);
+        tmp_dir.close()
+    }
+
     #[test]
     #[cfg(target_os = "windows")]
     fn contract_windows_style_home_directory() {
@@ -339,6 +432,53 @@ mod tests {
         assert_eq!(output, "/foo/baz");
     }
 
+    #[test]
+    fn substitute_single_prefix() {
+        let full_path = "~/Documents/projects/starship";
+        let mut substitutions = HashMap::new();
+        substitutions.insert("~/Documents/projects".to_string(), "📁proj");
+
+        let output = substitute_path(full_path.to_string(), &substitutions);
+        assert_eq!(output, "📁proj/starship");
+    }
+
+    #[test]
+    fn substitute_longest_overlapping_prefix() {
+        let full_path = "~/Documents/projects/starship";
+        let mut substitutions = HashMap::new();
+        substitutions.insert("~/Documents".to_string(), "📄docs");
+        substitutions.insert("~/Documents/projects".to_string(), "📁proj");
+        substitutions.insert("~/Doc".to_string(), "doc");
+
+        let output = substitute_path(full_path.to_string(), &substitutions);
+        assert_eq!(output, "📁proj/starship");
+
+        let output = substitute_path("~/Documents/notes".to_string(), &substitutions);
+        assert_eq!(output, "📄docs/notes");
+    }
+
+    #[test]
+    fn substitute_shorter_pattern_inside_replacement() {
+        let full_path = "~/Documents/projects/starship";
+        let mut substitutions = HashMap::new();
+        substitutions.insert("~/Documents/projects".to_string(), "~/Documents/p");
+        substitutions.insert("~/Documents".to_string(), "docs");
+        substitutions.insert("/starship".to_string(), "/~/Documents");
+
+        let output = substitute_path(full_path.to_string(), &substitutions);
+        assert_eq!(output, "~/Documents/p/~/Documents");
+    }
+
+    #[test]
+    fn substitute_partial_components() {
+        let mut substitutions = HashMap::new();
+        substitutions.insert("ship".to_string(), "boat");
+        substitutions.insert("star".to_string(), "sun");
+
+        let output = substitute_path("~/starship/ship".to_string(), &substitutions);
+        assert_eq!(output, "~/sunboat/boat");
+    }
+
     #[test]
     fn fish_style_with_user_home_contracted_path() {
         let path = "~/starship/engines/booster/rocket";
@@ -369,6 +509,29 @@ mod tests {
         assert_eq!(output, "/ab/Pa/no/in/");
     }
 
+    #[test]
+    fn fish_style_with_root_path() {
+        // `truncatation_length = 1`
+        let path = "/usr/local/bin";
+        let output = to_fish_style(1, path.to_string(), "bin");
+        assert_eq!(output, "/u/l/");
+    }
+
+    #[test]
+    fn fish_style_with_pwd_dir_len_and_user_home_contracted_path() {
+        // `truncatation_length = 1`
+        let path = "~/starship/engines/booster/rocket";
+        let output = to_fish_style(2, path.to_string(), "rocket");
+        assert_eq!(output, "~/st/en/bo/");
+    }
+
+    #[test]
+    fn fish_style_with_cjk_and_combining_marks() {
+        let path = "~/日本語/e\u{301}cole/.設定/rocket";
+        let output = to_fish_style(1, path.to_string(), "rocket");
+        assert_eq!(output, "~/日/e\u{301}/.設/");
+    }
+
     #[test]
     fn fish_style_with_duplicate_directories() {
         let path = "~/starship/tmp/C++/C++/C++";
@@ -0,0 +1,244 @@
+use super::{Context, Module, RootModuleConfig};
+
+use crate::configs::direnv::DirenvConfig;
+use crate::formatter::StringFormatter;
+
+/// Creates a module showing whether direnv has loaded an `.envrc`
+///
+/// Will display the `loaded_symbol` if `$DIRENV_FILE` or `$DIRENV_DIR` is set, i.e. direnv has
+/// loaded an `.envrc`, and the `blocked_symbol` if the current directory or one of its parents
+/// (up to the repository root or the home directory) contains an `.envrc` that direnv hasn't
+/// loaded, usually because it hasn't been allowed yet.
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let mut module = context.new_module("direnv");
+    let config: DirenvConfig = DirenvConfig::try_load(module.config);
+
+    // As we default to disabled=true, we have to check here after loading our config module,
+    // before it was only checking against whatever is in the config starship.toml
+    if config.disabled {
+        return None;
+    }
+
+    let is_loaded = ["DIRENV_FILE", "DIRENV_DIR"]
+        .iter()
+        .any(|var| context.get_env(var).is_some_and(|value| !value.is_empty()));
+
+    let state = if is_loaded {
+        config.loaded_symbol
+    } else if has_envrc(context) {
+        config.blocked_symbol
+    } else {
+        return None;
+    };
+
+    let parsed = StringFormatter::new(config.format).and_then(|formatter| {
+        formatter
+            .map_meta(|variable, _| match variable {
+                "symbol" => Some(config.symbol),
+                "state" => Some(state),
+                _ => None,
+            })
+            .map_style(|variable| match variable {
+                "style" => Some(Ok(config.style)),
+                _ => None,
+            })
+            .parse(None)
+    });
+
+    module.set_segments(match parsed {
+        Ok(segments) => segments,
+        Err(error) => {
+            log::warn!("Error in module `direnv`:\n{}", error);
+            return None;
+        }
+    });
+
+    Some(module)
+}
+
+/// Whether the current directory or one of its parents, up to the repository root or the home
+/// directory, contains an `.envrc`, the files direnv looks for
+fn has_envrc(context: &Context) -> bool {
+    context
+        .current_dir_ancestors()
+        .iter()
+        .any(|dir| dir.join(".envrc").is_file())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::modules::utils::test::render_module_with_env;
+    use ansi_term::Color;
+    use std::fs::{self, File};
+    use std::io;
+
+    fn enabled() -> Option<toml::Value> {
+        Some(toml::toml! {
+            [direnv]
+            disabled = false
+        })
+    }
+
+    #[test]
+    fn not_in_direnv_directory() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let home = dir.path().to_str().unwrap();
+
+        let actual = render_module_with_env("direnv", dir.path(), enabled(), &[("HOME", home)]);
+
+        let expected = None;
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn disabled_by_default() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join(".envrc"))?.sync_all()?;
+
+        let actual = render_module_with_env(
+            "direnv",
+            dir.path(),
+            None,
+            &[("DIRENV_DIR", "-/home/user/project")],
+        );
+
+        let expected = None;
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn envrc_loaded_with_direnv_dir() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join(".envrc"))?.sync_all()?;
+
+        let actual = render_module_with_env(
+            "direnv",
+            dir.path(),
+            enabled(),
+            &[("DIRENV_DIR", "-/home/user/project")],
+        );
+
+        let expected = Some(format!("{} ", Color::Fixed(11).bold().paint("direnv ✓")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn envrc_loaded_with_direnv_file() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+
+        let actual = render_module_with_env(
+            "direnv",
+            dir.path(),
+            enabled(),
+            &[("DIRENV_FILE", "/home/user/project/.envrc")],
+        );
+
+        let expected = Some(format!("{} ", Color::Fixed(11).bold().paint("direnv ✓")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn envrc_blocked() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join(".envrc"))?.sync_all()?;
+        let home = dir.path().to_str().unwrap();
+
+        let actual = render_module_with_env(
+            "direnv",
+            dir.path(),
+            enabled(),
+            &[("HOME", home), ("DIRENV_DIR", "")],
+        );
+
+        let expected = Some(format!("{} ", Color::Fixed(11).bold().paint("direnv ✗")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn envrc_blocked_in_parent_directory() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join(".envrc"))?.sync_all()?;
+        let sub_dir = dir.path().join("src/bin");
+        fs::create_dir_all(&sub_dir)?;
+        let home = dir.path().to_str().unwrap();
+
+        let actual = render_module_with_env("direnv", &sub_dir, enabled(), &[("HOME", home)]);
+
+        let expected = Some(format!("{} ", Color::Fixed(11).bold().paint("direnv ✗")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn envrc_outside_of_home_directory_is_ignored() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join(".envrc"))?.sync_all()?;
+        let home_dir = dir.path().join("home");
+        let project_dir = home_dir.join("project");
+        fs::create_dir_all(&project_dir)?;
+        let home = home_dir.to_str().unwrap();
+
+        let actual = render_module_with_env("direnv", &project_dir, enabled(), &[("HOME", home)]);
+
+        let expected = None;
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn envrc_outside_of_repo_is_ignored() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join(".envrc"))?.sync_all()?;
+        let repo_dir = dir.path().join("repo");
+        let sub_dir = repo_dir.join("src");
+        fs::create_dir_all(&sub_dir)?;
+        git2::Repository::init(&repo_dir).unwrap();
+        let home = dir.path().to_str().unwrap();
+
+        let actual = render_module_with_env("direnv", &sub_dir, enabled(), &[("HOME", home)]);
+
+        let expected = None;
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn custom_symbols() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join(".envrc"))?.sync_all()?;
+
+        let config = Some(toml::toml! {
+            [direnv]
+            disabled = false
+            loaded_symbol = "loaded"
+            blocked_symbol = "blocked"
+        });
+
+        let home = dir.path().to_str().unwrap();
+        let actual =
+            render_module_with_env("direnv", dir.path(), config.clone(), &[("HOME", home)]);
+        let expected = Some(format!(
+            "{} ",
+            Color::Fixed(11).bold().paint("direnv blocked")
+        ));
+        assert_eq!(expected, actual);
+
+        let actual = render_module_with_env(
+            "direnv",
+            dir.path(),
+            config,
+            &[("DIRENV_FILE", "/home/user/project/.envrc")],
+        );
+        let expected = Some(format!(
+            "{} ",
+            Color::Fixed(11).bold().paint("direnv loaded")
+        ));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+}
@@ -10,10 +10,13 @@ use crate::utils;
 /// Creates a module with the currently active Docker context
 ///
 /// Will display the Docker context if the following criteria are met:
-///     - There is a file named `$HOME/.docker/config.json`
-///     - Or a file named `$DOCKER_CONFIG/config.json`
-///     - The file is JSON and contains a field named `currentContext`
-///     - The value of `currentContext` is not `default`
+///     - `only_with_files` is false, or the current directory contains a
+///       `docker-compose.yml`, `Dockerfile` or `.dockerignore` file
+///     - A context is selected by either:
+///         - The `DOCKER_HOST` or `DOCKER_CONTEXT` environment variable, if not empty
+///         - The `currentContext` field of `$DOCKER_CONFIG/config.json`
+///           (`$HOME/.docker/config.json` by default)
+///     - The selected context is not `default`
 pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let mut module = context.new_module("docker_context");
     let config: DockerContextConfig = DockerContextConfig::try_load(module.config);
@@ -21,59 +24,65 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     if config.only_with_files
         && !context
             .try_begin_scan()?
-            .set_files(&["docker-compose.yml", "Dockerfile"])
+            .set_files(&["docker-compose.yml", "Dockerfile", ".dockerignore"])
             .is_match()
     {
         return None;
     }
-    let docker_config = PathBuf::from(
-        &env::var_os("DOCKER_CONFIG")
-            .unwrap_or(dirs_next::home_dir()?.join(".docker").into_os_string()),
-    )
-    .join("config.json");
 
-    if !docker_config.exists() {
+    let ctx = get_docker_context()?;
+    if ctx.is_empty() || ctx == "default" {
         return None;
     }
 
-    let json = utils::read_file(docker_config).ok()?;
-    let parsed_json = serde_json::from_str(&json).ok()?;
+    let parsed = StringFormatter::new(config.format).and_then(|formatter| {
+        formatter
+            .map_meta(|variable, _| match variable {
+                "symbol" => Some(config.symbol),
+                _ => None,
+            })
+            .map_style(|variable| match variable {
+                "style" => Some(Ok(config.style)),
+                _ => None,
+            })
+            .map(|variable| match variable {
+                "context" => Some(Ok(&ctx)),
+                _ => None,
+            })
+            .parse(None)
+    });
 
-    match parsed_json {
-        serde_json::Value::Object(root) => {
-            let current_context = root.get("currentContext")?;
-            match current_context {
-                serde_json::Value::String(ctx) => {
-                    let parsed = StringFormatter::new(config.format).and_then(|formatter| {
-                        formatter
-                            .map_meta(|variable, _| match variable {
-                                "symbol" => Some(config.symbol),
-                                _ => None,
-                            })
-                            .map_style(|variable| match variable {
-                                "style" => Some(Ok(config.style)),
-                                _ => None,
-                            })
-                            .map(|variable| match variable {
-                                "context" => Some(Ok(ctx)),
-                                _ => None,
-                            })
-                            .parse(None)
-                    });
+    module.set_segments(match parsed {
+        Ok(segments) => segments,
+        Err(error) => {
+            log::warn!("Error in module `docker_context`:\n{}", error);
+            return None;
+        }
+    });
 
-                    module.set_segments(match parsed {
-                        Ok(segments) => segments,
-                        Err(error) => {
-                            log::warn!("Error in module `docker_context`:\n{}", error);
-                            return None;
-                        }
-                    });
+    Some(module)
+}
 
-                    Some(module)
-                }
-                _ => None,
-            }
-        }
-        _ => None,
+/// Get the Docker context the same way the `docker` CLI picks it
+fn get_docker_context() -> Option<String> {
+    if let Some(ctx) = ["DOCKER_HOST", "DOCKER_CONTEXT"]
+        .iter()
+        .find_map(|var| env::var(var).ok().filter(|value| !value.is_empty()))
+    {
+        return Some(ctx);
     }
+
+    let docker_config = PathBuf::from(
+        &env::var_os("DOCKER_CONFIG")
+            .unwrap_or(dirs_next::home_dir()?.join(".docker").into_os_string()),
+    )
+    .join("config.json");
+
+    let json = utils::read_file(docker_config).ok()?;
+    let parsed_json: serde_json::Value = serde_json::from_str(&json).ok()?;
+
+    parsed_json
+        .get("currentContext")?
+        .as_str()
+        .map(String::from)
 }
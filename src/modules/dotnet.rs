@@ -3,7 +3,7 @@ use quick_xml::Reader;
 use std::ffi::OsStr;
 use std::iter::Iterator;
 use std::ops::Deref;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::str;
 
 use super::{Context, Module, RootModuleConfig};
@@ -66,9 +66,14 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
                 "version" => {
                     let version = if enable_heuristic {
                         let repo_root = context.get_repo().ok().and_then(|r| r.root.as_deref());
-                        estimate_dotnet_version(&dotnet_files, &context.current_dir, repo_root)
+                        estimate_dotnet_version(
+                            context,
+                            &dotnet_files,
+                            &context.current_dir,
+                            repo_root,
+                        )
                     } else {
-                        get_version_from_cli()
+                        get_version_from_cli(context)
                     };
                     version.map(|v| Ok(v.0))
                 }
@@ -89,12 +94,12 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     Some(module)
 }
 
-fn find_current_tfm<'a>(files: &[DotNetFile<'a>]) -> Option<String> {
+fn find_current_tfm(files: &[DotNetFile]) -> Option<String> {
     let get_file_of_type = |t: FileType| files.iter().find(|f| f.file_type == t);
 
     let relevant_file = get_file_of_type(FileType::ProjectFile)?;
 
-    get_tfm_from_project_file(relevant_file.path)
+    get_tfm_from_project_file(&relevant_file.path)
 }
 
 fn get_tfm_from_project_file(path: &Path) -> Option<String> {
@@ -135,8 +140,9 @@ fn get_tfm_from_project_file(path: &Path) -> Option<String> {
     None
 }
 
-fn estimate_dotnet_version<'a>(
-    files: &[DotNetFile<'a>],
+fn estimate_dotnet_version(
+    context: &Context,
+    files: &[DotNetFile],
     current_dir: &Path,
     repo_root: Option<&Path>,
 ) -> Option<Version> {
@@ -149,19 +155,19 @@ fn estimate_dotnet_version<'a>(
         .or_else(|| files.iter().next())?;
 
     match relevant_file.file_type {
-        FileType::GlobalJson => {
-            get_pinned_sdk_version_from_file(relevant_file.path).or_else(get_latest_sdk_from_cli)
-        }
+        FileType::GlobalJson => get_pinned_sdk_version_from_file(&relevant_file.path)
+            .or_else(|| get_latest_sdk_from_cli(context)),
         FileType::SolutionFile => {
             // With this heuristic, we'll assume that a "global.json" won't
             // be found in any directory above the solution file.
-            get_latest_sdk_from_cli()
+            get_latest_sdk_from_cli(context)
         }
         _ => {
             // If we see a dotnet project, we'll check a small number of neighboring
             // directories to see if we can find a global.json. Otherwise, assume the
             // latest SDK is in use.
-            try_find_nearby_global_json(current_dir, repo_root).or_else(get_latest_sdk_from_cli)
+            try_find_nearby_global_json(current_dir, repo_root)
+                .or_else(|| get_latest_sdk_from_cli(context))
         }
     }
 }
@@ -251,13 +257,14 @@ fn get_pinned_sdk_version(json: &str) -> Option<Version> {
     }
 }
 
-fn get_local_dotnet_files<'a>(context: &'a Context) -> Result<Vec<DotNetFile<'a>>, std::io::Error> {
+fn get_local_dotnet_files(context: &Context) -> Result<Vec<DotNetFile>, std::io::Error> {
     Ok(context
         .dir_contents()?
         .files()
         .filter_map(|p| {
             get_dotnet_file_type(p).map(|t| DotNetFile {
-                path: p.as_ref(),
+                // The directory contents are relative to the current directory
+                path: context.current_dir.join(p),
                 file_type: t,
             })
         })
@@ -289,13 +296,13 @@ fn map_str_to_lower(value: Option<&OsStr>) -> Option<String> {
     Some(value?.to_str()?.to_ascii_lowercase())
 }
 
-fn get_version_from_cli() -> Option<Version> {
-    let version_output = utils::exec_cmd("dotnet", &["--version"])?;
+fn get_version_from_cli(context: &Context) -> Option<Version> {
+    let version_output = context.exec_cmd("dotnet", &["--version"])?;
     Some(Version(format!("v{}", version_output.stdout.trim())))
 }
 
-fn get_latest_sdk_from_cli() -> Option<Version> {
-    match utils::exec_cmd("dotnet", &["--list-sdks"]) {
+fn get_latest_sdk_from_cli(context: &Context) -> Option<Version> {
+    match context.exec_cmd("dotnet", &["--list-sdks"]) {
         Some(sdks_output) => {
             fn parse_failed<T>() -> Option<T> {
                 log::warn!("Unable to parse the output from `dotnet --list-sdks`.");
@@ -326,13 +333,13 @@ fn get_latest_sdk_from_cli() -> Option<Version> {
                 "Received a non-success exit code from `dotnet --list-sdks`. \
                  Falling back to `dotnet --version`.",
             );
-            get_version_from_cli()
+            get_version_from_cli(context)
         }
     }
 }
 
-struct DotNetFile<'a> {
-    path: &'a Path,
+struct DotNetFile {
+    path: PathBuf,
     file_type: FileType,
 }
 
@@ -354,24 +361,116 @@ impl Deref for Version {
     }
 }
 
-#[test]
-fn should_parse_version_from_global_json() {
-    let json_text = r#"
-        {
-            "sdk": {
-                "version": "1.2.3"
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::utils::test::render_module;
+    use ansi_term::Color;
+    use std::fs::{self, File};
+    use std::io;
+
+    #[test]
+    fn should_parse_version_from_global_json() {
+        let json_text = r#"
+            {
+                "sdk": {
+                    "version": "1.2.3"
+                }
             }
-        }
-    "#;
+        "#;
 
-    let version = get_pinned_sdk_version(json_text).unwrap();
-    assert_eq!("v1.2.3", version.0);
-}
+        let version = get_pinned_sdk_version(json_text).unwrap();
+        assert_eq!("v1.2.3", version.0);
+    }
+
+    #[test]
+    fn should_ignore_empty_global_json() {
+        let json_text = "{}";
+
+        let version = get_pinned_sdk_version(json_text);
+        assert!(version.is_none());
+    }
+
+    #[test]
+    fn folder_without_dotnet_files() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+
+        let actual = render_module("dotnet", dir.path(), None);
+
+        let expected = None;
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_project_file_shows_latest_sdk() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join("project.csproj"))?.sync_all()?;
+
+        let actual = render_module("dotnet", dir.path(), None);
+
+        let expected = Some(format!("{} ", Color::Blue.bold().paint("•NET v3.1.103")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_global_json_shows_pinned_sdk() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join("project.fsproj"))?.sync_all()?;
+        fs::write(
+            dir.path().join("global.json"),
+            r#"{ "sdk": { "version": "2.2.402" } }"#,
+        )?;
 
-#[test]
-fn should_ignore_empty_global_json() {
-    let json_text = "{}";
+        let actual = render_module("dotnet", dir.path(), None);
 
-    let version = get_pinned_sdk_version(json_text);
-    assert!(version.is_none());
+        let expected = Some(format!("{} ", Color::Blue.bold().paint("•NET v2.2.402")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_global_json_without_heuristic_uses_binary() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        fs::write(
+            dir.path().join("global.json"),
+            r#"{ "sdk": { "version": "2.2.402" } }"#,
+        )?;
+
+        let actual = render_module(
+            "dotnet",
+            dir.path(),
+            Some(toml::toml! {
+                [dotnet]
+                heuristic = false
+            }),
+        );
+
+        let expected = Some(format!("{} ", Color::Blue.bold().paint("•NET v3.1.103")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_project_file_shows_target_framework() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        fs::write(
+            dir.path().join("project.csproj"),
+            r#"<Project Sdk="Microsoft.NET.Sdk">
+                <PropertyGroup>
+                    <TargetFramework>netcoreapp3.1</TargetFramework>
+                </PropertyGroup>
+            </Project>"#,
+        )?;
+
+        let actual = render_module("dotnet", dir.path(), None);
+
+        let expected = Some(format!(
+            "{} ",
+            Color::Blue.bold().paint("•NET v3.1.103 🎯 netcoreapp3.1")
+        ));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
 }
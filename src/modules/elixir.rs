@@ -2,7 +2,6 @@ use super::{Context, Module, RootModuleConfig};
 
 use crate::configs::elixir::ElixirConfig;
 use crate::formatter::StringFormatter;
-use crate::utils;
 
 use regex::Regex;
 const ELIXIR_VERSION_PATTERN: &str = "\
@@ -14,14 +13,19 @@ Elixir (?P<elixir>\\d[.\\d]+).*";
 ///
 /// Will display the Elixir version if any of the following criteria are met:
 ///     - Current directory contains a `mix.exs` file
+///     - Current directory contains a file with the `.ex` or `.exs` extension
 pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
-    let is_elixir_project = context.try_begin_scan()?.set_files(&["mix.exs"]).is_match();
+    let is_elixir_project = context
+        .try_begin_scan()?
+        .set_files(&["mix.exs"])
+        .set_extensions(&["ex", "exs"])
+        .is_match();
 
     if !is_elixir_project {
         return None;
     }
 
-    let (otp_version, elixir_version) = get_elixir_version()?;
+    let (otp_version, elixir_version) = get_elixir_version(context)?;
 
     let mut module = context.new_module("elixir");
     let config = ElixirConfig::try_load(module.config);
@@ -33,6 +37,7 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
             })
             .map_style(|variable| match variable {
                 "style" => Some(Ok(config.style)),
+                "otp_style" => Some(Ok(config.otp_style)),
                 _ => None,
             })
             .map(|variable| match variable {
@@ -54,8 +59,8 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     Some(module)
 }
 
-fn get_elixir_version() -> Option<(String, String)> {
-    let output = utils::exec_cmd("elixir", &["--version"])?.stdout;
+fn get_elixir_version(context: &Context) -> Option<(String, String)> {
+    let output = context.exec_cmd("elixir", &["--version"])?.stdout;
 
     parse_elixir_version(&output)
 }
@@ -119,4 +124,61 @@ Elixir 1.10 (compiled with Erlang/OTP 22)
 
         dir.close()
     }
+
+    #[test]
+    fn test_with_exs_script_file() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join("script.exs"))?.sync_all()?;
+
+        let expected = Some(format!(
+            "via {} ",
+            Color::Purple.bold().paint("💧 1.10 (OTP 22)")
+        ));
+        let output = render_module("elixir", dir.path(), None);
+
+        assert_eq!(output, expected);
+
+        dir.close()
+    }
+
+    #[test]
+    fn test_with_ex_file() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join("app.ex"))?.sync_all()?;
+
+        let expected = Some(format!(
+            "via {} ",
+            Color::Purple.bold().paint("💧 1.10 (OTP 22)")
+        ));
+        let output = render_module("elixir", dir.path(), None);
+
+        assert_eq!(output, expected);
+
+        dir.close()
+    }
+
+    #[test]
+    fn test_with_separate_otp_style() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join("mix.exs"))?.sync_all()?;
+
+        let expected = Some(format!(
+            "via {} {}",
+            Color::Purple.bold().paint("💧 1.10"),
+            Color::Red.paint("OTP 22")
+        ));
+        let output = render_module(
+            "elixir",
+            dir.path(),
+            Some(toml::toml! {
+                [elixir]
+                format = "via [$symbol$version]($style) [OTP $otp_version]($otp_style)"
+                otp_style = "red"
+            }),
+        );
+
+        assert_eq!(output, expected);
+
+        dir.close()
+    }
 }
@@ -2,7 +2,6 @@ use super::{Context, Module, RootModuleConfig};
 
 use crate::configs::elm::ElmConfig;
 use crate::formatter::StringFormatter;
-use crate::utils;
 
 /// Creates a module with the current Elm version
 ///
@@ -24,7 +23,7 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
         return None;
     }
 
-    let elm_version = utils::exec_cmd("elm", &["--version"])?.stdout;
+    let elm_version = context.exec_cmd("elm", &["--version"])?.stdout;
     let module_version = Some(format!("v{}", elm_version.trim()))?;
 
     let mut module = context.new_module("elm");
@@ -12,11 +12,26 @@ use crate::formatter::StringFormatter;
 ///     - env_var.disabled is absent or false
 ///     - env_var.variable is defined
 ///     - a variable named as the value of env_var.variable is defined
-pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
-    let mut module = context.new_module("env_var");
+///
+/// Named modules (`[env_var.NAME]`) are configured independently of each other and
+/// read the variable `NAME` unless `variable` is set.
+pub fn module<'a>(name: Option<&str>, context: &'a Context) -> Option<Module<'a>> {
+    let mut module = match name {
+        Some(name) => {
+            let config = context.config.get_env_var_module_config(name);
+            let desc = super::description("env_var");
+            Module::new(&format!("env_var.{}", name), desc, config)
+        }
+        None => context.new_module("env_var"),
+    };
     let config: EnvVarConfig = EnvVarConfig::try_load(module.config);
 
-    let env_value = get_env_value(config.variable?, config.default)?;
+    if config.disabled {
+        return None;
+    }
+
+    let variable = config.variable.or(name)?;
+    let env_value = get_env_value(variable, config.default)?;
     let parsed = StringFormatter::new(config.format).and_then(|formatter| {
         formatter
             .map_meta(|var, _| match var {
@@ -37,7 +52,7 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     module.set_segments(match parsed {
         Ok(segments) => segments,
         Err(error) => {
-            log::warn!("Error in module `env_var`:\n{}", error);
+            log::warn!("Error in module `{}`:\n{}", module.get_name(), error);
             return None;
         }
     });
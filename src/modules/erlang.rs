@@ -8,10 +8,12 @@ use crate::formatter::StringFormatter;
 /// Will display the Erlang version if any of the following criteria are met:
 ///     - Current directory contains a rebar.config file
 ///     - Current directory contains a erlang.mk file
+///     - Current directory contains a file with the `.erl` or `.hrl` extension
 pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let is_erlang_project = context
         .try_begin_scan()?
         .set_files(&["rebar.config", "erlang.mk"])
+        .set_extensions(&["erl", "hrl"])
         .is_match();
 
     if !is_erlang_project {
@@ -32,7 +34,7 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
                 _ => None,
             })
             .map(|variable| match variable {
-                "version" => get_erlang_version().map(Ok),
+                "version" => get_erlang_version(context).map(Ok),
                 _ => None,
             })
             .parse(None)
@@ -49,20 +51,26 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     Some(module)
 }
 
-fn get_erlang_version() -> Option<String> {
-    use crate::utils;
-
-    Some(utils::exec_cmd(
+fn get_erlang_version(context: &Context) -> Option<String> {
+    // The expression is passed to `erl` as is, without a shell to strip quotes around it.
+    // Should `erl` still not halt, the command timeout stops it.
+    let version = context.exec_cmd(
         "erl",
         &[
             "-noshell",
             "-eval",
-            "'Fn=filename:join([code:root_dir(),\"releases\",erlang:system_info(otp_release),\"OTP_VERSION\"]),\
+            "Fn=filename:join([code:root_dir(),\"releases\",erlang:system_info(otp_release),\"OTP_VERSION\"]),\
              {ok,Content}=file:read_file(Fn),\
              io:format(\"~s\",[Content]),\
-             halt(0).'"
+             halt(0)."
         ]
-    )?.stdout.trim().to_string())
+    )?.stdout.trim().to_string();
+
+    if version.is_empty() {
+        return None;
+    }
+
+    Some(version)
 }
 
 #[cfg(test)]
@@ -84,6 +92,32 @@ mod tests {
         dir.close()
     }
 
+    #[test]
+    fn test_with_erlang_mk() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join("erlang.mk"))?.sync_all()?;
+
+        let expected = Some(format!("via {} ", Color::Red.bold().paint("🖧 22.1.3")));
+        let output = render_module("erlang", dir.path(), None);
+
+        assert_eq!(output, expected);
+
+        dir.close()
+    }
+
+    #[test]
+    fn test_with_erl_file() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join("server.erl"))?.sync_all()?;
+
+        let expected = Some(format!("via {} ", Color::Red.bold().paint("🖧 22.1.3")));
+        let output = render_module("erlang", dir.path(), None);
+
+        assert_eq!(output, expected);
+
+        dir.close()
+    }
+
     #[test]
     fn test_with_config() -> io::Result<()> {
         let dir = tempfile::tempdir()?;
@@ -1,4 +1,3 @@
-use std::collections::HashMap;
 use std::env;
 use std::fs::File;
 use std::io::{BufRead, BufReader, Error, ErrorKind};
@@ -9,52 +8,62 @@ use super::{Context, Module, RootModuleConfig};
 
 use crate::configs::gcloud::GcloudConfig;
 use crate::formatter::StringFormatter;
+use crate::utils;
 
 type Account = String;
 type Project = String;
 type Region = String;
 type Active = String;
 
-fn get_gcloud_account_from_config(current_config: &PathBuf) -> Option<Account> {
-    let file = File::open(&current_config).ok()?;
+/// Find `key` in the `[section]` of a gcloud configuration file
+fn get_gcloud_value_from_config(
+    current_config: &PathBuf,
+    section: &str,
+    key: &str,
+) -> Option<String> {
+    let file = File::open(current_config).ok()?;
     let reader = BufReader::new(file);
-    let lines = reader.lines().filter_map(Result::ok);
-    let account_line = lines
-        .skip_while(|line| line != "[core]")
+    let lines = reader
+        .lines()
+        .map_while(Result::ok)
+        .map(|line| line.trim().to_string());
+    let section = format!("[{}]", section);
+    let value = lines
+        .skip_while(|line| line != &section)
         .skip(1)
         .take_while(|line| !line.starts_with('['))
-        .find(|line| line.starts_with("account"))?;
-    let account = account_line.split('=').nth(1)?.trim();
-    Some(account.to_string())
+        .find_map(|line| {
+            let mut key_value = line.splitn(2, '=');
+            if key_value.next()?.trim() == key {
+                Some(key_value.next()?.trim().to_string())
+            } else {
+                None
+            }
+        })?;
+    Some(value)
+}
+
+fn get_gcloud_account_from_config(current_config: &PathBuf) -> Option<Account> {
+    get_gcloud_value_from_config(current_config, "core", "account")
 }
 
 fn get_gcloud_project_from_config(current_config: &PathBuf) -> Option<Project> {
-    let file = File::open(&current_config).ok()?;
-    let reader = BufReader::new(file);
-    let lines = reader.lines().filter_map(Result::ok);
-    let project_line = lines
-        .skip_while(|line| line != "[core]")
-        .skip(1)
-        .take_while(|line| !line.starts_with('['))
-        .find(|line| line.starts_with("project"))?;
-    let project = project_line.split('=').nth(1)?.trim();
-    Some(project.to_string())
+    get_gcloud_value_from_config(current_config, "core", "project")
 }
 
 fn get_gcloud_region_from_config(current_config: &PathBuf) -> Option<Region> {
-    let file = File::open(&current_config).ok()?;
-    let reader = BufReader::new(file);
-    let lines = reader.lines().filter_map(Result::ok);
-    let region_line = lines
-        .skip_while(|line| line != "[compute]")
-        .skip(1)
-        .take_while(|line| !line.starts_with('['))
-        .find(|line| line.starts_with("region"))?;
-    let region = region_line.split('=').nth(1)?.trim();
-    Some(region.to_string())
+    get_gcloud_value_from_config(current_config, "compute", "region")
 }
 
+/// The active configuration can be overridden with `$CLOUDSDK_ACTIVE_CONFIG_NAME`, otherwise
+/// it's the one named in the `active_config` file
 fn get_active_config(config_root: &PathBuf) -> Option<String> {
+    if let Ok(active_config) = env::var("CLOUDSDK_ACTIVE_CONFIG_NAME") {
+        if !active_config.is_empty() {
+            return Some(active_config);
+        }
+    }
+
     let path = config_root.join("active_config");
     let file = File::open(&path).ok()?;
     let reader = BufReader::new(file);
@@ -63,7 +72,8 @@ fn get_active_config(config_root: &PathBuf) -> Option<String> {
         None => Err(Error::new(ErrorKind::NotFound, "empty")),
     };
     match first_line {
-        Ok(c) => Some(c),
+        Ok(c) if !c.trim().is_empty() => Some(c.trim().to_string()),
+        Ok(_) => None,
         Err(_) => None,
     }
 }
@@ -87,13 +97,6 @@ fn get_config_dir() -> Option<PathBuf> {
     Some(config_dir)
 }
 
-fn alias_region(region: String, aliases: &HashMap<String, &str>) -> String {
-    match aliases.get(&region) {
-        None => region.to_string(),
-        Some(alias) => (*alias).to_string(),
-    }
-}
-
 pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let mut module = context.new_module("gcloud");
     let config: GcloudConfig = GcloudConfig::try_load(module.config);
@@ -113,11 +116,9 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
         return None;
     }
 
-    let mapped_region = if let Some(gcloud_region) = gcloud_region {
-        Some(alias_region(gcloud_region, &config.region_aliases))
-    } else {
-        None
-    };
+    let mapped_region = gcloud_region.map(|region| {
+        utils::alias_or_truncate(&region, &config.region_aliases, config.truncation_length)
+    });
 
     let parsed = StringFormatter::new(config.format).and_then(|formatter| {
         formatter
@@ -142,7 +143,7 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     module.set_segments(match parsed {
         Ok(segments) => segments,
         Err(error) => {
-            log::error!("Error in module `gcloud`: \n{}", error);
+            log::warn!("Error in module `gcloud`:\n{}", error);
             return None;
         }
     });
@@ -1,9 +1,10 @@
-use unicode_segmentation::UnicodeSegmentation;
+use git2::Repository;
 
 use super::{Context, Module, RootModuleConfig};
 
 use crate::configs::git_branch::GitBranchConfig;
 use crate::formatter::StringFormatter;
+use crate::utils;
 
 /// Creates a module with the Git branch in the current directory
 ///
@@ -12,7 +13,7 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let mut module = context.new_module("git_branch");
     let config = GitBranchConfig::try_load(module.config);
 
-    let truncation_symbol = get_first_grapheme(config.truncation_symbol);
+    let truncation_symbol = utils::first_grapheme(config.truncation_symbol);
 
     // TODO: Once error handling is implemented, warn the user if their config
     //       truncation length is nonsensical
@@ -29,15 +30,22 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let repo = context.get_repo().ok()?;
     let branch_name = repo.branch.as_ref()?;
 
-    let mut graphemes = get_graphemes(&branch_name);
-    let trunc_len = len.min(graphemes.len());
+    let git_repo = repo.open();
 
-    if trunc_len < graphemes.len() {
-        // The truncation symbol should only be added if we truncate
-        graphemes[trunc_len] = truncation_symbol;
-        graphemes.truncate(trunc_len + 1)
+    let mut branch = utils::truncate_graphemes(branch_name, len, truncation_symbol);
+    if config.show_remote {
+        let remote_branch = git_repo
+            .as_ref()
+            .and_then(|git_repo| get_remote_branch(git_repo, branch_name));
+        if let Some((remote_branch, is_matching)) = remote_branch {
+            if !(is_matching && config.hide_matching) {
+                branch = format!("{} → {}", branch, remote_branch);
+            }
+        }
     }
 
+    let upstream_gone = git_repo.as_ref().is_some_and(is_upstream_gone);
+
     let parsed = StringFormatter::new(config.format).and_then(|formatter| {
         formatter
             .map_meta(|var, _| match var {
@@ -49,7 +57,8 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
                 _ => None,
             })
             .map(|variable| match variable {
-                "branch" => Some(Ok(graphemes.concat())),
+                "branch" => Some(Ok(branch.clone())),
+                "upstream_gone" if upstream_gone => Some(Ok(config.upstream_gone.to_string())),
                 _ => None,
             })
             .parse(None)
@@ -66,12 +75,40 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     Some(module)
 }
 
-fn get_first_grapheme(text: &str) -> &str {
-    UnicodeSegmentation::graphemes(text, true)
-        .next()
-        .unwrap_or("")
+/// The full name of the upstream ref of the current branch, e.g. `refs/remotes/origin/main`,
+/// if one is configured
+fn get_upstream_name(git_repo: &Repository) -> Option<String> {
+    let head = git_repo.head().ok().filter(|head| head.is_branch())?;
+    let upstream = git_repo.branch_upstream_name(head.name()?).ok()?;
+    upstream.as_str().map(String::from)
+}
+
+/// Whether the current branch tracks an upstream branch whose remote-tracking ref no longer
+/// exists, e.g. because it was deleted on the remote and then pruned
+fn is_upstream_gone(git_repo: &Repository) -> bool {
+    match get_upstream_name(git_repo) {
+        Some(upstream) => git_repo.find_reference(&upstream).is_err(),
+        None => false,
+    }
 }
 
-fn get_graphemes(text: &str) -> Vec<&str> {
-    UnicodeSegmentation::graphemes(text, true).collect()
+/// The name of the existing upstream branch of the current branch, e.g. `origin/main`, and
+/// whether it has the same name as the local `branch_name`
+fn get_remote_branch(git_repo: &Repository, branch_name: &str) -> Option<(String, bool)> {
+    let upstream = get_upstream_name(git_repo)?;
+    let upstream_ref = git_repo.find_reference(&upstream).ok()?;
+    let remote_branch = upstream_ref.shorthand()?.to_string();
+
+    let is_matching = match git_repo.branch_remote_name(&upstream) {
+        Ok(remote) => {
+            remote
+                .as_str()
+                .and_then(|remote| remote_branch.strip_prefix(remote)?.strip_prefix('/'))
+                == Some(branch_name)
+        }
+        // The upstream is a local branch
+        Err(_) => remote_branch == branch_name,
+    };
+
+    Some((remote_branch, is_matching))
 }
@@ -1,5 +1,4 @@
 use super::{Context, Module, RootModuleConfig};
-use git2::Repository;
 
 use crate::configs::git_commit::GitCommitConfig;
 use crate::formatter::StringFormatter;
@@ -12,8 +11,7 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let config: GitCommitConfig = GitCommitConfig::try_load(module.config);
 
     let repo = context.get_repo().ok()?;
-    let repo_root = repo.root.as_ref()?;
-    let git_repo = Repository::open(repo_root).ok()?;
+    let git_repo = repo.open()?;
 
     let is_detached = git_repo.head_detached().ok()?;
     if config.only_detached && !is_detached {
@@ -0,0 +1,86 @@
+use git2::{DiffOptions, Repository};
+
+use super::{Context, Module, RootModuleConfig};
+
+use crate::configs::git_metrics::GitMetricsConfig;
+use crate::formatter::StringFormatter;
+
+/// Creates a module with the number of lines added and deleted in the working tree
+///
+/// Only changes which haven't been staged yet are counted. To keep the prompt fast,
+/// nothing is shown for repositories with more than `max_files` files in their index.
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let mut module = context.new_module("git_metrics");
+    let config: GitMetricsConfig = GitMetricsConfig::try_load(module.config);
+    if config.disabled {
+        return None;
+    };
+
+    let repo = context.get_repo().ok()?;
+    let git_repo = repo.open()?;
+
+    let (added, deleted) = get_diff_stats(&git_repo, config.max_files)?;
+
+    let parsed = StringFormatter::new(config.format).and_then(|formatter| {
+        formatter
+            .map_style(|variable| match variable {
+                "added_style" => Some(Ok(config.added_style)),
+                "deleted_style" => Some(Ok(config.deleted_style)),
+                _ => None,
+            })
+            .map(|variable| match variable {
+                "added" => format_count(added).map(Ok),
+                "deleted" => format_count(deleted).map(Ok),
+                _ => None,
+            })
+            .parse(None)
+    });
+
+    module.set_segments(match parsed {
+        Ok(segments) => {
+            if segments.is_empty() {
+                return None;
+            } else {
+                segments
+            }
+        }
+        Err(error) => {
+            log::warn!("Error in module `git_metrics`:\n{}", error);
+            return None;
+        }
+    });
+
+    Some(module)
+}
+
+/// Returns the number of lines added and deleted between the index and the working tree
+///
+/// A `max_files` of `0` (or less) means there's no limit on the number of files.
+fn get_diff_stats(repository: &Repository, max_files: i64) -> Option<(usize, usize)> {
+    let index = repository.index().ok()?;
+    if max_files > 0 && index.len() > max_files as usize {
+        log::debug!(
+            "Skipping git_metrics, the index contains more than {} files",
+            max_files
+        );
+        return None;
+    }
+
+    let mut diff_options = DiffOptions::new();
+    diff_options.ignore_submodules(true);
+
+    let diff = repository
+        .diff_index_to_workdir(Some(&index), Some(&mut diff_options))
+        .ok()?;
+    let stats = diff.stats().ok()?;
+
+    Some((stats.insertions(), stats.deletions()))
+}
+
+fn format_count(count: usize) -> Option<String> {
+    if count == 0 {
+        None
+    } else {
+        Some(count.to_string())
+    }
+}
@@ -143,8 +143,8 @@ fn describe_rebase<'a>(root: &'a PathBuf, rebase_config: &'a str) -> StateDescri
 
     StateDescription {
         label: rebase_config,
-        current: Some(format!("{}", progress.unwrap().0)),
-        total: Some(format!("{}", progress.unwrap().1)),
+        current: progress.map(|(current, _)| format!("{}", current)),
+        total: progress.map(|(_, total)| format!("{}", total)),
     }
 }
 
@@ -1,4 +1,4 @@
-use git2::{Repository, Status};
+use git2::{ErrorCode, Repository, Status};
 
 use super::{Context, Module, RootModuleConfig};
 
@@ -8,7 +8,8 @@ use crate::formatter::StringFormatter;
 use crate::segment::Segment;
 use std::sync::{Arc, RwLock};
 
-const ALL_STATUS_FORMAT: &str = "$conflicted$stashed$deleted$renamed$modified$staged$untracked";
+const ALL_STATUS_FORMAT: &str =
+    "$conflicted$stashed$deleted$renamed$copied$modified$staged$untracked";
 
 /// Creates a module with the Git branch in the current directory
 ///
@@ -23,13 +24,17 @@ const ALL_STATUS_FORMAT: &str = "$conflicted$stashed$deleted$renamed$modified$st
 ///   - `!` — There are file modifications in the working directory
 ///   - `+` — A new file has been added to the staging area
 ///   - `»` — A renamed file has been added to the staging area
+///   - `⧉` — A copied file has been added to the staging area
 ///   - `✘` — A file's deletion has been added to the staging area
+///
+/// If `max_files` is set and the index of the repo tracks more files than that,
+/// the working tree isn't scanned and only the conflicts, stash and ahead/behind counts are shown.
 pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let repo = context.get_repo().ok()?;
-    let info = Arc::new(GitStatusInfo::load(repo));
 
     let mut module = context.new_module("git_status");
     let config: GitStatusConfig = GitStatusConfig::try_load(module.config);
+    let info = Arc::new(GitStatusInfo::load(repo, &config));
 
     let parsed = StringFormatter::new(config.format).and_then(|formatter| {
         formatter
@@ -73,6 +78,9 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
                     "renamed" => info.get_renamed().and_then(|count| {
                         format_count(config.renamed, "git_status.renamed", count)
                     }),
+                    "copied" => info
+                        .get_copied()
+                        .and_then(|count| format_count(config.copied, "git_status.copied", count)),
                     "modified" => info.get_modified().and_then(|count| {
                         format_count(config.modified, "git_status.modified", count)
                     }),
@@ -108,15 +116,19 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
 
 struct GitStatusInfo<'a> {
     repo: &'a Repo,
+    max_files: usize,
+    ignore_submodules: bool,
     ahead_behind: RwLock<Option<Result<(usize, usize), git2::Error>>>,
     repo_status: RwLock<Option<Result<RepoStatus, git2::Error>>>,
     stashed_count: RwLock<Option<Result<usize, git2::Error>>>,
 }
 
 impl<'a> GitStatusInfo<'a> {
-    pub fn load(repo: &'a Repo) -> Self {
+    pub fn load(repo: &'a Repo, config: &GitStatusConfig) -> Self {
         Self {
             repo,
+            max_files: config.max_files,
+            ignore_submodules: config.ignore_submodules,
             ahead_behind: RwLock::new(None),
             repo_status: RwLock::new(None),
             stashed_count: RwLock::new(None),
@@ -133,8 +145,7 @@ impl<'a> GitStatusInfo<'a> {
     fn get_repository(&self) -> Option<Repository> {
         // bare repos don't have a branch name, so `repo.branch.as_ref` would return None,
         // but git treats "master" as the default branch name
-        let repo_root = self.repo.root.as_ref()?;
-        Repository::open(repo_root).ok()
+        self.repo.open()
     }
 
     pub fn get_ahead_behind(&self) -> Option<(usize, usize)> {
@@ -183,7 +194,11 @@ impl<'a> GitStatusInfo<'a> {
         {
             let mut repo = self.get_repository()?;
             let mut data = self.repo_status.write().unwrap();
-            *data = Some(get_repo_status(&mut repo));
+            *data = Some(get_repo_status(
+                &mut repo,
+                self.max_files,
+                self.ignore_submodules,
+            ));
             match data.as_ref().unwrap() {
                 Ok(repo_status) => Some(*repo_status),
                 Err(error) => {
@@ -234,6 +249,10 @@ impl<'a> GitStatusInfo<'a> {
         self.get_repo_status().map(|data| data.renamed)
     }
 
+    pub fn get_copied(&self) -> Option<usize> {
+        self.get_repo_status().map(|data| data.copied)
+    }
+
     pub fn get_modified(&self) -> Option<usize> {
         self.get_repo_status().map(|data| data.modified)
     }
@@ -248,11 +267,33 @@ impl<'a> GitStatusInfo<'a> {
 }
 
 /// Gets the number of files in various git states (staged, modified, deleted, etc...)
-fn get_repo_status(repository: &mut Repository) -> Result<RepoStatus, git2::Error> {
+fn get_repo_status(
+    repository: &mut Repository,
+    max_files: usize,
+    ignore_submodules: bool,
+) -> Result<RepoStatus, git2::Error> {
     let mut status_options = git2::StatusOptions::new();
 
     let mut repo_status = RepoStatus::default();
 
+    let index = repository.index()?;
+
+    // Conflicts are the unmerged entries of the index, there's no need to walk the working tree
+    repo_status.conflicted = index.conflicts()?.count();
+
+    // Reading the size of the index is cheap compared to walking the working tree
+    if max_files > 0 {
+        let file_count = index.len();
+        if file_count > max_files {
+            log::debug!(
+                "Skipping git status, the index has {} files (max_files = {})",
+                file_count,
+                max_files
+            );
+            return Ok(repo_status);
+        }
+    }
+
     match repository.config()?.get_entry("status.showUntrackedFiles") {
         Ok(entry) => status_options.include_untracked(entry.value() != Some("no")),
         _ => status_options.include_untracked(true),
@@ -260,6 +301,7 @@ fn get_repo_status(repository: &mut Repository) -> Result<RepoStatus, git2::Erro
     status_options
         .renames_from_rewrites(true)
         .renames_head_to_index(true)
+        .exclude_submodules(ignore_submodules)
         .include_unmodified(true);
 
     let statuses = repository.statuses(Some(&mut status_options))?;
@@ -268,10 +310,20 @@ fn get_repo_status(repository: &mut Repository) -> Result<RepoStatus, git2::Erro
         return Err(git2::Error::from_str("Repo has no status"));
     }
 
-    statuses
-        .iter()
-        .map(|s| s.status())
-        .for_each(|status| repo_status.add(status));
+    statuses.iter().for_each(|entry| {
+        let mut status = entry.status();
+        // Statuses only detect renames, a "renamed" file whose source is still in the index is a copy
+        let is_copy = status.is_index_renamed()
+            && entry
+                .head_to_index()
+                .and_then(|delta| delta.old_file().path())
+                .is_some_and(|source| index.get_path(source, 0).is_some());
+        if is_copy {
+            status.remove(Status::INDEX_RENAMED);
+            repo_status.copied += 1;
+        }
+        repo_status.add(status);
+    });
 
     Ok(repo_status)
 }
@@ -287,13 +339,19 @@ fn get_stashed_count(repository: &mut Repository) -> Result<usize, git2::Error>
 
 /// Compares the current branch with the branch it is tracking to determine how
 /// far ahead or behind it is in relation
+///
+/// A branch without an upstream is treated as being neither ahead nor behind.
 fn get_ahead_behind(
     repository: &Repository,
     branch_name: &str,
 ) -> Result<(usize, usize), git2::Error> {
     let branch_object = repository.revparse_single(branch_name)?;
     let tracking_branch_name = format!("{}@{{upstream}}", branch_name);
-    let tracking_object = repository.revparse_single(&tracking_branch_name)?;
+    let tracking_object = match repository.revparse_single(&tracking_branch_name) {
+        Ok(tracking_object) => tracking_object,
+        Err(error) if error.code() == ErrorCode::NotFound => return Ok((0, 0)),
+        Err(error) => return Err(error),
+    };
 
     let branch_oid = branch_object.id();
     let tracking_oid = tracking_object.id();
@@ -306,16 +364,13 @@ struct RepoStatus {
     conflicted: usize,
     deleted: usize,
     renamed: usize,
+    copied: usize,
     modified: usize,
     staged: usize,
     untracked: usize,
 }
 
 impl RepoStatus {
-    fn is_conflicted(status: Status) -> bool {
-        status.is_conflicted()
-    }
-
     fn is_deleted(status: Status) -> bool {
         status.is_wt_deleted() || status.is_index_deleted()
     }
@@ -337,7 +392,6 @@ impl RepoStatus {
     }
 
     fn add(&mut self, s: Status) {
-        self.conflicted += RepoStatus::is_conflicted(s) as usize;
         self.deleted += RepoStatus::is_deleted(s) as usize;
         self.renamed += RepoStatus::is_renamed(s) as usize;
         self.modified += RepoStatus::is_modified(s) as usize;
@@ -15,6 +15,9 @@ use crate::utils;
 ///     - Current directory contains a `.go-version` file
 ///     - Current directory contains a `Godeps` directory
 ///     - Current directory contains a file with the `.go` extension
+///
+/// With `version_from_gomod` enabled, the `go` directive of `go.mod` is shown instead
+/// of the installed toolchain version, if there is one.
 pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let is_go_project = context
         .try_begin_scan()?
@@ -48,7 +51,16 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
             })
             .map(|variable| match variable {
                 "version" => {
-                    format_go_version(&utils::exec_cmd("go", &["version"])?.stdout.as_str()).map(Ok)
+                    let gomod_version = if config.version_from_gomod {
+                        get_gomod_version(context)
+                    } else {
+                        None
+                    };
+                    gomod_version
+                        .or_else(|| {
+                            format_go_version(&context.exec_cmd("go", &["version"])?.stdout)
+                        })
+                        .map(Ok)
                 }
                 _ => None,
             })
@@ -83,13 +95,47 @@ fn format_go_version(go_stdout: &str) -> Option<String> {
     Some(format!("v{}", version))
 }
 
+fn get_gomod_version(context: &Context) -> Option<String> {
+    let gomod = utils::read_file(context.current_dir.join("go.mod")).ok()?;
+    parse_gomod_version(&gomod)
+}
+
+fn parse_gomod_version(gomod: &str) -> Option<String> {
+    // The go directive in go.mod looks like this:
+    // go 1.18
+    let version = gomod
+        .lines()
+        .map(str::trim)
+        .find_map(|line| line.strip_prefix("go "))?
+        // Remove any trailing comment
+        .split("//")
+        .next()?
+        .trim();
+
+    if version.is_empty() {
+        None
+    } else {
+        Some(format!("v{}", version))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::modules::utils::test::render_module;
     use ansi_term::Color;
     use std::fs::{self, File};
-    use std::io;
+    use std::io::{self, Write};
+    use std::str;
+
+    const GOMOD: &[u8] = b"module github.com/starship/starship
+
+go 1.18
+
+require (
+\tgithub.com/example/dependency v1.2.3
+)
+";
 
     #[test]
     fn folder_without_go_files() -> io::Result<()> {
@@ -126,6 +172,62 @@ mod tests {
         dir.close()
     }
 
+    #[test]
+    fn folder_with_go_mod_and_version_from_gomod() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let mut file = File::create(dir.path().join("go.mod"))?;
+        file.write_all(GOMOD)?;
+        file.sync_all()?;
+
+        let actual = render_module(
+            "golang",
+            dir.path(),
+            Some(toml::toml! {
+                [golang]
+                version_from_gomod = true
+            }),
+        );
+
+        let expected = Some(format!("via {} ", Color::Cyan.bold().paint("🐹 v1.18")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_go_mod_without_version_from_gomod() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let mut file = File::create(dir.path().join("go.mod"))?;
+        file.write_all(GOMOD)?;
+        file.sync_all()?;
+
+        let actual = render_module("golang", dir.path(), None);
+
+        let expected = Some(format!("via {} ", Color::Cyan.bold().paint("🐹 v1.12.1")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_go_mod_without_directive() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let mut file = File::create(dir.path().join("go.mod"))?;
+        file.write_all(b"module github.com/starship/starship\n")?;
+        file.sync_all()?;
+
+        let actual = render_module(
+            "golang",
+            dir.path(),
+            Some(toml::toml! {
+                [golang]
+                version_from_gomod = true
+            }),
+        );
+
+        let expected = Some(format!("via {} ", Color::Cyan.bold().paint("🐹 v1.12.1")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
     #[test]
     fn folder_with_go_sum() -> io::Result<()> {
         let dir = tempfile::tempdir()?;
@@ -200,4 +302,16 @@ mod tests {
         let input = "go version go1.12 darwin/amd64";
         assert_eq!(format_go_version(input), Some("v1.12".to_string()));
     }
+
+    #[test]
+    fn test_parse_gomod_version() {
+        let input = str::from_utf8(GOMOD).unwrap();
+        assert_eq!(parse_gomod_version(input), Some("v1.18".to_string()));
+
+        let input = "module example.com/foo\n\ngo 1.16 // minimum version\n";
+        assert_eq!(parse_gomod_version(input), Some("v1.16".to_string()));
+
+        let input = "module example.com/foo\n";
+        assert_eq!(parse_gomod_version(input), None);
+    }
 }
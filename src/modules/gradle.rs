@@ -0,0 +1,204 @@
+use super::{Context, Module, RootModuleConfig};
+
+use crate::configs::gradle::GradleConfig;
+use crate::formatter::StringFormatter;
+use crate::utils;
+
+/// Creates a module with the current Gradle version
+///
+/// Will display the Gradle version if any of the following criteria are met:
+///     - Current directory contains a `build.gradle` or `build.gradle.kts` file
+///     - Current directory contains a `settings.gradle` or `settings.gradle.kts` file
+///     - Current directory contains a `gradle` directory
+///
+/// The version of the Gradle wrapper is preferred over the one reported by `gradle`,
+/// which has to start up a JVM and is slow.
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let is_gradle_project = context
+        .try_begin_scan()?
+        .set_files(&[
+            "build.gradle",
+            "build.gradle.kts",
+            "settings.gradle",
+            "settings.gradle.kts",
+        ])
+        .set_folders(&["gradle"])
+        .is_match();
+
+    if !is_gradle_project {
+        return None;
+    }
+
+    let gradle_version = get_wrapper_gradle_version(context).or_else(|| {
+        let output = context.exec_cmd("gradle", &["--version"])?;
+        parse_gradle_version(&output.stdout)
+    })?;
+
+    let mut module = context.new_module("gradle");
+    let config = GradleConfig::try_load(module.config);
+    let parsed = StringFormatter::new(config.format).and_then(|formatter| {
+        formatter
+            .map_meta(|var, _| match var {
+                "symbol" => Some(config.symbol),
+                _ => None,
+            })
+            .map_style(|variable| match variable {
+                "style" => Some(Ok(config.style)),
+                _ => None,
+            })
+            .map(|variable| match variable {
+                "version" => Some(Ok(&gradle_version)),
+                _ => None,
+            })
+            .parse(None)
+    });
+
+    module.set_segments(match parsed {
+        Ok(segments) => segments,
+        Err(error) => {
+            log::warn!("Error in module `gradle`:\n{}", error);
+            return None;
+        }
+    });
+
+    Some(module)
+}
+
+fn get_wrapper_gradle_version(context: &Context) -> Option<String> {
+    let properties = utils::read_file(
+        context
+            .current_dir
+            .join("gradle/wrapper/gradle-wrapper.properties"),
+    )
+    .ok()?;
+    parse_wrapper_gradle_version(&properties)
+}
+
+/// Extracts the version from the `distributionUrl` of the wrapper properties, e.g.
+/// `distributionUrl=https\://services.gradle.org/distributions/gradle-7.2-bin.zip`
+fn parse_wrapper_gradle_version(properties: &str) -> Option<String> {
+    let url = properties.lines().find_map(|line| {
+        let (key, value) = line.split_once('=')?;
+        if key.trim() == "distributionUrl" {
+            Some(value.trim())
+        } else {
+            None
+        }
+    })?;
+
+    let version = url
+        // get "gradle-7.2-bin.zip"
+        .rsplit('/')
+        .next()?
+        .strip_prefix("gradle-")?
+        // get "7.2"
+        .rsplit_once('-')?
+        .0;
+
+    if version.is_empty() {
+        return None;
+    }
+
+    Some(format!("v{}", version))
+}
+
+fn parse_gradle_version(gradle_stdout: &str) -> Option<String> {
+    // gradle --version output looks like this:
+    //
+    // ------------------------------------------------------------
+    // Gradle 7.2
+    // ------------------------------------------------------------
+    let version = gradle_stdout
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("Gradle "))?
+        .trim();
+
+    Some(format!("v{}", version))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::utils::test::render_module_with_commands;
+    use ansi_term::Color;
+    use std::fs::{self, File};
+    use std::io;
+
+    const GRADLE_VERSION: &[(&str, &str)] = &[("gradle --version", "\n------------------------------------------------------------\nGradle 7.2\n------------------------------------------------------------\n")];
+
+    #[test]
+    fn folder_without_gradle_files() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+
+        let actual = render_module_with_commands("gradle", dir.path(), None, GRADLE_VERSION);
+
+        let expected = None;
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_build_gradle() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join("build.gradle"))?.sync_all()?;
+
+        let actual = render_module_with_commands("gradle", dir.path(), None, GRADLE_VERSION);
+
+        let expected = Some(format!("via {} ", Color::Fixed(14).bold().paint("🅶 v7.2")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_gradle_wrapper() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join("build.gradle.kts"))?.sync_all()?;
+        let wrapper_dir = dir.path().join("gradle/wrapper");
+        fs::create_dir_all(&wrapper_dir)?;
+        fs::write(
+            wrapper_dir.join("gradle-wrapper.properties"),
+            "distributionBase=GRADLE_USER_HOME\n\
+             distributionPath=wrapper/dists\n\
+             distributionUrl=https\\://services.gradle.org/distributions/gradle-6.8.3-all.zip\n\
+             zipStoreBase=GRADLE_USER_HOME\n\
+             zipStorePath=wrapper/dists\n",
+        )?;
+
+        let actual = render_module_with_commands("gradle", dir.path(), None, GRADLE_VERSION);
+
+        let expected = Some(format!(
+            "via {} ",
+            Color::Fixed(14).bold().paint("🅶 v6.8.3")
+        ));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn test_parse_wrapper_gradle_version() {
+        let input =
+            "distributionUrl=https\\://services.gradle.org/distributions/gradle-7.2-bin.zip";
+        assert_eq!(
+            parse_wrapper_gradle_version(input),
+            Some("v7.2".to_string())
+        );
+
+        let input = "distributionUrl = https\\://example.com/gradle-7.3-rc-1-all.zip\n";
+        assert_eq!(
+            parse_wrapper_gradle_version(input),
+            Some("v7.3-rc-1".to_string())
+        );
+
+        let input = "distributionBase=GRADLE_USER_HOME";
+        assert_eq!(parse_wrapper_gradle_version(input), None);
+    }
+
+    #[test]
+    fn test_parse_gradle_version() {
+        let input = "\n------------------------------------------------------------\nGradle 7.2\n------------------------------------------------------------\n";
+        assert_eq!(parse_gradle_version(input), Some("v7.2".to_string()));
+
+        let input = "gradle: command not found";
+        assert_eq!(parse_gradle_version(input), None);
+    }
+}
@@ -0,0 +1,170 @@
+use super::{Context, Module, RootModuleConfig};
+
+use crate::configs::haskell::HaskellConfig;
+use crate::formatter::StringFormatter;
+
+/// Creates a module with the current GHC version
+///
+/// Will display the GHC version if any of the following criteria are met:
+///     - Current directory contains a `stack.yaml` file
+///     - Current directory contains a file with the `.cabal`, `.hs` or `.lhs` extension
+///
+/// Stack projects report the GHC version selected by their resolver, or the version of `ghc`
+/// if `stack` isn't installed or fails.
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let is_haskell_project = context
+        .try_begin_scan()?
+        .set_files(&["stack.yaml"])
+        .set_extensions(&["cabal", "hs", "lhs"])
+        .is_match();
+
+    if !is_haskell_project {
+        return None;
+    }
+
+    let is_stack_project = context
+        .try_begin_scan()?
+        .set_files(&["stack.yaml"])
+        .is_match();
+
+    let stack_ghc_version = if is_stack_project {
+        context.exec_cmd("stack", &["ghc", "--", "--numeric-version"])
+    } else {
+        None
+    };
+    let ghc_version =
+        stack_ghc_version.or_else(|| context.exec_cmd("ghc", &["--numeric-version"]))?;
+
+    let mut module = context.new_module("haskell");
+    let config: HaskellConfig = HaskellConfig::try_load(module.config);
+
+    let parsed = StringFormatter::new(config.format).and_then(|formatter| {
+        formatter
+            .map_meta(|var, _| match var {
+                "symbol" => Some(config.symbol),
+                _ => None,
+            })
+            .map_style(|variable| match variable {
+                "style" => Some(Ok(config.style)),
+                _ => None,
+            })
+            .map(|variable| match variable {
+                "version" => format_ghc_version(&ghc_version.stdout).map(Ok),
+                _ => None,
+            })
+            .parse(None)
+    });
+
+    module.set_segments(match parsed {
+        Ok(segments) => segments,
+        Err(error) => {
+            log::warn!("Error in module `haskell`:\n{}", error);
+            return None;
+        }
+    });
+
+    Some(module)
+}
+
+fn format_ghc_version(ghc_version: &str) -> Option<String> {
+    // `--numeric-version` prints only the version, e.g. "8.10.4"
+    let version = ghc_version.trim();
+
+    if version.is_empty() {
+        return None;
+    }
+
+    Some(format!("v{}", version))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::utils::test::{render_module, render_module_with_commands};
+    use ansi_term::Color;
+    use std::fs::File;
+    use std::io;
+
+    #[test]
+    fn test_format_ghc_version() {
+        assert_eq!(format_ghc_version("8.10.4\n"), Some("v8.10.4".to_string()));
+        assert_eq!(format_ghc_version(""), None);
+    }
+
+    #[test]
+    fn folder_without_haskell_files() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+
+        let actual = render_module("haskell", dir.path(), None);
+
+        let expected = None;
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_stack_yaml_uses_stack() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join("stack.yaml"))?.sync_all()?;
+        File::create(dir.path().join("Main.hs"))?.sync_all()?;
+
+        let actual = render_module("haskell", dir.path(), None);
+
+        let expected = Some(format!("via {} ", Color::Purple.bold().paint("λ v8.10.4")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_stack_yaml_without_stack_uses_ghc() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join("stack.yaml"))?.sync_all()?;
+
+        let actual = render_module_with_commands(
+            "haskell",
+            dir.path(),
+            None,
+            &[("ghc --numeric-version", "8.8.4\n")],
+        );
+
+        let expected = Some(format!("via {} ", Color::Purple.bold().paint("λ v8.8.4")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_cabal_file_uses_ghc() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join("project.cabal"))?.sync_all()?;
+
+        let actual = render_module("haskell", dir.path(), None);
+
+        let expected = Some(format!("via {} ", Color::Purple.bold().paint("λ v8.8.4")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_hs_file_uses_ghc() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join("Main.hs"))?.sync_all()?;
+
+        let actual = render_module("haskell", dir.path(), None);
+
+        let expected = Some(format!("via {} ", Color::Purple.bold().paint("λ v8.8.4")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_lhs_file_uses_ghc() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join("Main.lhs"))?.sync_all()?;
+
+        let actual = render_module("haskell", dir.path(), None);
+
+        let expected = Some(format!("via {} ", Color::Purple.bold().paint("λ v8.8.4")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+}
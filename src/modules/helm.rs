@@ -2,7 +2,6 @@ use super::{Context, Module, RootModuleConfig};
 
 use crate::configs::helm::HelmConfig;
 use crate::formatter::StringFormatter;
-use crate::utils;
 
 /// Creates a module with the current Helm version
 ///
@@ -33,7 +32,8 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
             })
             .map(|variable| match variable {
                 "version" => format_helm_version(
-                    &utils::exec_cmd("helm", &["version", "--short", "--client"])?
+                    &context
+                        .exec_cmd("helm", &["version", "--short", "--client"])?
                         .stdout
                         .as_str(),
                 )
@@ -59,16 +59,19 @@ fn format_helm_version(helm_stdout: &str) -> Option<String> {
     // v3.1.1+gafe7058
     // `helm version --short --client` output looks like this for Helm 2:
     // Client: v2.16.9+g8ad7037
+    // Some Helm builds print deprecation warnings before the version, e.g.:
+    // WARNING: "kubernetes.io/..." is deprecated
 
     Some(
         helm_stdout
-            // split into ["v3.1.1","gafe7058"] or ["Client: v3.1.1","gafe7058"]
+            .lines()
+            // return "v3.1.1+gafe7058", skipping any warnings and the "Client: " prefix
+            .map(|line| line.trim().trim_start_matches("Client: "))
+            .find(|line| line.starts_with('v'))?
+            // split into ["v3.1.1","gafe7058"]
             .splitn(2, '+')
-            // return "v3.1.1" or "Client: v3.1.1"
-            .next()?
-            // return "v3.1.1" or " v3.1.1"
-            .trim_start_matches("Client: ")
             // return "v3.1.1"
+            .next()?
             .trim()
             .to_owned(),
     )
@@ -124,4 +127,16 @@ mod tests {
         assert_eq!(format_helm_version(helm_2), Some("v2.16.9".to_string()));
         assert_eq!(format_helm_version(helm_3), Some("v3.1.1".to_string()));
     }
+
+    #[test]
+    fn test_format_helm_version_with_deprecation_warning() {
+        let helm_3 = "WARNING: Kubernetes configuration file is group-readable. This is insecure.\nv3.5.2+g167aac7\n";
+        assert_eq!(format_helm_version(helm_3), Some("v3.5.2".to_string()));
+    }
+
+    #[test]
+    fn test_format_helm_version_without_version() {
+        let output = "Error: unknown flag: --client";
+        assert_eq!(format_helm_version(output), None);
+    }
 }
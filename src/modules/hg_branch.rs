@@ -1,9 +1,8 @@
-use unicode_segmentation::UnicodeSegmentation;
-
 use super::{Context, Module, RootModuleConfig};
 
 use crate::configs::hg_branch::HgBranchConfig;
 use crate::formatter::StringFormatter;
+use crate::utils;
 
 /// Creates a module with the Hg bookmark or branch in the current directory
 ///
@@ -33,14 +32,11 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let branch_name =
         get_hg_current_bookmark(context).unwrap_or_else(|| get_hg_branch_name(context));
 
-    let truncated_graphemes = get_graphemes(&branch_name, len);
-    // The truncation symbol should only be added if we truncated
-    let truncated_and_symbol = if len < graphemes_len(&branch_name) {
-        let truncation_symbol = get_graphemes(config.truncation_symbol, 1);
-        truncated_graphemes + &truncation_symbol
-    } else {
-        truncated_graphemes
-    };
+    let truncated_and_symbol = utils::truncate_graphemes(
+        &branch_name,
+        len,
+        utils::first_grapheme(config.truncation_symbol),
+    );
 
     let parsed = StringFormatter::new(config.format).and_then(|formatter| {
         formatter
@@ -81,14 +77,3 @@ fn get_hg_current_bookmark(ctx: &Context) -> Option<String> {
         .map(|s| s.trim().into())
         .ok()
 }
-
-fn get_graphemes(text: &str, length: usize) -> String {
-    UnicodeSegmentation::graphemes(text, true)
-        .take(length)
-        .collect::<Vec<&str>>()
-        .concat()
-}
-
-fn graphemes_len(text: &str) -> usize {
-    UnicodeSegmentation::graphemes(&text[..], true).count()
-}
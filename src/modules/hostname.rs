@@ -11,13 +11,13 @@ use crate::formatter::StringFormatter;
 ///
 /// Will display the hostname if all of the following criteria are met:
 ///     - hostname.disabled is absent or false
-///     - hostname.ssh_only is false OR the user is currently connected as an SSH session (`$SSH_CONNECTION`)
+///     - hostname.ssh_only is false OR the user is currently connected as an SSH session
+///       (`$SSH_CONNECTION` or `$SSH_TTY` is set)
 pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let mut module = context.new_module("hostname");
     let config: HostnameConfig = HostnameConfig::try_load(module.config);
 
-    let ssh_connection = env::var("SSH_CONNECTION").ok();
-    if config.ssh_only && ssh_connection.is_none() {
+    if config.ssh_only && !is_ssh_session() {
         return None;
     }
 
@@ -31,17 +31,7 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
         }
     };
 
-    //rustc doesn't let you do an "if" and an "if let" in the same if statement
-    // if this changes in the future this can become a lot cleaner
-    let host = if config.trim_at != "" {
-        if let Some(index) = host.find(config.trim_at) {
-            host.split_at(index).0
-        } else {
-            host.as_ref()
-        }
-    } else {
-        host.as_ref()
-    };
+    let host = trim_hostname(&host, config.trim_at);
 
     let parsed = StringFormatter::new(config.format).and_then(|formatter| {
         formatter
@@ -66,3 +56,36 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
 
     Some(module)
 }
+
+fn is_ssh_session() -> bool {
+    env::var_os("SSH_CONNECTION").is_some() || env::var_os("SSH_TTY").is_some()
+}
+
+/// Cut the hostname off at the first occurrence of `trim_at`, if it is not empty
+fn trim_hostname<'a>(host: &'a str, trim_at: &str) -> &'a str {
+    if trim_at.is_empty() {
+        return host;
+    }
+
+    match host.find(trim_at) {
+        Some(index) => &host[..index],
+        None => host,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trim_hostname() {
+        assert_eq!(trim_hostname("rocket.example.com", "."), "rocket");
+        assert_eq!(trim_hostname("rocket.example.com", ".example"), "rocket");
+        assert_eq!(
+            trim_hostname("rocket.example.com", ""),
+            "rocket.example.com"
+        );
+        assert_eq!(trim_hostname("rocket", "."), "rocket");
+        assert_eq!(trim_hostname(".rocket", "."), "");
+    }
+}
@@ -2,10 +2,11 @@ use crate::configs::java::JavaConfig;
 use crate::formatter::StringFormatter;
 
 use super::{Context, Module, RootModuleConfig};
-
 use crate::utils;
 
 use regex::Regex;
+use std::path::Path;
+
 const JAVA_VERSION_PATTERN: &str = "(?P<version>[\\d\\.]+)[^\\s]*\\s(?:built|from)";
 
 /// Creates a module with the current Java version
@@ -13,6 +14,9 @@ const JAVA_VERSION_PATTERN: &str = "(?P<version>[\\d\\.]+)[^\\s]*\\s(?:built|fro
 /// Will display the Java version if any of the following criteria are met:
 ///     - Current directory contains a file with a `.java`, `.class`, `.gradle` or `.jar` extension
 ///     - Current directory contains a `pom.xml`, `build.gradle.kts`, `build.sbt` or `.java-version` file
+///
+/// When `JAVA_HOME` is set, the version is read from its `release` file so that no JVM has to be
+/// started. Otherwise it falls back to `java -Xinternalversion`.
 pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let is_java_project = context
         .try_begin_scan()?
@@ -24,7 +28,7 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
         return None;
     }
 
-    let java_version = get_java_version()?;
+    let java_version = get_java_version(context)?;
 
     let mut module = context.new_module("java");
     let config: JavaConfig = JavaConfig::try_load(module.config);
@@ -57,13 +61,20 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     Some(module)
 }
 
-fn get_java_version() -> Option<String> {
-    let java_command = match std::env::var("JAVA_HOME") {
-        Ok(java_home) => format!("{}/bin/java", java_home),
-        Err(_) => String::from("java"),
+fn get_java_version(context: &Context) -> Option<String> {
+    let java_home = std::env::var("JAVA_HOME")
+        .ok()
+        .filter(|java_home| !java_home.is_empty());
+    if let Some(version) = java_home.as_deref().and_then(get_java_home_version) {
+        return Some(version);
+    }
+
+    let java_command = match java_home {
+        Some(java_home) => format!("{}/bin/java", java_home),
+        None => String::from("java"),
     };
 
-    let output = utils::exec_cmd(&java_command.as_str(), &["-Xinternalversion"])?;
+    let output = context.exec_cmd(&java_command, &["-Xinternalversion"])?;
     let java_version = if output.stdout.is_empty() {
         output.stderr
     } else {
@@ -73,6 +84,30 @@ fn get_java_version() -> Option<String> {
     parse_java_version(&java_version)
 }
 
+fn get_java_home_version(java_home: &str) -> Option<String> {
+    let release = utils::read_file(Path::new(java_home).join("release")).ok()?;
+    parse_java_release(&release)
+}
+
+/// Parses the `JAVA_VERSION="..."` line of a JDK `release` file, which holds either a legacy
+/// version string such as `1.8.0_292` or a modern one such as `17.0.1`
+fn parse_java_release(release: &str) -> Option<String> {
+    let version = release
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("JAVA_VERSION="))?
+        .trim_matches('"')
+        .split(&['_', '+', '-'][..])
+        .next()?;
+
+    let is_version = utils::is_dotted_version(version);
+
+    if is_version {
+        Some(format!("v{}", version))
+    } else {
+        None
+    }
+}
+
 fn parse_java_version(java_version: &str) -> Option<String> {
     let re = Regex::new(JAVA_VERSION_PATTERN).ok()?;
     let captures = re.captures(java_version)?;
@@ -147,6 +182,22 @@ mod tests {
         assert_eq!(parse_java_version(java_11), Some("v11.0.4".to_string()));
     }
 
+    #[test]
+    fn test_parse_java_version_17() {
+        let java_17 = "OpenJDK 64-Bit Server VM (17.0.1+12) for linux-amd64 JRE (17.0.1+12), built on Oct 19 2021 00:00:00 by \"openjdk\" with gcc 10.3.0";
+        assert_eq!(parse_java_version(java_17), Some("v17.0.1".to_string()));
+    }
+
+    #[test]
+    fn test_parse_java_release() {
+        let java_8 = "JAVA_VERSION=\"1.8.0_292\"\nOS_NAME=\"Linux\"\n";
+        let java_17 = "IMPLEMENTOR=\"Eclipse Adoptium\"\nJAVA_VERSION=\"17.0.1\"\nJAVA_VERSION_DATE=\"2021-10-19\"\n";
+        assert_eq!(parse_java_release(java_8), Some("v1.8.0".to_string()));
+        assert_eq!(parse_java_release(java_17), Some("v17.0.1".to_string()));
+        assert_eq!(parse_java_release("JAVA_VERSION=\"\"\n"), None);
+        assert_eq!(parse_java_release("OS_NAME=\"Linux\"\n"), None);
+    }
+
     #[test]
     fn test_parse_java_version_unknown() {
         let unknown_jre = "Unknown JRE";
@@ -8,14 +8,8 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let mut module = context.new_module("jobs");
     let config = JobsConfig::try_load(module.config);
 
-    let props = &context.properties;
-    let num_of_jobs = props
-        .get("jobs")
-        .unwrap_or(&"0".into())
-        .trim()
-        .parse::<i64>()
-        .ok()?;
-    if num_of_jobs == 0 {
+    let num_of_jobs = context.jobs;
+    if num_of_jobs < config.symbol_threshold {
         return None;
     }
 
@@ -0,0 +1,153 @@
+use super::{Context, Module, RootModuleConfig};
+
+use crate::configs::kotlin::KotlinConfig;
+use crate::formatter::StringFormatter;
+
+use regex::Regex;
+const KOTLIN_VERSION_PATTERN: &str = "(?P<version>\\d+\\.\\d+(\\.\\d+)?)";
+
+/// Creates a module with the current Kotlin version
+///
+/// Will display the Kotlin version if any of the following criteria are met:
+///     - Current directory contains a file with a `.kt` or `.kts` extension
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let is_kotlin_project = context
+        .try_begin_scan()?
+        .set_extensions(&["kt", "kts"])
+        .is_match();
+
+    if !is_kotlin_project {
+        return None;
+    }
+
+    let mut module = context.new_module("kotlin");
+    let config = KotlinConfig::try_load(module.config);
+
+    let kotlin_version = get_kotlin_version(context, config.kotlin_binary)?;
+
+    let parsed = StringFormatter::new(config.format).and_then(|formatter| {
+        formatter
+            .map_meta(|variable, _| match variable {
+                "symbol" => Some(config.symbol),
+                _ => None,
+            })
+            .map_style(|variable| match variable {
+                "style" => Some(Ok(config.style)),
+                _ => None,
+            })
+            .map(|variable| match variable {
+                "version" => Some(Ok(&kotlin_version)),
+                _ => None,
+            })
+            .parse(None)
+    });
+
+    module.set_segments(match parsed {
+        Ok(segments) => segments,
+        Err(error) => {
+            log::warn!("Error in module `kotlin`:\n{}", error);
+            return None;
+        }
+    });
+
+    Some(module)
+}
+
+fn get_kotlin_version(context: &Context, kotlin_binary: &str) -> Option<String> {
+    let output = context.exec_cmd(kotlin_binary, &["-version"])?;
+    // Both `kotlin` and `kotlinc` print their version to stderr
+    let kotlin_version = if output.stderr.is_empty() {
+        output.stdout
+    } else {
+        output.stderr
+    };
+
+    parse_kotlin_version(&kotlin_version)
+}
+
+fn parse_kotlin_version(kotlin_version: &str) -> Option<String> {
+    // kotlin -version output looks like this:
+    // Kotlin version 1.5.31-release-548 (JRE 11.0.12+7)
+    //
+    // kotlinc -version output looks like this:
+    // info: kotlinc-jvm 1.5.31 (JRE 11.0.12+7)
+    let re = Regex::new(KOTLIN_VERSION_PATTERN).ok()?;
+    let captures = re.captures(kotlin_version)?;
+    let version = &captures["version"];
+
+    Some(format!("v{}", version))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::utils::test::render_module;
+    use ansi_term::Color;
+    use std::fs::File;
+    use std::io;
+
+    #[test]
+    fn folder_without_kotlin_files() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+
+        let actual = render_module("kotlin", dir.path(), None);
+
+        let expected = None;
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_kotlin_file() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join("main.kt"))?.sync_all()?;
+
+        let actual = render_module("kotlin", dir.path(), None);
+
+        let expected = Some(format!("via {} ", Color::Blue.bold().paint("🅺 v1.5.31")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_kotlin_script_file() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join("build.gradle.kts"))?.sync_all()?;
+
+        let actual = render_module("kotlin", dir.path(), None);
+
+        let expected = Some(format!("via {} ", Color::Blue.bold().paint("🅺 v1.5.31")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_kotlin_file_and_kotlinc_binary() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join("main.kt"))?.sync_all()?;
+
+        let actual = render_module(
+            "kotlin",
+            dir.path(),
+            Some(toml::toml! {
+                [kotlin]
+                kotlin_binary = "kotlinc"
+            }),
+        );
+
+        let expected = Some(format!("via {} ", Color::Blue.bold().paint("🅺 v1.5.30")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn test_parse_kotlin_version() {
+        let input = "Kotlin version 1.5.31-release-548 (JRE 11.0.12+7)";
+        assert_eq!(parse_kotlin_version(input), Some("v1.5.31".to_string()));
+
+        let input = "info: kotlinc-jvm 1.5.30 (JRE 11.0.12+7)";
+        assert_eq!(parse_kotlin_version(input), Some("v1.5.30".to_string()));
+
+        assert_eq!(parse_kotlin_version("kotlin"), None);
+    }
+}
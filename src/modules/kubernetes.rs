@@ -62,6 +62,12 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
                 return None;
             };
 
+            let display_context = utils::alias_or_truncate(
+                &kube_ctx,
+                &config.context_aliases,
+                config.truncation_length,
+            );
+
             let parsed = StringFormatter::new(config.format).and_then(|formatter| {
                 formatter
                     .map_meta(|variable, _| match variable {
@@ -73,10 +79,7 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
                         _ => None,
                     })
                     .map(|variable| match variable {
-                        "context" => match config.context_aliases.get(&kube_ctx) {
-                            None => Some(Ok(kube_ctx.as_str())),
-                            Some(&alias) => Some(Ok(alias)),
-                        },
+                        "context" => Some(Ok(display_context.as_str())),
                         _ => None,
                     })
                     .map(|variable| match variable {
@@ -15,3 +15,20 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
 
     Some(module)
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::modules::utils::test::render_module;
+    use std::io;
+
+    #[test]
+    fn produces_a_newline() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+
+        let actual = render_module("line_break", dir.path(), None);
+
+        let expected = Some(String::from("\n"));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+}
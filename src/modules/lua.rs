@@ -0,0 +1,153 @@
+use super::{Context, Module, RootModuleConfig};
+
+use crate::configs::lua::LuaConfig;
+use crate::formatter::StringFormatter;
+
+/// Creates a module with the current Lua version
+///
+/// Will display the Lua version if any of the following criteria are met:
+///     - Current directory contains a `.lua-version` file
+///     - Current directory contains a `lua` directory
+///     - Current directory contains a file with the `.lua` extension
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let is_lua_project = context
+        .try_begin_scan()?
+        .set_files(&[".lua-version"])
+        .set_folders(&["lua"])
+        .set_extensions(&["lua"])
+        .is_match();
+
+    if !is_lua_project {
+        return None;
+    }
+
+    let lua_version = get_lua_version(context)?;
+
+    let mut module = context.new_module("lua");
+    let config = LuaConfig::try_load(module.config);
+
+    let parsed = StringFormatter::new(config.format).and_then(|formatter| {
+        formatter
+            .map_meta(|variable, _| match variable {
+                "symbol" => Some(config.symbol),
+                _ => None,
+            })
+            .map_style(|variable| match variable {
+                "style" => Some(Ok(config.style)),
+                _ => None,
+            })
+            .map(|variable| match variable {
+                "version" => Some(Ok(&lua_version)),
+                _ => None,
+            })
+            .parse(None)
+    });
+
+    module.set_segments(match parsed {
+        Ok(segments) => segments,
+        Err(error) => {
+            log::warn!("Error in module `lua`:\n{}", error);
+            return None;
+        }
+    });
+
+    Some(module)
+}
+
+fn get_lua_version(context: &Context) -> Option<String> {
+    let output = context
+        .exec_cmd("lua", &["-v"])
+        .or_else(|| context.exec_cmd("luajit", &["-v"]))?;
+
+    // Lua 5.1 and older print their version to stderr
+    let version_output = if output.stdout.is_empty() {
+        output.stderr
+    } else {
+        output.stdout
+    };
+
+    format_lua_version(&version_output)
+}
+
+fn format_lua_version(lua_stdout: &str) -> Option<String> {
+    // lua -v output looks like this:
+    // Lua 5.4.2  Copyright (C) 1994-2020 Lua.org, PUC-Rio
+    //
+    // luajit -v output looks like this:
+    // LuaJIT 2.0.5 -- Copyright (C) 2005-2017 Mike Pall. http://luajit.org/
+    let version = lua_stdout
+        // split into ["Lua", "5.4.2", "Copyright", ...]
+        .split_whitespace()
+        // return "5.4.2"
+        .nth(1)?;
+
+    Some(format!("v{}", version))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::utils::test::render_module;
+    use ansi_term::Color;
+    use std::fs::{self, File};
+    use std::io;
+
+    #[test]
+    fn folder_without_lua_files() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join("lua.txt"))?.sync_all()?;
+
+        let actual = render_module("lua", dir.path(), None);
+
+        let expected = None;
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_lua_file() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join("main.lua"))?.sync_all()?;
+
+        let actual = render_module("lua", dir.path(), None);
+
+        let expected = Some(format!("via {} ", Color::Blue.bold().paint("🌙 v5.4.2")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_lua_version() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join(".lua-version"))?.sync_all()?;
+
+        let actual = render_module("lua", dir.path(), None);
+
+        let expected = Some(format!("via {} ", Color::Blue.bold().paint("🌙 v5.4.2")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_lua_folder() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        fs::create_dir_all(dir.path().join("lua"))?;
+
+        let actual = render_module("lua", dir.path(), None);
+
+        let expected = Some(format!("via {} ", Color::Blue.bold().paint("🌙 v5.4.2")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn test_format_lua_version() {
+        let input = "Lua 5.4.2  Copyright (C) 1994-2020 Lua.org, PUC-Rio";
+        assert_eq!(format_lua_version(input), Some("v5.4.2".to_string()));
+
+        let input = "LuaJIT 2.0.5 -- Copyright (C) 2005-2017 Mike Pall. http://luajit.org/";
+        assert_eq!(format_lua_version(input), Some("v2.0.5".to_string()));
+
+        assert_eq!(format_lua_version(""), None);
+    }
+}
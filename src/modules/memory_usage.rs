@@ -29,7 +29,7 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     // TODO: Update when v1.0 printing refactor is implemented to only
     // print escapes in a prompt context.
     let pct_sign = match context.shell {
-        Shell::Zsh => "%%", // % is an escape in zsh, see PROMPT in `man zshmisc`
+        Shell::Zsh | Shell::Tcsh => "%%", // % is an escape in zsh and tcsh, see PROMPT in `man zshmisc`
         _ => "%",
     };
 
@@ -37,9 +37,12 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
         return None;
     }
 
-    let system = sysinfo::System::new_with_specifics(RefreshKind::new().with_memory());
-    let used_memory_kib = system.get_used_memory();
-    let total_memory_kib = system.get_total_memory();
+    let MemoryInfo {
+        used_memory_kib,
+        total_memory_kib,
+        used_swap_kib,
+        total_swap_kib,
+    } = get_memory_info();
     let ram_used = (used_memory_kib as f64 / total_memory_kib as f64) * 100.;
     let ram_pct = format_pct(ram_used, pct_sign);
 
@@ -49,8 +52,6 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     }
 
     let ram = format_usage_total(used_memory_kib, total_memory_kib);
-    let total_swap_kib = system.get_total_swap();
-    let used_swap_kib = system.get_used_swap();
     let percent_swap_used = (used_swap_kib as f64 / total_swap_kib as f64) * 100.;
     let swap_pct = format_pct(percent_swap_used, pct_sign);
     let swap = format_usage_total(used_swap_kib, total_swap_kib);
@@ -86,3 +87,115 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
 
     Some(module)
 }
+
+/// Memory and swap usage of the system, in KiB
+#[derive(Debug, PartialEq)]
+struct MemoryInfo {
+    used_memory_kib: u64,
+    total_memory_kib: u64,
+    used_swap_kib: u64,
+    total_swap_kib: u64,
+}
+
+#[cfg(target_os = "linux")]
+fn get_memory_info() -> MemoryInfo {
+    // Reading `/proc/meminfo` directly is cheaper than refreshing sysinfo
+    crate::utils::read_file("/proc/meminfo")
+        .ok()
+        .and_then(|meminfo| parse_meminfo(&meminfo))
+        .unwrap_or_else(get_sysinfo_memory_info)
+}
+
+#[cfg(not(target_os = "linux"))]
+fn get_memory_info() -> MemoryInfo {
+    get_sysinfo_memory_info()
+}
+
+fn get_sysinfo_memory_info() -> MemoryInfo {
+    let system = sysinfo::System::new_with_specifics(RefreshKind::new().with_memory());
+    MemoryInfo {
+        used_memory_kib: system.get_used_memory(),
+        total_memory_kib: system.get_total_memory(),
+        used_swap_kib: system.get_used_swap(),
+        total_swap_kib: system.get_total_swap(),
+    }
+}
+
+/// Parse the contents of `/proc/meminfo`
+///
+/// Memory that is available for starting new applications (`MemAvailable`) is not
+/// counted as used. Kernels older than 3.14 don't report it, so `MemFree` is used instead.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn parse_meminfo(meminfo: &str) -> Option<MemoryInfo> {
+    let get_value = |key: &str| -> Option<u64> {
+        meminfo.lines().find_map(|line| {
+            let (name, value) = line.split_at(line.find(':')?);
+            if name != key {
+                return None;
+            }
+            value[1..].trim().trim_end_matches("kB").trim().parse().ok()
+        })
+    };
+
+    let total_memory_kib = get_value("MemTotal")?;
+    let available_memory_kib = get_value("MemAvailable").or_else(|| get_value("MemFree"))?;
+    let total_swap_kib = get_value("SwapTotal").unwrap_or(0);
+    let free_swap_kib = get_value("SwapFree").unwrap_or(0);
+
+    Some(MemoryInfo {
+        used_memory_kib: total_memory_kib.saturating_sub(available_memory_kib),
+        total_memory_kib,
+        used_swap_kib: total_swap_kib.saturating_sub(free_swap_kib),
+        total_swap_kib,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MEMINFO: &str = "MemTotal:       16314208 kB
+MemFree:         1893164 kB
+MemAvailable:    8157104 kB
+Buffers:          530952 kB
+Cached:          5905644 kB
+SwapCached:        10516 kB
+SwapTotal:       2097148 kB
+SwapFree:        1572860 kB
+";
+
+    #[test]
+    fn test_parse_meminfo() {
+        let expected = MemoryInfo {
+            used_memory_kib: 8_157_104,
+            total_memory_kib: 16_314_208,
+            used_swap_kib: 524_288,
+            total_swap_kib: 2_097_148,
+        };
+        assert_eq!(parse_meminfo(MEMINFO), Some(expected));
+    }
+
+    #[test]
+    fn test_parse_meminfo_without_available_or_swap() {
+        let meminfo = "MemTotal:        2048000 kB\nMemFree:          512000 kB\n";
+        let expected = MemoryInfo {
+            used_memory_kib: 1_536_000,
+            total_memory_kib: 2_048_000,
+            used_swap_kib: 0,
+            total_swap_kib: 0,
+        };
+        assert_eq!(parse_meminfo(meminfo), Some(expected));
+    }
+
+    #[test]
+    fn test_parse_meminfo_invalid() {
+        assert_eq!(parse_meminfo(""), None);
+        assert_eq!(parse_meminfo("MemTotal: lots\nMemFree: 1 kB\n"), None);
+    }
+
+    #[test]
+    fn test_format_usage_total() {
+        assert_eq!(format_usage_total(8_157_104, 16_314_208), "8GiB/16GiB");
+        assert_eq!(format_pct(50.0, "%"), "50%");
+    }
+}
@@ -1,5 +1,6 @@
 // While adding out new module add out module to src/module.rs ALL_MODULES const array also.
 mod aws;
+mod buf;
 mod character;
 mod cmake;
 mod cmd_duration;
@@ -7,7 +8,9 @@ mod conda;
 mod crystal;
 pub(crate) mod custom;
 mod dart;
+mod deno;
 mod directory;
+mod direnv;
 mod docker_context;
 mod dotnet;
 mod elixir;
@@ -17,35 +20,48 @@ mod erlang;
 mod gcloud;
 mod git_branch;
 mod git_commit;
+mod git_metrics;
 mod git_state;
 mod git_status;
 mod golang;
+mod gradle;
+mod haskell;
 mod helm;
 mod hg_branch;
 mod hostname;
 mod java;
 mod jobs;
 mod julia;
+mod kotlin;
 mod kubernetes;
 mod line_break;
+mod lua;
 mod memory_usage;
 mod nim;
 mod nix_shell;
 mod nodejs;
 mod ocaml;
+mod openstack;
 mod package;
 mod perl;
 mod php;
+mod pulumi;
 mod purescript;
 mod python;
+mod r;
 mod ruby;
 mod rust;
+mod scala;
+mod shlvl;
 mod singularity;
+mod status;
 mod swift;
 mod terraform;
 mod time;
 mod username;
 mod utils;
+mod vagrant;
+mod vlang;
 mod zig;
 
 #[cfg(feature = "battery")]
@@ -56,57 +72,80 @@ use crate::context::{Context, Shell};
 use crate::module::Module;
 
 pub fn handle<'a>(module: &str, context: &'a Context) -> Option<Module<'a>> {
+    // Skip disabled modules before they get to scan the directory or run any commands
+    if context.is_module_disabled_in_config(module) {
+        log::trace!("Module `{}` is disabled", module);
+        return None;
+    }
+
     match module {
         // Keep these ordered alphabetically.
         // Default ordering is handled in configs/mod.rs
         "aws" => aws::module(context),
         #[cfg(feature = "battery")]
         "battery" => battery::module(context),
+        "buf" => buf::module(context),
         "character" => character::module(context),
         "cmake" => cmake::module(context),
         "cmd_duration" => cmd_duration::module(context),
         "conda" => conda::module(context),
         "dart" => dart::module(context),
+        "deno" => deno::module(context),
         "directory" => directory::module(context),
+        "direnv" => direnv::module(context),
         "docker_context" => docker_context::module(context),
         "dotnet" => dotnet::module(context),
         "elixir" => elixir::module(context),
         "elm" => elm::module(context),
         "erlang" => erlang::module(context),
-        "env_var" => env_var::module(context),
+        "env_var" => env_var::module(None, context),
         "gcloud" => gcloud::module(context),
         "git_branch" => git_branch::module(context),
         "git_commit" => git_commit::module(context),
+        "git_metrics" => git_metrics::module(context),
         "git_state" => git_state::module(context),
         "git_status" => git_status::module(context),
         "golang" => golang::module(context),
+        "gradle" => gradle::module(context),
+        "haskell" => haskell::module(context),
         "helm" => helm::module(context),
         "hg_branch" => hg_branch::module(context),
         "hostname" => hostname::module(context),
         "java" => java::module(context),
         "jobs" => jobs::module(context),
         "julia" => julia::module(context),
+        "kotlin" => kotlin::module(context),
         "kubernetes" => kubernetes::module(context),
         "line_break" => line_break::module(context),
+        "lua" => lua::module(context),
         "memory_usage" => memory_usage::module(context),
         "nim" => nim::module(context),
         "nix_shell" => nix_shell::module(context),
         "nodejs" => nodejs::module(context),
         "ocaml" => ocaml::module(context),
+        "openstack" => openstack::module(context),
         "package" => package::module(context),
         "perl" => perl::module(context),
         "php" => php::module(context),
+        "pulumi" => pulumi::module(context),
         "purescript" => purescript::module(context),
         "python" => python::module(context),
+        "r" => r::module(context),
         "ruby" => ruby::module(context),
         "rust" => rust::module(context),
+        "scala" => scala::module(context),
+        "shlvl" => shlvl::module(context),
         "singularity" => singularity::module(context),
+        "status" => status::module(context),
         "swift" => swift::module(context),
         "terraform" => terraform::module(context),
         "time" => time::module(context),
         "crystal" => crystal::module(context),
         "username" => username::module(context),
+        "vagrant" => vagrant::module(context),
+        "vlang" => vlang::module(context),
         "zig" => zig::module(context),
+        env_var if env_var.starts_with("env_var.") => env_var::module(Some(&env_var[8..]), context),
         _ => {
             eprintln!("Error: Unknown module {}. Use starship module --list to list out all supported modules.", module);
             None
@@ -118,6 +157,7 @@ pub fn description(module: &str) -> &'static str {
     match module {
         "aws" => "The current AWS region and profile",
         "battery" => "The current charge of the device's battery and its current charging status",
+        "buf" => "The currently installed version of the buf CLI",
         "character" => {
             "A character (usually an arrow) beside where the text is entered in your terminal"
         }
@@ -126,7 +166,9 @@ pub fn description(module: &str) -> &'static str {
         "conda" => "The current conda environment, if $CONDA_DEFAULT_ENV is set",
         "crystal" => "The currently installed version of Crystal",
         "dart" => "The currently installed version of Dart",
+        "deno" => "The currently installed version of Deno",
         "directory" => "The current working directory",
+        "direnv" => "Whether direnv has loaded an .envrc file",
         "docker_context" => "The current docker context",
         "dotnet" => "The relevant version of the .NET Core SDK for the current directory",
         "env_var" => "Displays the current value of a selected environment variable",
@@ -134,33 +176,46 @@ pub fn description(module: &str) -> &'static str {
         "gcloud" => "The current GCP client configuration",
         "git_branch" => "The active branch of the repo in your current directory",
         "git_commit" => "The active commit of the repo in your current directory",
+        "git_metrics" => "The number of lines added and deleted in the working tree",
         "git_state" => "The current git operation, and it's progress",
         "git_status" => "Symbol representing the state of the repo",
         "golang" => "The currently installed version of Golang",
+        "gradle" => "The version of Gradle used by the project",
+        "haskell" => "The currently installed version of GHC",
         "helm" => "The currently installed version of Helm",
         "hg_branch" => "The active branch of the repo in your current directory",
         "hostname" => "The system hostname",
         "java" => "The currently installed version of Java",
         "jobs" => "The current number of jobs running",
         "julia" => "The currently installed version of Julia",
+        "kotlin" => "The currently installed version of Kotlin",
         "kubernetes" => "The current Kubernetes context name and, if set, the namespace",
         "line_break" => "Separates the prompt into two lines",
+        "lua" => "The currently installed version of Lua",
         "memory_usage" => "Current system memory and swap usage",
         "nim" => "The currently installed version of Nim",
         "nix_shell" => "The nix-shell environment",
         "nodejs" => "The currently installed version of NodeJS",
         "ocaml" => "The currently installed version of OCaml",
+        "openstack" => "The current OpenStack cloud and project",
         "package" => "The package version of the current directory's project",
         "perl" => "The currently installed version of Perl",
         "php" => "The currently installed version of PHP",
+        "pulumi" => "The current stack name of Pulumi",
         "purescript" => "The currently installed version of PureScript",
         "python" => "The currently installed version of Python",
+        "r" => "The currently installed version of R",
         "ruby" => "The currently installed version of Ruby",
         "rust" => "The currently installed version of Rust",
+        "scala" => "The currently installed version of Scala",
+        "shlvl" => "The current value of SHLVL",
+        "status" => "The status of the last command",
         "swift" => "The currently installed version of Swift",
         "terraform" => "The currently selected terraform workspace and version",
         "time" => "The current local time",
         "username" => "The active user's username",
+        "vagrant" => "The currently installed version of Vagrant",
+        "vlang" => "The currently installed version of V",
         "zig" => "The currently installed version of Zig",
         _ => "<no description>",
     }
@@ -2,7 +2,6 @@ use super::{Context, Module, RootModuleConfig};
 
 use crate::configs::nim::NimConfig;
 use crate::formatter::StringFormatter;
-use crate::utils;
 
 /// Creates a module with the current Nim version
 ///
@@ -34,7 +33,8 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
                 _ => None,
             })
             .map(|variable| match variable {
-                "version" => utils::exec_cmd("nim", &["--version"])
+                "version" => context
+                    .exec_cmd("nim", &["--version"])
                     .map(|command_output| command_output.stdout)
                     .and_then(|nim_version_output| {
                         Some(format!("v{}", parse_nim_version(&nim_version_output)?))
@@ -68,11 +68,19 @@ fn parse_nim_version(version_cmd_output: &str) -> Option<&str> {
 #[cfg(test)]
 mod tests {
     use super::parse_nim_version;
-    use crate::modules::utils::test::render_module;
+    use crate::modules::utils::test::render_module_with_commands;
     use ansi_term::Color;
     use std::fs::File;
     use std::io;
 
+    const NIM_BANNER: &str = "\
+Nim Compiler Version 1.2.0 [Linux: amd64]
+Compiled at 2020-04-03
+Copyright (c) 2006-2020 by Andreas Rumpf
+git hash: 7e83adff84be5d0c401a213eccb61e321a3fb1ff
+active boot switches: -d:release\n";
+    const NIM_VERSION: &[(&str, &str)] = &[("nim --version", NIM_BANNER)];
+
     #[test]
     fn nim_version() {
         let ok_versions = ["1.1.1", "2", "."];
@@ -93,14 +101,17 @@ mod tests {
             git hash: 7e83adff84be5d0c401a213eccb61e321a3fb1ff
             active boot switches: -d:release\n";
 
-        assert_eq!(Some("1.2.0"), parse_nim_version(sample_nimc_output))
+        assert_eq!(Some("1.2.0"), parse_nim_version(sample_nimc_output));
+
+        let sample_nimc_output = "Nim Compiler Version 1.4.8 [MacOSX: amd64]\n";
+        assert_eq!(Some("1.4.8"), parse_nim_version(sample_nimc_output));
     }
 
     #[test]
     fn folder_without_nim() -> io::Result<()> {
         let dir = tempfile::tempdir()?;
         File::create(dir.path().join("nim.txt"))?.sync_all()?;
-        let actual = render_module("nim", dir.path(), None);
+        let actual = render_module_with_commands("nim", dir.path(), None, NIM_VERSION);
         let expected = None;
         assert_eq!(expected, actual);
         dir.close()
@@ -110,7 +121,7 @@ mod tests {
     fn folder_with_nimble_file() -> io::Result<()> {
         let dir = tempfile::tempdir()?;
         File::create(dir.path().join("main.nimble"))?.sync_all()?;
-        let actual = render_module("nim", dir.path(), None);
+        let actual = render_module_with_commands("nim", dir.path(), None, NIM_VERSION);
         let expected = Some(format!("via {} ", Color::Yellow.bold().paint("👑 v1.2.0")));
         assert_eq!(expected, actual);
         dir.close()
@@ -120,7 +131,7 @@ mod tests {
     fn folder_with_nim_file() -> io::Result<()> {
         let dir = tempfile::tempdir()?;
         File::create(dir.path().join("main.nim"))?.sync_all()?;
-        let actual = render_module("nim", dir.path(), None);
+        let actual = render_module_with_commands("nim", dir.path(), None, NIM_VERSION);
         let expected = Some(format!("via {} ", Color::Yellow.bold().paint("👑 v1.2.0")));
         assert_eq!(expected, actual);
         dir.close()
@@ -130,7 +141,7 @@ mod tests {
     fn folder_with_nims_file() -> io::Result<()> {
         let dir = tempfile::tempdir()?;
         File::create(dir.path().join("main.nims"))?.sync_all()?;
-        let actual = render_module("nim", dir.path(), None);
+        let actual = render_module_with_commands("nim", dir.path(), None, NIM_VERSION);
         let expected = Some(format!("via {} ", Color::Yellow.bold().paint("👑 v1.2.0")));
         assert_eq!(expected, actual);
         dir.close()
@@ -139,8 +150,8 @@ mod tests {
     #[test]
     fn folder_with_cfg_file() -> io::Result<()> {
         let dir = tempfile::tempdir()?;
-        File::create(dir.path().join("cfg.nim"))?.sync_all()?;
-        let actual = render_module("nim", dir.path(), None);
+        File::create(dir.path().join("nim.cfg"))?.sync_all()?;
+        let actual = render_module_with_commands("nim", dir.path(), None, NIM_VERSION);
         let expected = Some(format!("via {} ", Color::Yellow.bold().paint("👑 v1.2.0")));
         assert_eq!(expected, actual);
         dir.close()
@@ -19,14 +19,17 @@ use crate::formatter::StringFormatter;
 ///     - impure (name)  // $name == "name" in an impure nix-shell
 ///     - pure           // $name == "" in a pure nix-shell
 ///     - impure         // $name == "" in an impure nix-shell
+///
+/// `$IN_NIX_SHELL` set to "1", as done by older versions of Nix, is treated as impure.
 pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let mut module = context.new_module("nix_shell");
     let config: NixShellConfig = NixShellConfig::try_load(module.config);
 
-    let shell_name = env::var("name").ok();
+    let shell_name = env::var("name").ok().filter(|name| !name.is_empty());
     let shell_type = env::var("IN_NIX_SHELL").ok()?;
     let shell_type_format = match shell_type.as_ref() {
-        "impure" => config.impure_msg,
+        // Nix 1.x sets `$IN_NIX_SHELL` to "1" in (impure) nix-shells
+        "impure" | "1" => config.impure_msg,
         "pure" => config.pure_msg,
         _ => {
             return None;
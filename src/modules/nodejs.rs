@@ -4,33 +4,50 @@ use crate::configs::nodejs::NodejsConfig;
 use crate::formatter::StringFormatter;
 use crate::utils;
 
+use serde_json as json;
+
 /// Creates a module with the current Node.js version
 ///
 /// Will display the Node.js version if any of the following criteria are met:
 ///     - Current directory contains a `.js`, `.mjs` or `.cjs` file
 ///     - Current directory contains a `.ts` file
-///     - Current directory contains a `package.json` or `.node-version` file
+///     - Current directory contains a `package.json`, `.nvmrc` or `.node-version` file
 ///     - Current directory contains a `node_modules` directory
+///
+/// Unless `read_version_files` is disabled, the version pinned in `.nvmrc` or
+/// `.node-version` is preferred over the one reported by `node`. With `show_engine`
+/// enabled, the range required by `engines.node` in `package.json` is shown instead.
 pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let is_js_project = context
         .try_begin_scan()?
-        .set_files(&["package.json", ".node-version"])
+        .set_files(&["package.json", ".nvmrc", ".node-version"])
         .set_extensions(&["js", "mjs", "cjs", "ts"])
         .set_folders(&["node_modules"])
+        .set_excluded(&["esy.lock"])
         .is_match();
 
-    let is_esy_project = context
-        .try_begin_scan()?
-        .set_folders(&["esy.lock"])
-        .is_match();
-
-    if !is_js_project || is_esy_project {
+    if !is_js_project {
         return None;
     }
 
     let mut module = context.new_module("nodejs");
     let config = NodejsConfig::try_load(module.config);
-    let nodejs_version = utils::exec_cmd("node", &["--version"])?.stdout;
+    let engine_version = if config.show_engine {
+        get_engine_node_version(context)
+    } else {
+        None
+    };
+    let pinned_version = engine_version.or_else(|| {
+        if config.read_version_files {
+            get_pinned_node_version(context)
+        } else {
+            None
+        }
+    });
+    let nodejs_version = match pinned_version {
+        Some(version) => version,
+        None => context.exec_cmd("node", &["--version"])?.stdout,
+    };
     let parsed = StringFormatter::new(config.format).and_then(|formatter| {
         formatter
             .map_meta(|var, _| match var {
@@ -59,12 +76,93 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     Some(module)
 }
 
+fn get_pinned_node_version(context: &Context) -> Option<String> {
+    [".nvmrc", ".node-version"].iter().find_map(|file_name| {
+        let contents = utils::read_file(context.current_dir.join(file_name)).ok()?;
+        format_pinned_node_version(&contents)
+    })
+}
+
+fn get_engine_node_version(context: &Context) -> Option<String> {
+    let contents = utils::read_file(context.current_dir.join("package.json")).ok()?;
+    parse_engine_node_version(&contents)
+}
+
+/// Extracts the range of Node.js versions required by the `engines` field of a `package.json`
+fn parse_engine_node_version(package_json: &str) -> Option<String> {
+    let package_json: json::Value = json::from_str(package_json).ok()?;
+    let range = package_json.get("engines")?.get("node")?.as_str()?.trim();
+    if range.is_empty() {
+        return None;
+    }
+
+    Some(range.to_string())
+}
+
+/// Normalizes the contents of a version file
+///
+/// Plain versions such as `14` or `v12.18.3` are shown with a leading `v`, while
+/// aliases (`lts/erbium`, `node`) and ranges are shown as they are written.
+fn format_pinned_node_version(contents: &str) -> Option<String> {
+    let version = contents.lines().next()?.trim();
+    if version.is_empty() {
+        return None;
+    }
+
+    let number = version.trim_start_matches('v');
+    let is_plain_version = utils::is_dotted_version(number);
+
+    if is_plain_version {
+        Some(format!("v{}", number))
+    } else {
+        Some(version.to_string())
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use super::{format_pinned_node_version, parse_engine_node_version};
     use crate::modules::utils::test::render_module;
     use ansi_term::Color;
     use std::fs::{self, File};
-    use std::io;
+    use std::io::{self, Write};
+
+    #[test]
+    fn test_format_pinned_node_version() {
+        assert_eq!(format_pinned_node_version("14\n"), Some("v14".to_string()));
+        assert_eq!(
+            format_pinned_node_version("v12.18.3"),
+            Some("v12.18.3".to_string())
+        );
+        assert_eq!(
+            format_pinned_node_version("lts/erbium\n"),
+            Some("lts/erbium".to_string())
+        );
+        assert_eq!(
+            format_pinned_node_version(">=14.0.0"),
+            Some(">=14.0.0".to_string())
+        );
+        assert_eq!(format_pinned_node_version("  \n"), None);
+        assert_eq!(format_pinned_node_version(""), None);
+    }
+
+    #[test]
+    fn test_parse_engine_node_version() {
+        assert_eq!(
+            parse_engine_node_version(r#"{"engines": {"node": ">=14.0.0 <16"}}"#),
+            Some(">=14.0.0 <16".to_string())
+        );
+        assert_eq!(
+            parse_engine_node_version(r#"{"engines": {"npm": "7.x"}}"#),
+            None
+        );
+        assert_eq!(
+            parse_engine_node_version(r#"{"engines": {"node": ""}}"#),
+            None
+        );
+        assert_eq!(parse_engine_node_version(r#"{"name": "starship"}"#), None);
+        assert_eq!(parse_engine_node_version("not json"), None);
+    }
 
     #[test]
     fn folder_without_node_files() -> io::Result<()> {
@@ -86,6 +184,30 @@ mod tests {
         dir.close()
     }
 
+    #[test]
+    fn folder_with_package_json_engines() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let mut file = File::create(dir.path().join("package.json"))?;
+        file.write_all(br#"{"name": "starship", "engines": {"node": ">=14.0.0"}}"#)?;
+        file.sync_all()?;
+
+        let actual = render_module("nodejs", dir.path(), None);
+        let expected = Some(format!("via {} ", Color::Green.bold().paint("⬢ v12.0.0")));
+        assert_eq!(expected, actual);
+
+        let actual = render_module(
+            "nodejs",
+            dir.path(),
+            Some(toml::toml! {
+                [nodejs]
+                show_engine = true
+            }),
+        );
+        let expected = Some(format!("via {} ", Color::Green.bold().paint("⬢ >=14.0.0")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
     #[test]
     fn folder_with_package_json_and_esy_lock() -> io::Result<()> {
         let dir = tempfile::tempdir()?;
@@ -110,6 +232,66 @@ mod tests {
         dir.close()
     }
 
+    #[test]
+    fn folder_with_pinned_node_version() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let mut file = File::create(dir.path().join(".node-version"))?;
+        file.write_all(b"14.15.4\n")?;
+        file.sync_all()?;
+
+        let actual = render_module("nodejs", dir.path(), None);
+        let expected = Some(format!("via {} ", Color::Green.bold().paint("⬢ v14.15.4")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_nvmrc() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let mut file = File::create(dir.path().join(".nvmrc"))?;
+        file.write_all(b"lts/erbium\n")?;
+        file.sync_all()?;
+
+        let actual = render_module("nodejs", dir.path(), None);
+        let expected = Some(format!(
+            "via {} ",
+            Color::Green.bold().paint("⬢ lts/erbium")
+        ));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_empty_nvmrc() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join(".nvmrc"))?.sync_all()?;
+
+        let actual = render_module("nodejs", dir.path(), None);
+        let expected = Some(format!("via {} ", Color::Green.bold().paint("⬢ v12.0.0")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_nvmrc_and_read_version_files_disabled() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let mut file = File::create(dir.path().join(".nvmrc"))?;
+        file.write_all(b"14\n")?;
+        file.sync_all()?;
+
+        let actual = render_module(
+            "nodejs",
+            dir.path(),
+            Some(toml::toml! {
+                [nodejs]
+                read_version_files = false
+            }),
+        );
+        let expected = Some(format!("via {} ", Color::Green.bold().paint("⬢ v12.0.0")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
     #[test]
     fn folder_with_js_file() -> io::Result<()> {
         let dir = tempfile::tempdir()?;
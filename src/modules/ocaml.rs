@@ -2,7 +2,6 @@ use super::{Context, Module, RootModuleConfig};
 
 use crate::configs::ocaml::OCamlConfig;
 use crate::formatter::StringFormatter;
-use crate::utils;
 
 /// Creates a module with the current OCaml version
 ///
@@ -30,11 +29,12 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
         .set_folders(&["esy.lock"])
         .is_match();
 
-    let ocaml_version = if is_esy_project {
-        utils::exec_cmd("esy", &["ocaml", "-vnum"])?.stdout
+    let ocaml_output = if is_esy_project {
+        context.exec_cmd("esy", &["ocaml", "-vnum"])?
     } else {
-        utils::exec_cmd("ocaml", &["-vnum"])?.stdout
+        context.exec_cmd("ocaml", &["-vnum"])?
     };
+    let ocaml_version = format_ocaml_version(&ocaml_output.stdout)?;
 
     let mut module = context.new_module("ocaml");
     let config: OCamlConfig = OCamlConfig::try_load(module.config);
@@ -50,7 +50,7 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
                 _ => None,
             })
             .map(|variable| match variable {
-                "version" => Some(Ok(format!("v{}", &ocaml_version.trim()))),
+                "version" => Some(Ok(&ocaml_version)),
                 _ => None,
             })
             .parse(None)
@@ -67,13 +67,39 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     Some(module)
 }
 
+fn format_ocaml_version(ocaml_stdout: &str) -> Option<String> {
+    // ocaml -vnum output looks like this:
+    // 4.10.0
+    let version = ocaml_stdout.trim();
+    if version.starts_with(|c: char| c.is_ascii_digit()) {
+        Some(format!("v{}", version))
+    } else {
+        None
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use super::format_ocaml_version;
     use crate::modules::utils::test::render_module;
     use ansi_term::Color;
     use std::fs::{self, File};
     use std::io;
 
+    #[test]
+    fn test_format_ocaml_version() {
+        assert_eq!(
+            format_ocaml_version("4.10.0\n"),
+            Some("v4.10.0".to_string())
+        );
+        assert_eq!(
+            format_ocaml_version("4.12.0+flambda"),
+            Some("v4.12.0+flambda".to_string())
+        );
+        assert_eq!(format_ocaml_version(""), None);
+        assert_eq!(format_ocaml_version("ocaml: unknown option"), None);
+    }
+
     #[test]
     fn folder_without_ocaml_file() -> io::Result<()> {
         let dir = tempfile::tempdir()?;
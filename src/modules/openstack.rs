@@ -0,0 +1,79 @@
+use yaml_rust::YamlLoader;
+
+use std::env;
+use std::path::PathBuf;
+
+use super::{Context, Module, RootModuleConfig};
+
+use crate::configs::openstack::OpenStackConfig;
+use crate::formatter::StringFormatter;
+use crate::utils;
+
+type Project = String;
+
+/// Find the project of `cloud` in the first `clouds.yaml` that defines it. Like the OpenStack
+/// CLI, `$OS_CLIENT_CONFIG_FILE` is tried first, then the current directory,
+/// `~/.config/openstack` and `/etc/openstack`.
+fn get_osp_project_from_config(context: &Context, cloud: &str) -> Option<Project> {
+    let config_locations = vec![
+        env::var_os("OS_CLIENT_CONFIG_FILE").map(PathBuf::from),
+        Some(context.current_dir.join("clouds.yaml")),
+        dirs_next::home_dir().map(|home| home.join(".config/openstack/clouds.yaml")),
+        Some(PathBuf::from("/etc/openstack/clouds.yaml")),
+    ];
+
+    config_locations
+        .into_iter()
+        .flatten()
+        .filter_map(|location| utils::read_file(location).ok())
+        .filter_map(|contents| YamlLoader::load_from_str(&contents).ok())
+        .find_map(|yaml_docs| {
+            let project = yaml_docs.first()?["clouds"][cloud]["auth"]["project_name"].as_str()?;
+            if project.is_empty() {
+                return None;
+            }
+            Some(project.to_string())
+        })
+}
+
+/// Creates a module with the current OpenStack cloud and project
+///
+/// Will display the cloud from `$OS_CLOUD` if it is set, along with the project from the
+/// cloud's entry in `clouds.yaml`.
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let cloud = env::var("OS_CLOUD")
+        .ok()
+        .filter(|cloud| !cloud.is_empty())?;
+    let project = get_osp_project_from_config(context, &cloud);
+
+    let mut module = context.new_module("openstack");
+    let config: OpenStackConfig = OpenStackConfig::try_load(module.config);
+
+    let parsed = StringFormatter::new(config.format).and_then(|formatter| {
+        formatter
+            .map_meta(|variable, _| match variable {
+                "symbol" => Some(config.symbol),
+                _ => None,
+            })
+            .map_style(|variable| match variable {
+                "style" => Some(Ok(config.style)),
+                _ => None,
+            })
+            .map(|variable| match variable {
+                "cloud" => Some(Ok(cloud.as_str())),
+                "project" => project.as_deref().map(Ok),
+                _ => None,
+            })
+            .parse(None)
+    });
+
+    module.set_segments(match parsed {
+        Ok(segments) => segments,
+        Err(error) => {
+            log::warn!("Error in module `openstack`:\n{}", error);
+            return None;
+        }
+    });
+
+    Some(module)
+}
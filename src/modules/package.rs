@@ -1,3 +1,4 @@
+use std::fs;
 use std::path::PathBuf;
 
 use super::{Context, Module, RootModuleConfig};
@@ -12,7 +13,8 @@ use serde_json as json;
 
 /// Creates a module with the current package version
 ///
-/// Will display if a version is defined for your Node.js or Rust project (if one exists)
+/// Will display if a version is defined for your Node.js, Rust, Python, PHP, Ruby or other
+/// supported project (if one exists)
 pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let mut module = context.new_module("package");
     let config: PackageConfig = PackageConfig::try_load(module.config);
@@ -73,10 +75,12 @@ fn extract_package_version(file_contents: &str, display_private: bool) -> Option
 
 fn extract_poetry_version(file_contents: &str) -> Option<String> {
     let poetry_toml: toml::Value = toml::from_str(file_contents).ok()?;
+    // Poetry keeps its metadata under `[tool.poetry]`, while PEP 621 uses `[project]`
     let raw_version = poetry_toml
-        .get("tool")?
-        .get("poetry")?
-        .get("version")?
+        .get("tool")
+        .and_then(|tool| tool.get("poetry"))
+        .and_then(|poetry| poetry.get("version"))
+        .or_else(|| poetry_toml.get("project")?.get("version"))?
         .as_str()?;
 
     let formatted_version = format_version(raw_version);
@@ -84,7 +88,8 @@ fn extract_poetry_version(file_contents: &str) -> Option<String> {
 }
 
 fn extract_gradle_version(file_contents: &str) -> Option<String> {
-    let re = Regex::new(r#"(?m)^version ['"](?P<version>[^'"]+)['"]$"#).unwrap();
+    // Matches both `version '1.2.3'` (Groovy) and `version = "1.2.3"` (Groovy or Kotlin DSL)
+    let re = Regex::new(r#"(?m)^\s*version\s*=?\s*['"](?P<version>[^'"]+)['"]\s*$"#).unwrap();
     let caps = re.captures(file_contents)?;
 
     let formatted_version = format_version(&caps["version"]);
@@ -110,6 +115,14 @@ fn extract_project_version(file_contents: &str) -> Option<String> {
     Some(formatted_version)
 }
 
+fn extract_gemspec_version(file_contents: &str) -> Option<String> {
+    let re = Regex::new(r#"(?m)^\s*\w+\.version\s*=\s*["'](?P<version>[^"']+)["']"#).unwrap();
+    let caps = re.captures(file_contents)?;
+
+    let formatted_version = format_version(&caps["version"]);
+    Some(formatted_version)
+}
+
 fn extract_helm_package_version(file_contents: &str) -> Option<String> {
     let yaml = yaml_rust::YamlLoader::load_from_str(file_contents).ok()?;
     let version = yaml.first()?["version"].as_str()?;
@@ -173,6 +186,8 @@ fn get_package_version(base_dir: &PathBuf, config: &PackageConfig) -> Option<Str
         extract_composer_version(&composer_json)
     } else if let Ok(build_gradle) = utils::read_file(base_dir.join("build.gradle")) {
         extract_gradle_version(&build_gradle)
+    } else if let Ok(build_gradle_kts) = utils::read_file(base_dir.join("build.gradle.kts")) {
+        extract_gradle_version(&build_gradle_kts)
     } else if let Ok(project_toml) = utils::read_file(base_dir.join("Project.toml")) {
         extract_project_version(&project_toml)
     } else if let Ok(mix_file) = utils::read_file(base_dir.join("mix.exs")) {
@@ -181,11 +196,24 @@ fn get_package_version(base_dir: &PathBuf, config: &PackageConfig) -> Option<Str
         extract_helm_package_version(&chart_file)
     } else if let Ok(pom_file) = utils::read_file(base_dir.join("pom.xml")) {
         extract_maven_version(&pom_file)
+    } else if let Some(gemspec_file) = find_gemspec_file(base_dir) {
+        extract_gemspec_version(&utils::read_file(gemspec_file).ok()?)
     } else {
         None
     }
 }
 
+fn find_gemspec_file(base_dir: &PathBuf) -> Option<PathBuf> {
+    fs::read_dir(base_dir)
+        .ok()?
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.is_file() && path.extension().and_then(|ext| ext.to_str()) == Some("gemspec")
+        })
+        .min()
+}
+
 fn format_version(version: &str) -> String {
     let cleaned = version.replace('"', "").trim().to_string();
     if cleaned.starts_with('v') {
@@ -362,6 +390,84 @@ mod tests {
         project_dir.close()
     }
 
+    #[test]
+    fn test_extract_poetry_without_version_falls_back_to_pep621() -> io::Result<()> {
+        let config_name = "pyproject.toml";
+        let config_content = toml::toml! {
+            [project]
+            name = "starship"
+            version = "0.1.0"
+
+            [tool.poetry]
+            name = "starship"
+        }
+        .to_string();
+
+        let project_dir = create_project_dir()?;
+        fill_config(&project_dir, config_name, Some(&config_content))?;
+        expect_output(&project_dir, Some("v0.1.0"), None)?;
+        project_dir.close()
+    }
+
+    #[test]
+    fn test_extract_pep621_version() -> io::Result<()> {
+        let config_name = "pyproject.toml";
+        let config_content = toml::toml! {
+            [project]
+            name = "starship"
+            version = "0.1.0"
+        }
+        .to_string();
+
+        let project_dir = create_project_dir()?;
+        fill_config(&project_dir, config_name, Some(&config_content))?;
+        expect_output(&project_dir, Some("v0.1.0"), None)?;
+        project_dir.close()
+    }
+
+    #[test]
+    fn test_extract_gemspec_version() -> io::Result<()> {
+        let config_name = "starship.gemspec";
+        let config_content = "Gem::Specification.new do |spec|
+  spec.name          = \"starship\"
+  spec.version       = \"1.2.3\"
+  spec.authors       = [\"Starship\"]
+end";
+
+        let project_dir = create_project_dir()?;
+        fill_config(&project_dir, config_name, Some(config_content))?;
+        expect_output(&project_dir, Some("v1.2.3"), None)?;
+        project_dir.close()
+    }
+
+    #[test]
+    fn test_extract_gemspec_version_single_quote() -> io::Result<()> {
+        let config_name = "starship.gemspec";
+        let config_content = "Gem::Specification.new do |s|
+  s.name    = 'starship'
+  s.version = '0.4.0.pre'.freeze
+end";
+
+        let project_dir = create_project_dir()?;
+        fill_config(&project_dir, config_name, Some(config_content))?;
+        expect_output(&project_dir, Some("v0.4.0.pre"), None)?;
+        project_dir.close()
+    }
+
+    #[test]
+    fn test_extract_gemspec_version_without_version() -> io::Result<()> {
+        let config_name = "starship.gemspec";
+        let config_content = "Gem::Specification.new do |spec|
+  spec.name = \"starship\"
+  spec.required_ruby_version = \">= 2.5\"
+end";
+
+        let project_dir = create_project_dir()?;
+        fill_config(&project_dir, config_name, Some(config_content))?;
+        expect_output(&project_dir, None, None)?;
+        project_dir.close()
+    }
+
     #[test]
     fn test_extract_gradle_version_single_quote() -> io::Result<()> {
         let config_name = "build.gradle";
@@ -437,6 +543,53 @@ java {
         project_dir.close()
     }
 
+    #[test]
+    fn test_extract_gradle_version_assignment() -> io::Result<()> {
+        let config_name = "build.gradle";
+        let config_content = "plugins {
+    id 'java'
+}
+group = 'com.example'
+version = '1.2.3'
+";
+
+        let project_dir = create_project_dir()?;
+        fill_config(&project_dir, config_name, Some(config_content))?;
+        expect_output(&project_dir, Some("v1.2.3"), None)?;
+        project_dir.close()
+    }
+
+    #[test]
+    fn test_extract_gradle_kts_version() -> io::Result<()> {
+        let config_name = "build.gradle.kts";
+        let config_content = "plugins {
+    kotlin(\"jvm\") version \"1.5.31\"
+}
+group = \"com.example\"
+version = \"0.3.0-SNAPSHOT\"
+";
+
+        let project_dir = create_project_dir()?;
+        fill_config(&project_dir, config_name, Some(config_content))?;
+        expect_output(&project_dir, Some("v0.3.0-SNAPSHOT"), None)?;
+        project_dir.close()
+    }
+
+    #[test]
+    fn test_extract_gradle_kts_version_without_version() -> io::Result<()> {
+        let config_name = "build.gradle.kts";
+        let config_content = "plugins {
+    kotlin(\"jvm\") version \"1.5.31\"
+}
+group = \"com.example\"
+";
+
+        let project_dir = create_project_dir()?;
+        fill_config(&project_dir, config_name, Some(config_content))?;
+        expect_output(&project_dir, None, None)?;
+        project_dir.close()
+    }
+
     #[test]
     fn test_extract_mix_version() -> io::Result<()> {
         let config_name = "mix.exs";
@@ -1,51 +1,55 @@
 use super::{Context, Module, RootModuleConfig};
 
 use crate::configs::perl::PerlConfig;
-use crate::formatter::StringFormatter;
+use crate::formatter::{StringFormatter, VersionFormatter};
 use crate::utils;
 
 /// Creates a module with the current perl version
 ///
 /// Will display the perl version if any of the following criteria are met:
-///     - Current directory contains a `.pl`, `.pm` or a `.pod` file
+///     - Current directory contains a `.pl`, `.pm` or a `.pod` file, in any case
 ///     - Current directory contains a "Makefile.PL", "Build.PL",  "cpanfile", "cpanfile.snapshot",
 ///       "META.json", "META.yml", or ".perl-version" file
+///
+/// These can be changed with the `detect_extensions`, `detect_files` and `detect_folders` options.
+///
+/// The version pinned in `.perl-version` is preferred over the one reported by `perl`.
 pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let mut module = context.new_module("perl");
+    let config: PerlConfig = PerlConfig::try_load(module.config);
+
     let is_perl_project = context
         .try_begin_scan()?
-        .set_files(&[
-            "Makefile.PL",
-            "Build.PL",
-            "cpanfile",
-            "cpanfile.snapshot",
-            "META.json",
-            "META.yml",
-            ".perl-version",
-        ])
-        .set_extensions(&["pl", "pm", "pod"])
+        .set_files(&config.detect_files)
+        .set_extensions_case_insensitive(&config.detect_extensions)
+        .set_folders(&config.detect_folders)
         .is_match();
 
     if !is_perl_project {
         return None;
     }
 
-    let perl_version = utils::exec_cmd("perl", &["-e", "printf q#%vd#,$^V;"])?.stdout;
-
-    let mut module = context.new_module("perl");
-    let config: PerlConfig = PerlConfig::try_load(module.config);
+    let perl_version = get_pinned_perl_version(context, config.version_format).or_else(|| {
+        let output = context.exec_cmd("perl", &["-e", "printf q#%vd#,$^V;"])?;
+        format_perl_version(&output.stdout, config.version_format)
+    })?;
 
     let parsed = StringFormatter::new(config.format).and_then(|formatter| {
         formatter
             .map_meta(|var, _| match var {
                 "symbol" => Some(config.symbol),
+                "prefix" => Some(config.prefix),
+                "suffix" => Some(config.suffix),
                 _ => None,
             })
             .map_style(|variable| match variable {
                 "style" => Some(Ok(config.style)),
+                "symbol_style" => Some(Ok(style_or_default(config.symbol_style, config.style))),
+                "version_style" => Some(Ok(style_or_default(config.version_style, config.style))),
                 _ => None,
             })
             .map(|variable| match variable {
-                "version" => Some(Ok(format!("v{}", &perl_version))),
+                "version" => Some(Ok(&perl_version)),
                 _ => None,
             })
             .parse(None)
@@ -62,18 +66,102 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     Some(module)
 }
 
+/// An unset `symbol_style` or `version_style` falls back to `style`
+fn style_or_default<'a>(style: &'a str, default: &'a str) -> &'a str {
+    if style.is_empty() {
+        default
+    } else {
+        style
+    }
+}
+
+fn get_pinned_perl_version(context: &Context, version_format: &str) -> Option<String> {
+    let version_file = utils::read_file(context.current_dir.join(".perl-version")).ok()?;
+    format_perl_version(&version_file, version_format)
+}
+
+fn format_perl_version(perl_version: &str, version_format: &str) -> Option<String> {
+    let perl_version = perl_version.trim();
+    let is_version = utils::is_dotted_version(perl_version);
+
+    if is_version {
+        VersionFormatter::format_module_version("perl", perl_version, version_format)
+    } else {
+        None
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::modules::utils::test::render_module;
-    use ansi_term::Color;
+    use super::*;
+    use crate::modules::utils::test::render_module_with_commands;
+    use ansi_term::{ANSIStrings, Color};
     use std::fs::File;
-    use std::io;
+    use std::io::{self, Write};
+
+    const PERL_VERSION: &[(&str, &str)] = &[("perl -e printf q#%vd#,$^V;", "5.34.0")];
+
+    #[test]
+    fn test_format_perl_version() {
+        assert_eq!(
+            format_perl_version("5.30.1", "v${raw}"),
+            Some("v5.30.1".to_string())
+        );
+        assert_eq!(
+            format_perl_version("5.8", "v${raw}"),
+            Some("v5.8".to_string())
+        );
+        assert_eq!(format_perl_version("", "v${raw}"), None);
+        assert_eq!(format_perl_version("system", "v${raw}"), None);
+        assert_eq!(format_perl_version("5..1", "v${raw}"), None);
+    }
+
+    #[test]
+    fn test_format_perl_version_with_version_format() {
+        let version = "5.30.1\n";
+        assert_eq!(
+            format_perl_version(version, "v${major}.${minor}.${patch}"),
+            Some("v5.30.1".to_string())
+        );
+        assert_eq!(
+            format_perl_version(version, "v${major}.${minor}"),
+            Some("v5.30".to_string())
+        );
+        assert_eq!(
+            format_perl_version(version, "${major}"),
+            Some("5".to_string())
+        );
+    }
+
+    #[test]
+    fn test_format_perl_version_with_trailing_newline() {
+        assert_eq!(
+            format_perl_version("5.30.0\n", "v${raw}"),
+            Some("v5.30.0".to_string())
+        );
+        assert_eq!(
+            format_perl_version("5.30.0\r\n", "v${raw}"),
+            Some("v5.30.0".to_string())
+        );
+    }
 
     #[test]
     fn folder_without_perl_files() -> io::Result<()> {
         let dir = tempfile::tempdir()?;
 
-        let actual = render_module("perl", dir.path(), None);
+        let actual = render_module_with_commands("perl", dir.path(), None, PERL_VERSION);
+
+        let expected = None;
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_perl_file_without_perl() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join("any.pl"))?.sync_all()?;
+
+        let actual = render_module_with_commands("perl", dir.path(), None, &[]);
 
         let expected = None;
         assert_eq!(expected, actual);
@@ -85,11 +173,11 @@ mod tests {
         let dir = tempfile::tempdir()?;
         File::create(dir.path().join("Makefile.PL"))?.sync_all()?;
 
-        let actual = render_module("perl", dir.path(), None);
+        let actual = render_module_with_commands("perl", dir.path(), None, PERL_VERSION);
 
         let expected = Some(format!(
             "via {} ",
-            Color::Fixed(149).bold().paint("🐪 v5.26.1")
+            Color::Fixed(149).bold().paint("🐪 v5.34.0")
         ));
         assert_eq!(expected, actual);
         dir.close()
@@ -100,11 +188,11 @@ mod tests {
         let dir = tempfile::tempdir()?;
         File::create(dir.path().join("Build.PL"))?.sync_all()?;
 
-        let actual = render_module("perl", dir.path(), None);
+        let actual = render_module_with_commands("perl", dir.path(), None, PERL_VERSION);
 
         let expected = Some(format!(
             "via {} ",
-            Color::Fixed(149).bold().paint("🐪 v5.26.1")
+            Color::Fixed(149).bold().paint("🐪 v5.34.0")
         ));
         assert_eq!(expected, actual);
         dir.close()
@@ -115,11 +203,11 @@ mod tests {
         let dir = tempfile::tempdir()?;
         File::create(dir.path().join("cpanfile"))?.sync_all()?;
 
-        let actual = render_module("perl", dir.path(), None);
+        let actual = render_module_with_commands("perl", dir.path(), None, PERL_VERSION);
 
         let expected = Some(format!(
             "via {} ",
-            Color::Fixed(149).bold().paint("🐪 v5.26.1")
+            Color::Fixed(149).bold().paint("🐪 v5.34.0")
         ));
         assert_eq!(expected, actual);
         dir.close()
@@ -130,11 +218,11 @@ mod tests {
         let dir = tempfile::tempdir()?;
         File::create(dir.path().join("cpanfile.snapshot"))?.sync_all()?;
 
-        let actual = render_module("perl", dir.path(), None);
+        let actual = render_module_with_commands("perl", dir.path(), None, PERL_VERSION);
 
         let expected = Some(format!(
             "via {} ",
-            Color::Fixed(149).bold().paint("🐪 v5.26.1")
+            Color::Fixed(149).bold().paint("🐪 v5.34.0")
         ));
         assert_eq!(expected, actual);
         dir.close()
@@ -145,11 +233,11 @@ mod tests {
         let dir = tempfile::tempdir()?;
         File::create(dir.path().join("META.json"))?.sync_all()?;
 
-        let actual = render_module("perl", dir.path(), None);
+        let actual = render_module_with_commands("perl", dir.path(), None, PERL_VERSION);
 
         let expected = Some(format!(
             "via {} ",
-            Color::Fixed(149).bold().paint("🐪 v5.26.1")
+            Color::Fixed(149).bold().paint("🐪 v5.34.0")
         ));
         assert_eq!(expected, actual);
         dir.close()
@@ -160,11 +248,11 @@ mod tests {
         let dir = tempfile::tempdir()?;
         File::create(dir.path().join("META.yml"))?.sync_all()?;
 
-        let actual = render_module("perl", dir.path(), None);
+        let actual = render_module_with_commands("perl", dir.path(), None, PERL_VERSION);
 
         let expected = Some(format!(
             "via {} ",
-            Color::Fixed(149).bold().paint("🐪 v5.26.1")
+            Color::Fixed(149).bold().paint("🐪 v5.34.0")
         ));
         assert_eq!(expected, actual);
         dir.close()
@@ -175,11 +263,255 @@ mod tests {
         let dir = tempfile::tempdir()?;
         File::create(dir.path().join(".perl-version"))?.sync_all()?;
 
-        let actual = render_module("perl", dir.path(), None);
+        let actual = render_module_with_commands("perl", dir.path(), None, PERL_VERSION);
+
+        let expected = Some(format!(
+            "via {} ",
+            Color::Fixed(149).bold().paint("🐪 v5.34.0")
+        ));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_pinned_perl_version() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let mut file = File::create(dir.path().join(".perl-version"))?;
+        file.write_all(b"5.30.1\n")?;
+        file.sync_all()?;
+
+        let actual = render_module_with_commands("perl", dir.path(), None, PERL_VERSION);
+
+        let expected = Some(format!(
+            "via {} ",
+            Color::Fixed(149).bold().paint("🐪 v5.30.1")
+        ));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_blank_perl_version() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let mut file = File::create(dir.path().join(".perl-version"))?;
+        file.write_all(b"  \n")?;
+        file.sync_all()?;
+
+        let actual = render_module_with_commands("perl", dir.path(), None, PERL_VERSION);
+
+        let expected = Some(format!(
+            "via {} ",
+            Color::Fixed(149).bold().paint("🐪 v5.34.0")
+        ));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_custom_format() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join("any.pl"))?.sync_all()?;
+
+        let actual = render_module_with_commands(
+            "perl",
+            dir.path(),
+            Some(toml::toml! {
+                [perl]
+                format = "[$version $symbol]($style)\\$ "
+                symbol = "perl"
+                style = "blue"
+            }),
+            PERL_VERSION,
+        );
+
+        let expected = Some(format!("{}$ ", Color::Blue.paint("v5.34.0 perl")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_version_format() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join("any.pl"))?.sync_all()?;
+
+        let actual = render_module_with_commands(
+            "perl",
+            dir.path(),
+            Some(toml::toml! {
+                [perl]
+                version_format = "v${major}.${minor}"
+            }),
+            PERL_VERSION,
+        );
+
+        let expected = Some(format!(
+            "via {} ",
+            Color::Fixed(149).bold().paint("🐪 v5.34")
+        ));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_prefix_and_suffix() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join("any.pl"))?.sync_all()?;
+
+        let actual = render_module_with_commands(
+            "perl",
+            dir.path(),
+            Some(toml::toml! {
+                [perl]
+                prefix = "using "
+                suffix = " | "
+            }),
+            PERL_VERSION,
+        );
+
+        let expected = Some(format!(
+            "using {} | ",
+            Color::Fixed(149).bold().paint("🐪 v5.34.0")
+        ));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_empty_prefix() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join("any.pl"))?.sync_all()?;
+
+        let actual = render_module_with_commands(
+            "perl",
+            dir.path(),
+            Some(toml::toml! {
+                [perl]
+                prefix = ""
+            }),
+            PERL_VERSION,
+        );
+
+        let expected = Some(format!("{} ", Color::Fixed(149).bold().paint("🐪 v5.34.0")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_symbol_preset() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join("any.pl"))?.sync_all()?;
+
+        let render_with_preset = |preset: &str| {
+            let mut config = toml::value::Table::new();
+            config.insert("symbol_preset".to_owned(), preset.into());
+            render_module_with_commands(
+                "perl",
+                dir.path(),
+                Some(toml::Value::Table(config)),
+                PERL_VERSION,
+            )
+        };
+        let expected = |symbol: &str| {
+            Some(format!(
+                "via {} ",
+                Color::Fixed(149).bold().paint(format!("{}v5.34.0", symbol))
+            ))
+        };
+
+        assert_eq!(render_with_preset("plain"), expected("pl "));
+        assert_eq!(render_with_preset("nerd-font"), expected("\u{e769} "));
+        // The unicode preset keeps the default perl symbol
+        assert_eq!(render_with_preset("unicode"), expected("🐪 "));
+
+        // An explicitly configured symbol takes precedence over the preset
+        let actual = render_module_with_commands(
+            "perl",
+            dir.path(),
+            Some(toml::toml! {
+                symbol_preset = "plain"
+
+                [perl]
+                symbol = "perl "
+            }),
+            PERL_VERSION,
+        );
+        assert_eq!(actual, expected("perl "));
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_symbol_and_version_styles() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join("any.pl"))?.sync_all()?;
+
+        let actual = render_module_with_commands(
+            "perl",
+            dir.path(),
+            Some(toml::toml! {
+                [perl]
+                format = "via [$symbol]($symbol_style)[$version]($version_style) "
+                symbol_style = "red"
+                version_style = "blue"
+            }),
+            PERL_VERSION,
+        );
+
+        let expected = Some(format!(
+            "via {} ",
+            ANSIStrings(&[Color::Red.paint("🐪 "), Color::Blue.paint("v5.34.0")])
+        ));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_unset_version_style() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join("any.pl"))?.sync_all()?;
+
+        let actual = render_module_with_commands(
+            "perl",
+            dir.path(),
+            Some(toml::toml! {
+                [perl]
+                format = "via [$symbol]($symbol_style)[$version]($version_style) "
+                symbol_style = "red"
+            }),
+            PERL_VERSION,
+        );
 
         let expected = Some(format!(
             "via {} ",
-            Color::Fixed(149).bold().paint("🐪 v5.26.1")
+            ANSIStrings(&[
+                Color::Red.paint("🐪 "),
+                Color::Fixed(149).bold().paint("v5.34.0")
+            ])
+        ));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_symbol_style_and_default_format() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join("any.pl"))?.sync_all()?;
+
+        let actual = render_module_with_commands(
+            "perl",
+            dir.path(),
+            Some(toml::toml! {
+                [perl]
+                symbol_style = "red"
+            }),
+            PERL_VERSION,
+        );
+
+        let expected = Some(format!(
+            "via {} ",
+            ANSIStrings(&[
+                Color::Red.paint("🐪 "),
+                Color::Fixed(149).bold().paint("v5.34.0")
+            ])
         ));
         assert_eq!(expected, actual);
         dir.close()
@@ -190,11 +522,77 @@ mod tests {
         let dir = tempfile::tempdir()?;
         File::create(dir.path().join("any.pl"))?.sync_all()?;
 
-        let actual = render_module("perl", dir.path(), None);
+        let actual = render_module_with_commands("perl", dir.path(), None, PERL_VERSION);
+
+        let expected = Some(format!(
+            "via {} ",
+            Color::Fixed(149).bold().paint("🐪 v5.34.0")
+        ));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_uppercase_perl_file() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join("SCRIPT.PL"))?.sync_all()?;
+
+        let actual = render_module_with_commands("perl", dir.path(), None, PERL_VERSION);
+
+        let expected = Some(format!(
+            "via {} ",
+            Color::Fixed(149).bold().paint("🐪 v5.34.0")
+        ));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_custom_detect_extension() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join("basic.t"))?.sync_all()?;
+
+        let actual = render_module_with_commands("perl", dir.path(), None, PERL_VERSION);
+        assert_eq!(None, actual);
+
+        let actual = render_module_with_commands(
+            "perl",
+            dir.path(),
+            Some(toml::toml! {
+                [perl]
+                detect_extensions = ["pl", "pm", "pod", "t"]
+            }),
+            PERL_VERSION,
+        );
+        let expected = Some(format!(
+            "via {} ",
+            Color::Fixed(149).bold().paint("🐪 v5.34.0")
+        ));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_custom_detect_files_and_folders() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join("cpanfile"))?.sync_all()?;
+
+        let config = toml::toml! {
+            [perl]
+            detect_files = ["dist.ini"]
+            detect_folders = ["local"]
+        };
+
+        // `cpanfile` is no longer a trigger
+        let actual =
+            render_module_with_commands("perl", dir.path(), Some(config.clone()), PERL_VERSION);
+        assert_eq!(None, actual);
 
+        std::fs::create_dir(dir.path().join("local"))?;
+        let actual = render_module_with_commands("perl", dir.path(), Some(config), PERL_VERSION);
         let expected = Some(format!(
             "via {} ",
-            Color::Fixed(149).bold().paint("🐪 v5.26.1")
+            Color::Fixed(149).bold().paint("🐪 v5.34.0")
         ));
         assert_eq!(expected, actual);
         dir.close()
@@ -205,11 +603,11 @@ mod tests {
         let dir = tempfile::tempdir()?;
         File::create(dir.path().join("any.pm"))?.sync_all()?;
 
-        let actual = render_module("perl", dir.path(), None);
+        let actual = render_module_with_commands("perl", dir.path(), None, PERL_VERSION);
 
         let expected = Some(format!(
             "via {} ",
-            Color::Fixed(149).bold().paint("🐪 v5.26.1")
+            Color::Fixed(149).bold().paint("🐪 v5.34.0")
         ));
         assert_eq!(expected, actual);
         dir.close()
@@ -220,11 +618,11 @@ mod tests {
         let dir = tempfile::tempdir()?;
         File::create(dir.path().join("any.pod"))?.sync_all()?;
 
-        let actual = render_module("perl", dir.path(), None);
+        let actual = render_module_with_commands("perl", dir.path(), None, PERL_VERSION);
 
         let expected = Some(format!(
             "via {} ",
-            Color::Fixed(149).bold().paint("🐪 v5.26.1")
+            Color::Fixed(149).bold().paint("🐪 v5.34.0")
         ));
         assert_eq!(expected, actual);
         dir.close()
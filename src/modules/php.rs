@@ -9,6 +9,8 @@ use crate::utils;
 /// Will display the PHP version if any of the following criteria are met:
 ///     - Current directory contains a `.php` file
 ///     - Current directory contains a `composer.json` or `.php-version` file
+///
+/// The version pinned in `.php-version` is preferred over the one reported by `php`.
 pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let is_php_project = context
         .try_begin_scan()?
@@ -20,53 +22,63 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
         return None;
     }
 
-    match utils::exec_cmd(
-        "php",
-        &[
-            "-nr",
-            "'echo PHP_MAJOR_VERSION.\".\".PHP_MINOR_VERSION.\".\".PHP_RELEASE_VERSION;'",
-        ],
-    ) {
-        Some(php_cmd_output) => {
-            let mut module = context.new_module("php");
-            let config: PhpConfig = PhpConfig::try_load(module.config);
-
-            let parsed = StringFormatter::new(config.format).and_then(|formatter| {
-                formatter
-                    .map_meta(|variable, _| match variable {
-                        "symbol" => Some(config.symbol),
-                        _ => None,
-                    })
-                    .map_style(|variable| match variable {
-                        "style" => Some(Ok(config.style)),
-                        _ => None,
-                    })
-                    .map(|variable| match variable {
-                        "version" => format_php_version(&php_cmd_output.stdout).map(Ok),
-                        _ => None,
-                    })
-                    .parse(None)
-            });
-
-            module.set_segments(match parsed {
-                Ok(segments) => segments,
-                Err(error) => {
-                    log::warn!("Error in module `php`:\n{}", error);
-                    return None;
-                }
-            });
-
-            Some(module)
+    let php_version = get_pinned_php_version(context).or_else(|| {
+        // The code is passed to php as a single argument, without any shell quoting
+        let output = context.exec_cmd(
+            "php",
+            &[
+                "-nr",
+                "echo PHP_MAJOR_VERSION.\".\".PHP_MINOR_VERSION.\".\".PHP_RELEASE_VERSION;",
+            ],
+        )?;
+        format_php_version(&output.stdout)
+    })?;
+
+    let mut module = context.new_module("php");
+    let config: PhpConfig = PhpConfig::try_load(module.config);
+
+    let parsed = StringFormatter::new(config.format).and_then(|formatter| {
+        formatter
+            .map_meta(|variable, _| match variable {
+                "symbol" => Some(config.symbol),
+                _ => None,
+            })
+            .map_style(|variable| match variable {
+                "style" => Some(Ok(config.style)),
+                _ => None,
+            })
+            .map(|variable| match variable {
+                "version" => Some(Ok(&php_version)),
+                _ => None,
+            })
+            .parse(None)
+    });
+
+    module.set_segments(match parsed {
+        Ok(segments) => segments,
+        Err(error) => {
+            log::warn!("Error in module `php`:\n{}", error);
+            return None;
         }
-        None => None,
-    }
+    });
+
+    Some(module)
+}
+
+fn get_pinned_php_version(context: &Context) -> Option<String> {
+    let version_file = utils::read_file(context.current_dir.join(".php-version")).ok()?;
+    format_php_version(&version_file)
 }
 
 fn format_php_version(php_version: &str) -> Option<String> {
-    let mut formatted_version = String::with_capacity(php_version.len() + 1);
-    formatted_version.push('v');
-    formatted_version.push_str(php_version);
-    Some(formatted_version)
+    let php_version = php_version.trim();
+    let is_version = utils::is_dotted_version(php_version);
+
+    if is_version {
+        Some(format!("v{}", php_version))
+    } else {
+        None
+    }
 }
 
 #[cfg(test)]
@@ -75,12 +87,16 @@ mod tests {
     use crate::modules::utils::test::render_module;
     use ansi_term::Color;
     use std::fs::File;
-    use std::io;
+    use std::io::{self, Write};
 
     #[test]
     fn test_format_php_version() {
         let input = "7.3.8";
         assert_eq!(format_php_version(input), Some("v7.3.8".to_string()));
+
+        assert_eq!(format_php_version("8.0.3\n"), Some("v8.0.3".to_string()));
+        assert_eq!(format_php_version(""), None);
+        assert_eq!(format_php_version("system"), None);
     }
 
     #[test]
@@ -124,6 +140,23 @@ mod tests {
         dir.close()
     }
 
+    #[test]
+    fn folder_with_pinned_php_version() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let mut file = File::create(dir.path().join(".php-version"))?;
+        file.write_all(b"8.0.3\n")?;
+        file.sync_all()?;
+
+        let actual = render_module("php", dir.path(), None);
+
+        let expected = Some(format!(
+            "via {} ",
+            Color::Fixed(147).bold().paint("🐘 v8.0.3")
+        ));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
     #[test]
     fn folder_with_php_file() -> io::Result<()> {
         let dir = tempfile::tempdir()?;
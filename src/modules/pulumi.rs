@@ -0,0 +1,111 @@
+use sha1::{Digest, Sha1};
+use yaml_rust::YamlLoader;
+
+use std::env;
+use std::path::{Path, PathBuf};
+
+use super::{Context, Module, RootModuleConfig};
+
+use crate::configs::pulumi::PulumiConfig;
+use crate::formatter::StringFormatter;
+use crate::utils;
+
+/// Creates a module with the currently selected Pulumi stack
+///
+/// Will display the Pulumi stack if the following criteria are met:
+///     - Current directory contains a `Pulumi.yaml` or `Pulumi.yml` file
+///     - A stack has been selected for the project, in the workspace file kept in
+///       `$PULUMI_HOME/workspaces` (`$HOME/.pulumi/workspaces` by default)
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let project_file = ["Pulumi.yaml", "Pulumi.yml"]
+        .iter()
+        .map(|file| context.current_dir.join(file))
+        .find(|path| path.is_file())?;
+
+    let pulumi_home = get_pulumi_home()?;
+    let project_name = get_project_name(&project_file)?;
+    let stack = get_stack_name(&pulumi_home, &project_name, &project_file)?;
+
+    let mut module = context.new_module("pulumi");
+    let config: PulumiConfig = PulumiConfig::try_load(module.config);
+
+    let parsed = StringFormatter::new(config.format).and_then(|formatter| {
+        formatter
+            .map_meta(|var, _| match var {
+                "symbol" => Some(config.symbol),
+                _ => None,
+            })
+            .map_style(|variable| match variable {
+                "style" => Some(Ok(config.style)),
+                _ => None,
+            })
+            .map(|variable| match variable {
+                "stack" => Some(Ok(stack.clone())),
+                "username" => get_pulumi_username(&pulumi_home).map(Ok),
+                _ => None,
+            })
+            .parse(None)
+    });
+
+    module.set_segments(match parsed {
+        Ok(segments) => segments,
+        Err(error) => {
+            log::warn!("Error in module `pulumi`:\n{}", error);
+            return None;
+        }
+    });
+
+    Some(module)
+}
+
+fn get_pulumi_home() -> Option<PathBuf> {
+    match env::var_os("PULUMI_HOME") {
+        Some(pulumi_home) => Some(PathBuf::from(pulumi_home)),
+        None => Some(dirs_next::home_dir()?.join(".pulumi")),
+    }
+}
+
+fn get_project_name(project_file: &Path) -> Option<String> {
+    let contents = utils::read_file(project_file).ok()?;
+    let yaml_docs = YamlLoader::load_from_str(&contents).ok()?;
+    let name = yaml_docs.first()?["name"].as_str()?;
+
+    if name.is_empty() {
+        return None;
+    }
+
+    Some(name.to_string())
+}
+
+/// Read the selected stack from the project's workspace file, which Pulumi names
+/// `<project name>-<sha1 of the project file path>-workspace.json`
+fn get_stack_name(pulumi_home: &Path, project_name: &str, project_file: &Path) -> Option<String> {
+    let path_hash = Sha1::digest(project_file.to_str()?.as_bytes());
+    let workspace_file = pulumi_home
+        .join("workspaces")
+        .join(format!("{}-{:x}-workspace.json", project_name, path_hash));
+
+    let contents = utils::read_file(workspace_file).ok()?;
+    let workspace: serde_json::Value = serde_json::from_str(&contents).ok()?;
+    let stack = workspace.get("stack")?.as_str()?;
+
+    if stack.is_empty() {
+        return None;
+    }
+
+    Some(stack.to_string())
+}
+
+/// Read the username of the currently logged in account from `credentials.json`
+fn get_pulumi_username(pulumi_home: &Path) -> Option<String> {
+    let contents = utils::read_file(pulumi_home.join("credentials.json")).ok()?;
+    let credentials: serde_json::Value = serde_json::from_str(&contents).ok()?;
+    let current = credentials.get("current")?.as_str()?;
+
+    credentials
+        .get("accounts")?
+        .get(current)?
+        .get("username")?
+        .as_str()
+        .map(String::from)
+}
@@ -2,7 +2,6 @@ use super::{Context, Module, RootModuleConfig};
 
 use crate::configs::purescript::PureScriptConfig;
 use crate::formatter::StringFormatter;
-use crate::utils;
 
 /// Creates a module with the current PureScript version
 ///
@@ -20,7 +19,7 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
         return None;
     }
 
-    let purs_version = utils::exec_cmd("purs", &["--version"])?.stdout;
+    let purs_version = context.exec_cmd("purs", &["--version"])?.stdout;
 
     let mut module = context.new_module("purescript");
     let config: PureScriptConfig = PureScriptConfig::try_load(module.config);
@@ -15,6 +15,9 @@ use crate::utils;
 ///     - Current directory contains a file with the `.py` extension
 ///     - Current directory contains a `Pipfile` file
 ///     - Current directory contains a `tox.ini` file
+///
+/// The version pinned in the nearest `.python-version` file is preferred over the
+/// one reported by `python`.
 pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let mut module = context.new_module("python");
     let config: PythonConfig = PythonConfig::try_load(module.config);
@@ -43,10 +46,15 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     }
 
     let python_version = if config.pyenv_version_name {
-        utils::exec_cmd("pyenv", &["version-name"])?.stdout
+        context.exec_cmd("pyenv", &["version-name"])?.stdout
     } else {
-        let version = get_python_version(&config.python_binary)?;
-        format_python_version(&version)
+        match get_pinned_python_version(context) {
+            Some(version) => version,
+            None => {
+                let version = get_python_version(context, &config.python_binary)?;
+                format_python_version(&version)
+            }
+        }
     };
     let virtual_env = get_python_virtual_env();
 
@@ -79,8 +87,32 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     Some(module)
 }
 
-fn get_python_version(python_binary: &str) -> Option<String> {
-    match utils::exec_cmd(python_binary, &["--version"]) {
+/// Looks for a `.python-version` file in the current directory and its parents, up to the
+/// repository root or the home directory
+///
+/// Pins naming a pyenv environment rather than a numeric version are ignored.
+fn get_pinned_python_version(context: &Context) -> Option<String> {
+    let version_file = context
+        .current_dir_ancestors()
+        .iter()
+        .map(|dir| dir.join(".python-version"))
+        .find(|path| path.is_file())?;
+    let contents = utils::read_file(version_file).ok()?;
+    format_pinned_python_version(&contents)
+}
+
+fn format_pinned_python_version(contents: &str) -> Option<String> {
+    // pyenv allows multiple versions to be pinned, the first one takes precedence
+    let version = contents.lines().next()?.trim();
+    if version.starts_with(|c: char| c.is_ascii_digit()) {
+        Some(format!("v{}", version))
+    } else {
+        None
+    }
+}
+
+fn get_python_version(context: &Context, python_binary: &str) -> Option<String> {
+    match context.exec_cmd(python_binary, &["--version"]) {
         Some(output) => {
             if output.stdout.is_empty() {
                 Some(output.stderr)
@@ -113,10 +145,24 @@ fn get_python_virtual_env() -> Option<String> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::modules::utils::test::render_module;
+    use crate::modules::utils::test::{render_module, render_module_with_env};
     use ansi_term::Color;
-    use std::fs::File;
-    use std::io;
+    use std::fs::{self, File};
+    use std::io::{self, Write};
+
+    #[test]
+    fn test_format_pinned_python_version() {
+        assert_eq!(
+            format_pinned_python_version("3.9.1\n"),
+            Some("v3.9.1".to_string())
+        );
+        assert_eq!(
+            format_pinned_python_version("3.9.1\n2.7.18\n"),
+            Some("v3.9.1".to_string())
+        );
+        assert_eq!(format_pinned_python_version("my_env\n"), None);
+        assert_eq!(format_pinned_python_version(""), None);
+    }
 
     #[test]
     fn test_format_python_version() {
@@ -150,6 +196,74 @@ mod tests {
         dir.close()
     }
 
+    #[test]
+    fn folder_with_pinned_python_version() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let mut file = File::create(dir.path().join(".python-version"))?;
+        file.write_all(b"3.9.1\n")?;
+        file.sync_all()?;
+
+        let actual = render_module("python", dir.path(), None);
+        let expected = Some(format!("via {} ", Color::Yellow.bold().paint("🐍 v3.9.1")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn subfolder_of_pinned_python_version() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let mut file = File::create(dir.path().join(".python-version"))?;
+        file.write_all(b"3.9.1\n")?;
+        file.sync_all()?;
+        let sub_dir = dir.path().join("src");
+        fs::create_dir(&sub_dir)?;
+        File::create(sub_dir.join("main.py"))?.sync_all()?;
+
+        let home = dir.path().to_str().unwrap();
+        let actual = render_module_with_env("python", &sub_dir, None, &[("HOME", home)]);
+        let expected = Some(format!("via {} ", Color::Yellow.bold().paint("🐍 v3.9.1")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn pinned_python_version_outside_of_home_directory_is_ignored() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let mut file = File::create(dir.path().join(".python-version"))?;
+        file.write_all(b"3.9.1\n")?;
+        file.sync_all()?;
+        let home_dir = dir.path().join("home");
+        let project_dir = home_dir.join("project");
+        fs::create_dir_all(&project_dir)?;
+        File::create(project_dir.join("main.py"))?.sync_all()?;
+
+        let home = home_dir.to_str().unwrap();
+        let actual = render_module_with_env("python", &project_dir, None, &[("HOME", home)]);
+        let expected = Some(format!("via {} ", Color::Yellow.bold().paint("🐍 v2.7.17")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_pinned_python_version_and_pyenv_version_name() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let mut file = File::create(dir.path().join(".python-version"))?;
+        file.write_all(b"3.9.1\n")?;
+        file.sync_all()?;
+
+        let config = toml::toml! {
+            [python]
+            pyenv_version_name = true
+        };
+        let actual = render_module("python", dir.path(), Some(config));
+        let expected = Some(format!(
+            "via {} ",
+            Color::Yellow.bold().paint("🐍 my_pyenv")
+        ));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
     #[test]
     fn folder_with_requirements_txt() -> io::Result<()> {
         let dir = tempfile::tempdir()?;
@@ -0,0 +1,163 @@
+use super::{Context, Module, RootModuleConfig};
+
+use crate::configs::r::RConfig;
+use crate::formatter::StringFormatter;
+
+/// Creates a module with the current R version
+///
+/// Will display the R version if any of the following criteria are met:
+///     - Current directory contains a file with the `.R`, `.Rmd` or `.Rproj` extension, in any
+///       case
+///     - Current directory contains a `DESCRIPTION` file
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let is_r_project = context
+        .try_begin_scan()?
+        .set_files(&["DESCRIPTION"])
+        .set_extensions_case_insensitive(&["r", "rmd", "rproj"])
+        .is_match();
+
+    if !is_r_project {
+        return None;
+    }
+
+    let mut module = context.new_module("r");
+    let config: RConfig = RConfig::try_load(module.config);
+
+    let parsed = StringFormatter::new(config.format).and_then(|formatter| {
+        formatter
+            .map_meta(|var, _| match var {
+                "symbol" => Some(config.symbol),
+                _ => None,
+            })
+            .map_style(|variable| match variable {
+                "style" => Some(Ok(config.style)),
+                _ => None,
+            })
+            .map(|variable| match variable {
+                "version" => {
+                    let output = context.exec_cmd("R", &["--version"])?;
+                    // Some builds of R print their banner to stderr
+                    parse_r_version(&output.stdout)
+                        .or_else(|| parse_r_version(&output.stderr))
+                        .map(Ok)
+                }
+                _ => None,
+            })
+            .parse(None)
+    });
+
+    module.set_segments(match parsed {
+        Ok(segments) => segments,
+        Err(error) => {
+            log::warn!("Error in module `r`:\n{}", error);
+            return None;
+        }
+    });
+
+    Some(module)
+}
+
+fn parse_r_version(r_version: &str) -> Option<String> {
+    let version = r_version
+        .lines()
+        // find the line looking like `R version 4.1.0 (2021-05-18) -- "Camp Pontanezen"`
+        .find_map(|line| line.trim_start().strip_prefix("R version "))?
+        .split_whitespace()
+        // return "4.1.0"
+        .next()?;
+
+    Some(format!("v{}", version))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_r_version;
+    use crate::modules::utils::test::render_module_with_commands;
+    use ansi_term::Color;
+    use std::fs::File;
+    use std::io;
+
+    const R_BANNER: &str = "\
+R version 4.1.0 (2021-05-18) -- \"Camp Pontanezen\"
+Copyright (C) 2021 The R Foundation for Statistical Computing
+Platform: x86_64-pc-linux-gnu (64-bit)
+
+R is free software and comes with ABSOLUTELY NO WARRANTY.
+You are welcome to redistribute it under the terms of the
+GNU General Public License versions 2 or 3.
+For more information about these matters see
+https://www.gnu.org/licenses/.\n";
+
+    const R_VERSION: &[(&str, &str)] = &[("R --version", R_BANNER)];
+
+    #[test]
+    fn test_parse_r_version() {
+        assert_eq!(parse_r_version(R_BANNER), Some("v4.1.0".to_string()));
+
+        let input = "\nR version 3.6.3 (2020-02-29) -- \"Holding the Windsock\"\n";
+        assert_eq!(parse_r_version(input), Some("v3.6.3".to_string()));
+
+        let input = "R: command not found";
+        assert_eq!(parse_r_version(input), None);
+    }
+
+    #[test]
+    fn folder_without_r_files() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join("description.txt"))?.sync_all()?;
+
+        let actual = render_module_with_commands("r", dir.path(), None, R_VERSION);
+
+        let expected = None;
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_rproj_file() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join("analysis.Rproj"))?.sync_all()?;
+
+        let actual = render_module_with_commands("r", dir.path(), None, R_VERSION);
+
+        let expected = Some(format!("via {} ", Color::Blue.bold().paint("📐 v4.1.0")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_r_file() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join("plot.R"))?.sync_all()?;
+
+        let actual = render_module_with_commands("r", dir.path(), None, R_VERSION);
+
+        let expected = Some(format!("via {} ", Color::Blue.bold().paint("📐 v4.1.0")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_rmd_file() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join("report.Rmd"))?.sync_all()?;
+
+        let actual = render_module_with_commands("r", dir.path(), None, R_VERSION);
+
+        let expected = Some(format!("via {} ", Color::Blue.bold().paint("📐 v4.1.0")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_description_file() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join("DESCRIPTION"))?.sync_all()?;
+
+        let actual = render_module_with_commands("r", dir.path(), None, R_VERSION);
+
+        let expected = Some(format!("via {} ", Color::Blue.bold().paint("📐 v4.1.0")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+}
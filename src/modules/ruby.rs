@@ -8,11 +8,14 @@ use crate::utils;
 ///
 /// Will display the Ruby version if any of the following criteria are met:
 ///     - Current directory contains a `.rb` file
-///     - Current directory contains a `Gemfile` or `.ruby-version` file
+///     - Current directory contains a `Gemfile`, `.ruby-version` or `.rbenv-version` file
+///
+/// The version pinned in `.ruby-version` or `.rbenv-version` is preferred over the one
+/// reported by `ruby`.
 pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let is_rb_project = context
         .try_begin_scan()?
-        .set_files(&["Gemfile", ".ruby-version"])
+        .set_files(&["Gemfile", ".ruby-version", ".rbenv-version"])
         .set_extensions(&["rb"])
         .is_match();
 
@@ -33,9 +36,9 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
                 _ => None,
             })
             .map(|variable| match variable {
-                "version" => {
-                    format_ruby_version(&utils::exec_cmd("ruby", &["-v"])?.stdout.as_str()).map(Ok)
-                }
+                "version" => get_pinned_ruby_version(context)
+                    .or_else(|| format_ruby_version(&context.exec_cmd("ruby", &["-v"])?.stdout))
+                    .map(Ok),
                 _ => None,
             })
             .parse(None)
@@ -52,6 +55,27 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     Some(module)
 }
 
+fn get_pinned_ruby_version(context: &Context) -> Option<String> {
+    [".ruby-version", ".rbenv-version"].iter().find_map(|file| {
+        let version_file = utils::read_file(context.current_dir.join(file)).ok()?;
+        format_pinned_ruby_version(&version_file)
+    })
+}
+
+fn format_pinned_ruby_version(pinned_version: &str) -> Option<String> {
+    // Version files contain e.g. `2.7.1`, or `ruby-2.7.1` when written by rvm. Pins
+    // like `system` or `jruby-9.2.14.0` don't say which version of ruby will run.
+    let version = pinned_version.trim();
+    let version = version.strip_prefix("ruby-").unwrap_or(version);
+    let is_version = utils::is_dotted_version(version);
+
+    if is_version {
+        Some(format!("v{}", version))
+    } else {
+        None
+    }
+}
+
 fn format_ruby_version(ruby_version: &str) -> Option<String> {
     let version = ruby_version
         // split into ["ruby", "2.6.0p0", "linux/amd64"]
@@ -75,7 +99,7 @@ mod tests {
     use crate::modules::utils::test::render_module;
     use ansi_term::Color;
     use std::fs::File;
-    use std::io;
+    use std::io::{self, Write};
 
     #[test]
     fn folder_without_ruby_files() -> io::Result<()> {
@@ -112,6 +136,48 @@ mod tests {
         dir.close()
     }
 
+    #[test]
+    fn folder_with_pinned_ruby_version() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let mut file = File::create(dir.path().join(".ruby-version"))?;
+        file.write_all(b"2.7.1\n")?;
+        file.sync_all()?;
+
+        let actual = render_module("ruby", dir.path(), None);
+
+        let expected = Some(format!("via {} ", Color::Red.bold().paint("💎 v2.7.1")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_rbenv_version() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let mut file = File::create(dir.path().join(".rbenv-version"))?;
+        file.write_all(b"ruby-2.6.6\n")?;
+        file.sync_all()?;
+
+        let actual = render_module("ruby", dir.path(), None);
+
+        let expected = Some(format!("via {} ", Color::Red.bold().paint("💎 v2.6.6")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_system_ruby_version() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let mut file = File::create(dir.path().join(".ruby-version"))?;
+        file.write_all(b"system\n")?;
+        file.sync_all()?;
+
+        let actual = render_module("ruby", dir.path(), None);
+
+        let expected = Some(format!("via {} ", Color::Red.bold().paint("💎 v2.5.1")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
     #[test]
     fn folder_with_rb_file() -> io::Result<()> {
         let dir = tempfile::tempdir()?;
@@ -140,7 +206,26 @@ mod tests {
             ),
             Some("v2.7.0".to_string())
         );
+        assert_eq!(
+            format_ruby_version("ruby 2.7.1p83 (2020-03-31 revision a0c7c23c9c) [x86_64-linux]"),
+            Some("v2.7.1".to_string())
+        );
 
         Ok(())
     }
+
+    #[test]
+    fn test_format_pinned_ruby_version() {
+        assert_eq!(
+            format_pinned_ruby_version("2.7.1\n"),
+            Some("v2.7.1".to_string())
+        );
+        assert_eq!(
+            format_pinned_ruby_version("ruby-3.0.0"),
+            Some("v3.0.0".to_string())
+        );
+        assert_eq!(format_pinned_ruby_version("jruby-9.2.14.0"), None);
+        assert_eq!(format_pinned_ruby_version("system"), None);
+        assert_eq!(format_pinned_ruby_version(""), None);
+    }
 }
@@ -63,7 +63,7 @@ fn get_module_version(context: &Context) -> Option<String> {
     // check
     // 1. `$RUSTUP_TOOLCHAIN`
     // 2. `rustup override list`
-    // 3. `rust-toolchain` in `.` or parent directories
+    // 3. `rust-toolchain` or `rust-toolchain.toml` in `.` or parent directories
     // as `rustup` does.
     // https://github.com/rust-lang/rustup.rs/tree/eb694fcada7becc5d9d160bf7c623abe84f8971d#override-precedence
     //
@@ -124,33 +124,56 @@ fn extract_toolchain_from_rustup_override_list(stdout: &str, cwd: &Path) -> Opti
 }
 
 fn find_rust_toolchain_file(context: &Context) -> Option<String> {
-    // Look for 'rust-toolchain' as rustup does.
+    // Look for 'rust-toolchain' or 'rust-toolchain.toml' as rustup does.
     // https://github.com/rust-lang/rustup.rs/blob/d84e6e50126bccd84649e42482fc35a11d019401/src/config.rs#L320-L358
 
-    fn read_first_line(path: &Path) -> Option<String> {
-        let content = fs::read_to_string(path).ok()?;
-        let line = content.lines().next()?;
-        Some(line.trim().to_owned())
-    }
-
     if let Ok(true) = context
         .dir_contents()
-        .map(|dir| dir.has_file("rust-toolchain"))
+        .map(|dir| dir.has_file("rust-toolchain") || dir.has_file("rust-toolchain.toml"))
     {
-        if let Some(toolchain) = read_first_line(Path::new("rust-toolchain")) {
+        if let Some(toolchain) = read_toolchain_files(&context.current_dir) {
             return Some(toolchain);
         }
     }
 
-    let mut dir = &*context.current_dir;
+    let mut dir = context.current_dir.parent()?;
     loop {
-        if let Some(toolchain) = read_first_line(&dir.join("rust-toolchain")) {
+        if let Some(toolchain) = read_toolchain_files(dir) {
             return Some(toolchain);
         }
         dir = dir.parent()?;
     }
 }
 
+fn read_toolchain_files(dir: &Path) -> Option<String> {
+    fs::read_to_string(dir.join("rust-toolchain"))
+        .ok()
+        .and_then(|contents| parse_toolchain_file(&contents, true))
+        .or_else(|| {
+            let contents = fs::read_to_string(dir.join("rust-toolchain.toml")).ok()?;
+            parse_toolchain_file(&contents, false)
+        })
+}
+
+/// Reads the channel out of a toolchain file
+///
+/// A legacy `rust-toolchain` file may contain nothing but the channel name, while the
+/// TOML form specifies it as `channel = "..."` in the `[toolchain]` table.
+fn parse_toolchain_file(contents: &str, legacy_allowed: bool) -> Option<String> {
+    let trimmed = contents.trim();
+    if legacy_allowed && trimmed.lines().count() == 1 && !trimmed.contains('=') {
+        return Some(trimmed.to_owned());
+    }
+
+    let toml = toml::from_str::<toml::Value>(contents).ok()?;
+    let channel = toml.get("toolchain")?.get("channel")?.as_str()?.trim();
+    if channel.is_empty() {
+        None
+    } else {
+        Some(channel.to_owned())
+    }
+}
+
 fn execute_rustup_run_rustc_version(toolchain: &str) -> RustupRunRustcVersionOutcome {
     Command::new("rustup")
         .args(&["run", toolchain, "rustc", "--version"])
@@ -200,10 +223,69 @@ enum RustupRunRustcVersionOutcome {
 #[cfg(test)]
 mod tests {
     use once_cell::sync::Lazy;
+    use std::fs::File;
+    use std::io::{self, Write};
     use std::process::{ExitStatus, Output};
 
     use super::*;
 
+    #[test]
+    fn test_parse_toolchain_file() {
+        assert_eq!(
+            parse_toolchain_file("nightly-2020-07-10\n", true),
+            Some("nightly-2020-07-10".to_owned())
+        );
+        assert_eq!(parse_toolchain_file("nightly-2020-07-10\n", false), None);
+
+        let toml_input = "[toolchain]\nchannel = \"1.45.0\"\ncomponents = [\"rustfmt\"]\n";
+        assert_eq!(
+            parse_toolchain_file(toml_input, true),
+            Some("1.45.0".to_owned())
+        );
+        assert_eq!(
+            parse_toolchain_file(toml_input, false),
+            Some("1.45.0".to_owned())
+        );
+
+        assert_eq!(parse_toolchain_file("[toolchain]\n", false), None);
+        assert_eq!(parse_toolchain_file("", true), None);
+    }
+
+    #[test]
+    fn test_find_rust_toolchain_file_legacy() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let mut file = File::create(dir.path().join("rust-toolchain"))?;
+        file.write_all(b"beta\n")?;
+        file.sync_all()?;
+
+        let context = Context::new_with_dir(clap::ArgMatches::default(), dir.path());
+        assert_eq!(find_rust_toolchain_file(&context), Some("beta".to_owned()));
+        dir.close()
+    }
+
+    #[test]
+    fn test_find_rust_toolchain_file_toml() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let mut file = File::create(dir.path().join("rust-toolchain.toml"))?;
+        file.write_all(b"[toolchain]\nchannel = \"nightly-2020-07-10\"\n")?;
+        file.sync_all()?;
+        let sub_dir = dir.path().join("src");
+        fs::create_dir(&sub_dir)?;
+
+        let context = Context::new_with_dir(clap::ArgMatches::default(), dir.path());
+        assert_eq!(
+            find_rust_toolchain_file(&context),
+            Some("nightly-2020-07-10".to_owned())
+        );
+
+        let context = Context::new_with_dir(clap::ArgMatches::default(), &sub_dir);
+        assert_eq!(
+            find_rust_toolchain_file(&context),
+            Some("nightly-2020-07-10".to_owned())
+        );
+        dir.close()
+    }
+
     #[test]
     fn test_extract_toolchain_from_rustup_override_list() {
         static NO_OVERRIDES_INPUT: &str = "no overrides\n";
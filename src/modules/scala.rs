@@ -0,0 +1,158 @@
+use super::{Context, Module, RootModuleConfig};
+
+use crate::configs::scala::ScalaConfig;
+use crate::formatter::StringFormatter;
+use crate::utils;
+
+/// Creates a module with the current Scala version
+///
+/// Will display the Scala version if any of the following criteria are met:
+///     - Current directory contains a `build.sbt`, `.scalaenv`, `.sbtenv` or `.scala-version` file
+///     - Current directory contains a file with the `.scala` or `.sc` extension
+///
+/// The version pinned in `.scala-version` is preferred over the one reported by `scalac`.
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let is_scala_project = context
+        .try_begin_scan()?
+        .set_files(&["build.sbt", ".scalaenv", ".sbtenv", ".scala-version"])
+        .set_extensions(&["scala", "sc"])
+        .is_match();
+
+    if !is_scala_project {
+        return None;
+    }
+
+    let scala_version = get_pinned_scala_version(context).or_else(|| {
+        // `scalac -version` prints its version to stderr, and some releases exit with a
+        // non-zero status while doing so
+        let output = context.exec_cmd_allow_failure("scalac", &["-version"])?;
+        let version_output = if output.stderr.trim().is_empty() {
+            output.stdout
+        } else {
+            output.stderr
+        };
+        parse_scalac_version(&version_output)
+    })?;
+
+    let mut module = context.new_module("scala");
+    let config: ScalaConfig = ScalaConfig::try_load(module.config);
+
+    let parsed = StringFormatter::new(config.format).and_then(|formatter| {
+        formatter
+            .map_meta(|var, _| match var {
+                "symbol" => Some(config.symbol),
+                _ => None,
+            })
+            .map_style(|variable| match variable {
+                "style" => Some(Ok(config.style)),
+                _ => None,
+            })
+            .map(|variable| match variable {
+                "version" => Some(Ok(&scala_version)),
+                _ => None,
+            })
+            .parse(None)
+    });
+
+    module.set_segments(match parsed {
+        Ok(segments) => segments,
+        Err(error) => {
+            log::warn!("Error in module `scala`:\n{}", error);
+            return None;
+        }
+    });
+
+    Some(module)
+}
+
+fn get_pinned_scala_version(context: &Context) -> Option<String> {
+    let version_file = utils::read_file(context.current_dir.join(".scala-version")).ok()?;
+    let version = version_file.trim();
+
+    if version.is_empty() {
+        return None;
+    }
+
+    Some(format!("v{}", version))
+}
+
+fn parse_scalac_version(scalac_version: &str) -> Option<String> {
+    // scalac -version output looks like this:
+    // Scala compiler version 2.13.5 -- Copyright 2002-2020, LAMP/EPFL and Lightbend, Inc.
+    let version = scalac_version
+        // split into ["", "2.13.5 -- Copyright ..."]
+        .split("Scala compiler version")
+        // return "2.13.5"
+        .nth(1)?
+        .split_whitespace()
+        .next()?;
+
+    Some(format!("v{}", version))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::utils::test::render_module;
+    use ansi_term::Color;
+    use std::fs::{self, File};
+    use std::io;
+
+    #[test]
+    fn test_parse_scalac_version() {
+        let input =
+            "Scala compiler version 2.13.5 -- Copyright 2002-2020, LAMP/EPFL and Lightbend, Inc.\n";
+        assert_eq!(parse_scalac_version(input), Some("v2.13.5".to_string()));
+
+        let input = "scalac: command not found";
+        assert_eq!(parse_scalac_version(input), None);
+    }
+
+    #[test]
+    fn folder_without_scala_files() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+
+        let actual = render_module("scala", dir.path(), None);
+
+        let expected = None;
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_build_sbt() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join("build.sbt"))?.sync_all()?;
+
+        let actual = render_module("scala", dir.path(), None);
+
+        let expected = Some(format!("via {} ", Color::Red.dimmed().paint("🆂 v2.13.5")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_scala_file() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join("Main.scala"))?.sync_all()?;
+
+        let actual = render_module("scala", dir.path(), None);
+
+        let expected = Some(format!("via {} ", Color::Red.dimmed().paint("🆂 v2.13.5")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_scala_version_pin() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join("build.sbt"))?.sync_all()?;
+        fs::write(dir.path().join(".scala-version"), "2.12.13\n")?;
+
+        let actual = render_module("scala", dir.path(), None);
+
+        let expected = Some(format!("via {} ", Color::Red.dimmed().paint("🆂 v2.12.13")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+}
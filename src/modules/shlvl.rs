@@ -0,0 +1,71 @@
+use super::{Context, Module, RootModuleConfig};
+
+use crate::configs::shlvl::ShLvlConfig;
+use crate::formatter::StringFormatter;
+
+const SHLVL_ENV_VAR: &str = "SHLVL";
+
+/// Creates a module with the current shell level
+///
+/// Will display the shell level if all of the following criteria are met:
+///     - shlvl.disabled is set to false
+///     - the `SHLVL` environment variable is set to a number
+///     - the shell level exceeds shlvl.threshold
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let shlvl = get_shell_level(context)?;
+
+    let mut module = context.new_module("shlvl");
+    let config: ShLvlConfig = ShLvlConfig::try_load(module.config);
+
+    // As we default to disabled=true, we have to check here after loading our config module,
+    // before it was only checking against whatever is in the config starship.toml
+    if config.disabled || shlvl <= config.threshold {
+        return None;
+    }
+
+    let shlvl_str = shlvl.to_string();
+    let symbol = if config.repeat {
+        let count = (shlvl.max(0) as usize).min(config.repeat_limit);
+        config.symbol.repeat(count)
+    } else {
+        config.symbol.to_string()
+    };
+
+    let parsed = StringFormatter::new(config.format).and_then(|formatter| {
+        formatter
+            .map_meta(|var, _| match var {
+                "symbol" => Some(symbol.as_str()),
+                _ => None,
+            })
+            .map_style(|variable| match variable {
+                "style" => Some(Ok(config.style)),
+                _ => None,
+            })
+            .map(|variable| match variable {
+                "shlvl" => Some(Ok(&shlvl_str)),
+                _ => None,
+            })
+            .parse(None)
+    });
+
+    module.set_segments(match parsed {
+        Ok(segments) => segments,
+        Err(error) => {
+            log::warn!("Error in module `shlvl`:\n{}", error);
+            return None;
+        }
+    });
+
+    Some(module)
+}
+
+fn get_shell_level(context: &Context) -> Option<i64> {
+    let shlvl = context.get_env(SHLVL_ENV_VAR)?;
+    match shlvl.trim().parse::<i64>() {
+        Ok(level) => Some(level),
+        Err(error) => {
+            log::debug!("Unable to parse ${}={:?}: {}", SHLVL_ENV_VAR, shlvl, error);
+            None
+        }
+    }
+}
@@ -7,10 +7,12 @@ use crate::formatter::StringFormatter;
 
 /// Creates a module with the current Singularity image
 ///
-/// Will display the Singularity image if `$SINGULARITY_NAME` is set.
+/// Will display the Singularity image if `$SINGULARITY_NAME` is set and not empty.
 pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
-    let singularity_env = env::var("SINGULARITY_NAME").ok();
-    singularity_env.as_ref()?;
+    let singularity_env = env::var("SINGULARITY_NAME").ok()?;
+    if singularity_env.trim().is_empty() {
+        return None;
+    }
 
     let mut module = context.new_module("singularity");
     let config: SingularityConfig = SingularityConfig::try_load(module.config);
@@ -26,7 +28,7 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
                 _ => None,
             })
             .map(|variable| match variable {
-                "env" => singularity_env.as_ref().map(Ok),
+                "env" => Some(Ok(&singularity_env)),
                 _ => None,
             })
             .parse(None)
@@ -35,7 +37,7 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     module.set_segments(match parsed {
         Ok(segments) => segments,
         Err(error) => {
-            log::warn!("Error in module `singularity`: \n{}", error);
+            log::warn!("Error in module `singularity`:\n{}", error);
             return None;
         }
     });
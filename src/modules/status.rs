@@ -0,0 +1,101 @@
+use super::{Context, Module, RootModuleConfig};
+
+use crate::configs::status::StatusConfig;
+use crate::formatter::StringFormatter;
+
+/// Creates a module with the status of the last command
+///
+/// Will display the exit code of the last command if it was not `0`, or always
+/// if `always` is set to `true`. When `map_symbol` is set, exit codes above 128
+/// that correspond to a signal are shown as the signal name (e.g. `SIGKILL`).
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let mut module = context.new_module("status");
+    let config: StatusConfig = StatusConfig::try_load(module.config);
+
+    // As we default to disabled=true, we have to check here after loading our config module,
+    // before it was only checking against whatever is in the config starship.toml
+    if config.disabled {
+        return None;
+    };
+
+    let exit_code = context.status_code.as_str();
+    let exit_success = exit_code == "0";
+
+    if exit_success && !config.always {
+        return None;
+    }
+
+    let symbol = if exit_success {
+        config.success_symbol
+    } else {
+        config.symbol
+    };
+
+    let status = if config.map_symbol {
+        exit_code
+            .parse::<i64>()
+            .ok()
+            .and_then(signal_name)
+            .unwrap_or(exit_code)
+    } else {
+        exit_code
+    };
+
+    let parsed = StringFormatter::new(config.format).and_then(|formatter| {
+        formatter
+            .map_meta(|var, _| match var {
+                "symbol" => Some(symbol),
+                _ => None,
+            })
+            .map_style(|variable| match variable {
+                "style" => Some(Ok(config.style)),
+                _ => None,
+            })
+            .map(|variable| match variable {
+                "status" => Some(Ok(status)),
+                _ => None,
+            })
+            .parse(None)
+    });
+
+    module.set_segments(match parsed {
+        Ok(segments) => segments,
+        Err(error) => {
+            log::warn!("Error in module `status`:\n{}", error);
+            return None;
+        }
+    });
+
+    Some(module)
+}
+
+/// Shells report a command killed by signal `n` with the exit code `128 + n`, the signal
+/// numbers are looked up for the platform since they differ between unix flavors
+#[cfg(not(target_os = "windows"))]
+fn signal_name(exit_code: i64) -> Option<&'static str> {
+    use nix::sys::signal::Signal;
+    use std::convert::TryFrom;
+
+    let signal_number = i32::try_from(exit_code - 128).ok()?;
+    Signal::try_from(signal_number).ok().map(Signal::as_str)
+}
+
+#[cfg(target_os = "windows")]
+fn signal_name(_exit_code: i64) -> Option<&'static str> {
+    None
+}
+
+#[cfg(all(test, not(target_os = "windows")))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_signal_name() {
+        assert_eq!(signal_name(130), Some("SIGINT"));
+        assert_eq!(signal_name(137), Some("SIGKILL"));
+        assert_eq!(signal_name(143), Some("SIGTERM"));
+        assert_eq!(signal_name(1), None);
+        assert_eq!(signal_name(128), None);
+        assert_eq!(signal_name(200), None);
+    }
+}
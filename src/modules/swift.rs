@@ -2,7 +2,6 @@ use super::{Context, Module, RootModuleConfig};
 
 use crate::configs::swift::SwiftConfig;
 use crate::formatter::StringFormatter;
-use crate::utils;
 
 /// Creates a module with the current Swift version
 ///
@@ -19,7 +18,7 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
         return None;
     }
 
-    let swift_version = utils::exec_cmd("swift", &["--version"])?.stdout;
+    let swift_version = context.exec_cmd("swift", &["--version"])?.stdout;
 
     let mut module = context.new_module("swift");
     let config: SwiftConfig = SwiftConfig::try_load(module.config);
@@ -39,7 +39,7 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
             })
             .map(|variable| match variable {
                 "version" => format_terraform_version(
-                    &utils::exec_cmd("terraform", &["version"])?.stdout.as_str(),
+                    &context.exec_cmd("terraform", &["version"])?.stdout.as_str(),
                 )
                 .map(Ok),
                 "workspace" => get_terraform_workspace(&context.current_dir).map(Ok),
@@ -74,7 +74,7 @@ fn get_terraform_workspace(cwd: &PathBuf) -> Option<String> {
     };
     match utils::read_file(datadir.join("environment")) {
         Err(ref e) if e.kind() == io::ErrorKind::NotFound => Some("default".to_string()),
-        Ok(s) => Some(s),
+        Ok(s) => Some(s.trim().to_string()),
         _ => None,
     }
 }
@@ -191,4 +191,49 @@ is 0.12.14. You can update by downloading from www.terraform.io/downloads.html
         assert_eq!(expected, actual);
         Ok(())
     }
+
+    #[test]
+    fn folder_with_dotterraform_no_version_with_environment() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let tf_dir = dir.path().join(".terraform");
+        fs::create_dir(&tf_dir)?;
+        let mut file = File::create(tf_dir.join("environment"))?;
+        file.write_all(b"staging\n")?;
+        file.sync_all()?;
+
+        let actual = render_module("terraform", dir.path(), None);
+
+        let expected = Some(format!(
+            "via {} ",
+            Color::Fixed(105).bold().paint("💠 staging")
+        ));
+        assert_eq!(expected, actual);
+        Ok(())
+    }
+
+    #[test]
+    fn folder_with_tf_file_no_environment() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join("main.tf"))?.sync_all()?;
+
+        let actual = render_module("terraform", dir.path(), None);
+
+        let expected = Some(format!(
+            "via {} ",
+            Color::Fixed(105).bold().paint("💠 default")
+        ));
+        assert_eq!(expected, actual);
+        Ok(())
+    }
+
+    #[test]
+    fn folder_without_terraform_files() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+
+        let actual = render_module("terraform", dir.path(), None);
+
+        let expected = None;
+        assert_eq!(expected, actual);
+        Ok(())
+    }
 }
@@ -12,9 +12,12 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
         return None;
     };
 
+    let utc_now = now();
+    let local_now = utc_now.with_timezone(&Local);
+
     // Hide prompt if current time is not inside time_range
     let (display_start, display_end) = parse_time_range(config.time_range);
-    let time_now = Local::now().time();
+    let time_now = local_now.time();
     if !is_inside_time_range(time_now, display_start, display_end) {
         return None;
     }
@@ -28,17 +31,17 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     );
 
     let formatted_time_string = if config.utc_time_offset != "local" {
-        match create_offset_time_string(Utc::now(), &config.utc_time_offset, &time_format) {
+        match create_offset_time_string(utc_now, &config.utc_time_offset, &time_format) {
             Ok(formatted_string) => formatted_string,
             Err(_) => {
                 log::warn!(
                     "Invalid utc_time_offset configuration provided! Falling back to \"local\"."
                 );
-                format_time(&time_format, Local::now())
+                format_time(&time_format, local_now)
             }
         }
     } else {
-        format_time(&time_format, Local::now())
+        format_time(&time_format, local_now)
     };
 
     let parsed = StringFormatter::new(config.format).and_then(|formatter| {
@@ -65,6 +68,19 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     Some(module)
 }
 
+/// Returns the current time
+#[cfg(not(test))]
+fn now() -> DateTime<Utc> {
+    Utc::now()
+}
+
+/// Returns a fixed point in time, so that the output of the module is deterministic in tests
+#[cfg(test)]
+fn now() -> DateTime<Utc> {
+    use chrono::offset::TimeZone;
+    Utc.ymd(2014, 7, 8).and_hms(15, 36, 47)
+}
+
 fn create_offset_time_string(
     utc_time: DateTime<Utc>,
     utc_time_offset_str: &str,
@@ -151,7 +167,10 @@ tests become extra important */
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::modules::utils::test::render_module;
+    use ansi_term::Color;
     use chrono::offset::TimeZone;
+    use std::path::Path;
 
     const FMT_12: &str = "%r";
     const FMT_24: &str = "%T";
@@ -368,6 +387,66 @@ mod tests {
             .expect("Invalid timezone offset.");
     }
 
+    #[test]
+    fn test_render_with_utc_time_offset() {
+        let actual = render_module(
+            "time",
+            Path::new("."),
+            Some(toml::toml! {
+                [time]
+                disabled = false
+                utc_time_offset = "-3"
+            }),
+        );
+
+        let expected = Some(format!("at {} ", Color::Yellow.bold().paint("12:36:47")));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_render_with_time_format() {
+        let actual = render_module(
+            "time",
+            Path::new("."),
+            Some(toml::toml! {
+                [time]
+                disabled = false
+                utc_time_offset = "0"
+                time_format = "%Y-%m-%d %H:%M"
+                format = "[$time]($style)"
+            }),
+        );
+
+        let expected = Some(format!(
+            "{}",
+            Color::Yellow.bold().paint("2014-07-08 15:36")
+        ));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_render_outside_time_range() {
+        // Timezone offsets are whole minutes, so the local time is always at second 47
+        let actual = render_module(
+            "time",
+            Path::new("."),
+            Some(toml::toml! {
+                [time]
+                disabled = false
+                time_range = "00:00:00-00:00:01"
+            }),
+        );
+
+        assert_eq!(None, actual);
+    }
+
+    #[test]
+    fn test_render_disabled_by_default() {
+        let actual = render_module("time", Path::new("."), None);
+
+        assert_eq!(None, actual);
+    }
+
     #[test]
     fn test_parse_invalid_time_range() {
         let time_range = "10:00:00-12:00:00-13:00:00";
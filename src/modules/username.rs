@@ -4,7 +4,6 @@ use super::{Context, Module, RootModuleConfig};
 
 use crate::configs::username::UsernameConfig;
 use crate::formatter::StringFormatter;
-use crate::utils;
 
 /// Creates a module with the current user's username
 ///
@@ -17,21 +16,21 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let logname = env::var("LOGNAME").ok();
     let ssh_connection = env::var("SSH_CONNECTION").ok();
 
-    const ROOT_UID: Option<u32> = Some(0);
-    let user_uid = get_uid();
+    let is_root = is_root_user(context);
 
     let mut module = context.new_module("username");
     let config: UsernameConfig = UsernameConfig::try_load(module.config);
 
-    if user != logname || ssh_connection.is_some() || user_uid == ROOT_UID || config.show_always {
+    if user != logname || ssh_connection.is_some() || is_root || config.show_always {
         let username = user?;
         let parsed = StringFormatter::new(config.format).and_then(|formatter| {
             formatter
                 .map_style(|variable| match variable {
                     "style" => {
-                        let module_style = match user_uid {
-                            Some(0) => config.style_root,
-                            _ => config.style_user,
+                        let module_style = if is_root {
+                            config.style_root
+                        } else {
+                            config.style_user
                         };
                         Some(Ok(module_style))
                     }
@@ -57,10 +56,16 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     }
 }
 
-fn get_uid() -> Option<u32> {
-    utils::exec_cmd("id", &["-u"])?
-        .stdout
-        .trim()
-        .parse::<u32>()
-        .ok()
+#[cfg(not(target_os = "windows"))]
+fn is_root_user(_context: &Context) -> bool {
+    nix::unistd::Uid::effective().is_root()
+}
+
+#[cfg(target_os = "windows")]
+fn is_root_user(context: &Context) -> bool {
+    const ROOT_UID: Option<u32> = Some(0);
+    let user_uid = context
+        .exec_cmd("id", &["-u"])
+        .and_then(|output| output.stdout.trim().parse::<u32>().ok());
+    user_uid == ROOT_UID
 }
@@ -59,4 +59,18 @@ mod tests {
             Err("Unable to stat() directory")
         );
     }
+
+    #[test]
+    fn read_only_temp_dir() -> std::io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        assert_eq!(is_write_allowed(dir.path()), Ok(true));
+
+        fs::set_permissions(dir.path(), fs::Permissions::from_mode(0o555))?;
+        // The superuser is allowed to write to any directory
+        let expected = Uid::effective().is_root();
+        assert_eq!(is_write_allowed(dir.path()), Ok(expected));
+
+        fs::set_permissions(dir.path(), fs::Permissions::from_mode(0o755))?;
+        dir.close()
+    }
 }
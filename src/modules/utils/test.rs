@@ -1,5 +1,6 @@
 use crate::config::StarshipConfig;
 use crate::context::{Context, Shell};
+use crate::utils::{CommandRunner, FakeCommandRunner};
 use std::path::Path;
 
 /// Render a specific starship module by name
@@ -8,9 +9,57 @@ pub fn render_module(
     path: &Path,
     config: Option<toml::Value>,
 ) -> Option<String> {
-    let mut context = Context::new_with_dir(clap::ArgMatches::default(), path);
-    context.config = StarshipConfig { config };
-    context.shell = Shell::Unknown;
+    crate::print::get_module(module_name, test_context(path, config))
+}
+
+/// Render a specific starship module by name, with only the given environment variables set
+pub fn render_module_with_env(
+    module_name: &str,
+    path: &Path,
+    config: Option<toml::Value>,
+    env: &[(&str, &str)],
+) -> Option<String> {
+    let mut context = test_context(path, config);
+    context.set_env(env);
 
     crate::print::get_module(module_name, context)
 }
+
+/// Render a specific starship module by name, with the commands it executes answered by `runner`
+pub fn render_module_with_runner<R: CommandRunner + 'static>(
+    module_name: &str,
+    path: &Path,
+    config: Option<toml::Value>,
+    runner: R,
+) -> Option<String> {
+    let mut context = test_context(path, config);
+    context.set_cmd_runner(runner);
+
+    crate::print::get_module(module_name, context)
+}
+
+/// Render a specific starship module by name, with only the given command lines (e.g.
+/// `perl --version`) running and printing their stdout
+pub fn render_module_with_commands(
+    module_name: &str,
+    path: &Path,
+    config: Option<toml::Value>,
+    commands: &[(&str, &str)],
+) -> Option<String> {
+    let runner = commands.iter().fold(
+        FakeCommandRunner::default(),
+        |runner, (command_line, stdout)| runner.with_output(command_line, stdout),
+    );
+
+    render_module_with_runner(module_name, path, config, runner)
+}
+
+fn test_context(path: &Path, config: Option<toml::Value>) -> Context<'static> {
+    let mut context = Context::new_with_dir(clap::ArgMatches::default(), path);
+    context.config = match config {
+        Some(config) => StarshipConfig::from_value(config),
+        None => StarshipConfig { config: None },
+    };
+    context.shell = Shell::Unknown;
+    context
+}
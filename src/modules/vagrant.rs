@@ -0,0 +1,115 @@
+use super::{Context, Module, RootModuleConfig};
+
+use crate::configs::vagrant::VagrantConfig;
+use crate::formatter::{StringFormatter, VersionFormatter};
+
+/// Creates a module with the current Vagrant version
+///
+/// Will display the Vagrant version if any of the following criteria are met:
+///     - Current directory contains a `Vagrantfile` file
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let is_vagrant_project = context
+        .try_begin_scan()?
+        .set_files(&["Vagrantfile"])
+        .is_match();
+
+    if !is_vagrant_project {
+        return None;
+    }
+
+    let mut module = context.new_module("vagrant");
+    let config: VagrantConfig = VagrantConfig::try_load(module.config);
+
+    let parsed = StringFormatter::new(config.format).and_then(|formatter| {
+        formatter
+            .map_meta(|var, _| match var {
+                "symbol" => Some(config.symbol),
+                _ => None,
+            })
+            .map_style(|variable| match variable {
+                "style" => Some(Ok(config.style)),
+                _ => None,
+            })
+            .map(|variable| match variable {
+                "version" => format_vagrant_version(
+                    &context.exec_cmd("vagrant", &["--version"])?.stdout,
+                    config.version_format,
+                )
+                .map(Ok),
+                _ => None,
+            })
+            .parse(None)
+    });
+
+    module.set_segments(match parsed {
+        Ok(segments) => segments,
+        Err(error) => {
+            log::warn!("Error in module `vagrant`:\n{}", error);
+            return None;
+        }
+    });
+
+    Some(module)
+}
+
+fn format_vagrant_version(vagrant_stdout: &str, version_format: &str) -> Option<String> {
+    // vagrant version output looks like this:
+    // Vagrant 2.2.10
+    let version = vagrant_stdout
+        // split into ["", "2.2.10"]
+        .split("Vagrant ")
+        // return "2.2.10"
+        .nth(1)?
+        .split_whitespace()
+        .next()?;
+
+    VersionFormatter::format_module_version("vagrant", version, version_format)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::utils::test::render_module;
+    use ansi_term::Color;
+    use std::fs::File;
+    use std::io;
+
+    #[test]
+    fn test_format_vagrant_version() {
+        let input = "Vagrant 2.2.10\n";
+        assert_eq!(
+            format_vagrant_version(input, "${raw}"),
+            Some("2.2.10".to_string())
+        );
+        assert_eq!(
+            format_vagrant_version(input, "v${major}.${minor}"),
+            Some("v2.2".to_string())
+        );
+
+        let input = "vagrant: command not found";
+        assert_eq!(format_vagrant_version(input, "${raw}"), None);
+    }
+
+    #[test]
+    fn folder_without_vagrant_file() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+
+        let actual = render_module("vagrant", dir.path(), None);
+
+        let expected = None;
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_vagrant_file() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join("Vagrantfile"))?.sync_all()?;
+
+        let actual = render_module("vagrant", dir.path(), None);
+
+        let expected = Some(format!("via {} ", Color::Cyan.bold().paint("⍱ 2.2.10")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+}
@@ -0,0 +1,142 @@
+use super::{Context, Module, RootModuleConfig};
+
+use crate::configs::vlang::VLangConfig;
+use crate::formatter::StringFormatter;
+
+/// Creates a module with the current V version
+///
+/// Will display the V version if any of the following criteria are met:
+///     - Current directory contains a `v.mod` file
+///     - Current directory contains a file with the `.v` extension, unless `only_with_vmod` is
+///       set, as Verilog sources share that extension
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let mut module = context.new_module("vlang");
+    let config: VLangConfig = VLangConfig::try_load(module.config);
+
+    let extensions: &[&str] = if config.only_with_vmod { &[] } else { &["v"] };
+    let is_v_project = context
+        .try_begin_scan()?
+        .set_files(&["v.mod"])
+        .set_extensions(extensions)
+        .is_match();
+
+    if !is_v_project {
+        return None;
+    }
+
+    let parsed = StringFormatter::new(config.format).and_then(|formatter| {
+        formatter
+            .map_meta(|var, _| match var {
+                "symbol" => Some(config.symbol),
+                _ => None,
+            })
+            .map_style(|variable| match variable {
+                "style" => Some(Ok(config.style)),
+                _ => None,
+            })
+            .map(|variable| match variable {
+                "version" => parse_v_version(&context.exec_cmd("v", &["version"])?.stdout).map(Ok),
+                _ => None,
+            })
+            .parse(None)
+    });
+
+    module.set_segments(match parsed {
+        Ok(segments) => segments,
+        Err(error) => {
+            log::warn!("Error in module `vlang`:\n{}", error);
+            return None;
+        }
+    });
+
+    Some(module)
+}
+
+fn parse_v_version(v_version: &str) -> Option<String> {
+    let version = v_version
+        // split into ["V", "0.2.2", "d7a6f0b"]
+        .split_whitespace()
+        // return "0.2.2"
+        .nth(1)?;
+
+    Some(format!("v{}", version))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_v_version;
+    use crate::modules::utils::test::render_module_with_commands;
+    use ansi_term::Color;
+    use std::fs::File;
+    use std::io;
+
+    const V_VERSION: &[(&str, &str)] = &[("v version", "V 0.2.2 d7a6f0b\n")];
+
+    #[test]
+    fn test_parse_v_version() {
+        let input = "V 0.2.2 d7a6f0b";
+        assert_eq!(parse_v_version(input), Some("v0.2.2".to_string()));
+
+        let input = "V 0.2 30c0659\n";
+        assert_eq!(parse_v_version(input), Some("v0.2".to_string()));
+
+        let input = "V";
+        assert_eq!(parse_v_version(input), None);
+    }
+
+    #[test]
+    fn folder_without_v_files() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+
+        let actual = render_module_with_commands("vlang", dir.path(), None, V_VERSION);
+
+        let expected = None;
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_v_mod_file() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join("v.mod"))?.sync_all()?;
+
+        let actual = render_module_with_commands("vlang", dir.path(), None, V_VERSION);
+
+        let expected = Some(format!("via {} ", Color::Blue.bold().paint("V v0.2.2")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_v_file() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join("hello.v"))?.sync_all()?;
+
+        let actual = render_module_with_commands("vlang", dir.path(), None, V_VERSION);
+
+        let expected = Some(format!("via {} ", Color::Blue.bold().paint("V v0.2.2")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_v_file_only_with_vmod() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join("counter.v"))?.sync_all()?;
+
+        let config = toml::toml! {
+            [vlang]
+            only_with_vmod = true
+        };
+        let actual =
+            render_module_with_commands("vlang", dir.path(), Some(config.clone()), V_VERSION);
+        let expected = None;
+        assert_eq!(expected, actual);
+
+        File::create(dir.path().join("v.mod"))?.sync_all()?;
+        let actual = render_module_with_commands("vlang", dir.path(), Some(config), V_VERSION);
+        let expected = Some(format!("via {} ", Color::Blue.bold().paint("V v0.2.2")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+}
@@ -2,15 +2,16 @@ use super::{Context, Module, RootModuleConfig};
 
 use crate::configs::zig::ZigConfig;
 use crate::formatter::StringFormatter;
-use crate::utils;
 
 /// Creates a module with the current Zig version
 ///
 /// Will display the Zig version if any of the following criteria are met:
+///     - The current directory contains a `build.zig` file
 ///     - The current directory contains a file with extension `.zig`
 pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let is_zig_project = context
         .try_begin_scan()?
+        .set_files(&["build.zig"])
         .set_extensions(&["zig"])
         .is_match();
 
@@ -18,7 +19,8 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
         return None;
     }
 
-    let zig_version_output = utils::exec_cmd("zig", &["version"])?
+    let zig_version_output = context
+        .exec_cmd("zig", &["version"])?
         .stdout
         .trim()
         .to_string();
@@ -81,4 +83,23 @@ mod tests {
         assert_eq!(expected, actual);
         dir.close()
     }
+
+    #[test]
+    fn folder_with_build_zig() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join("build.zig"))?.sync_all()?;
+        let actual = render_module("zig", dir.path(), None);
+        let expected = Some(format!("via {} ", Color::Yellow.bold().paint("↯ v0.6.0")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_without_files() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let actual = render_module("zig", dir.path(), None);
+        let expected = None;
+        assert_eq!(expected, actual);
+        dir.close()
+    }
 }
@@ -4,15 +4,15 @@ use rayon::prelude::*;
 use std::collections::BTreeSet;
 use std::fmt::{self, Debug, Write as FmtWrite};
 use std::io::{self, Write};
-use unicode_width::UnicodeWidthChar;
 
 use crate::configs::PROMPT_ORDER;
-use crate::context::{Context, Shell};
+use crate::context::{Context, Shell, Target};
 use crate::formatter::{StringFormatter, VariableHolder};
 use crate::module::Module;
 use crate::module::ALL_MODULES;
 use crate::modules;
 use crate::segment::Segment;
+use crate::utils;
 
 pub fn prompt(args: ArgMatches) {
     let context = Context::new(args);
@@ -21,29 +21,73 @@ pub fn prompt(args: ArgMatches) {
     write!(handle, "{}", get_prompt(context)).unwrap();
 }
 
+/// Whether the prompt starts with a new line. Before `add_newline` existed, the new line
+/// was part of the default `format`, so a custom `format` keeps the prompt it had unless
+/// `add_newline` is set as well.
+fn adds_newline(context: &Context, format: &str, add_newline: bool) -> bool {
+    if format.starts_with('\n') {
+        return false;
+    }
+
+    let is_configured = |key| {
+        context
+            .config
+            .config
+            .as_ref()
+            .and_then(|config| config.get(key))
+            .is_some()
+    };
+    if is_configured("add_newline") {
+        add_newline
+    } else {
+        !is_configured("format")
+    }
+}
+
 pub fn get_prompt(context: Context) -> String {
     let config = context.config.get_root_config();
     let mut buf = String::new();
 
     // A workaround for a fish bug (see #739,#279). Applying it to all shells
     // breaks things (see #808,#824,#834). Should only be printed in fish.
-    if let Shell::Fish = context.shell {
+    if let (Shell::Fish, Target::Main) = (context.shell, context.target) {
         buf.push_str("\x1b[J"); // An ASCII control code to clear screen
     }
 
-    let formatter = if let Ok(formatter) = StringFormatter::new(config.format) {
+    if let Target::Main = context.target {
+        if adds_newline(&context, config.format, config.add_newline) {
+            buf.push('\n');
+        }
+    }
+
+    let format = match context.target {
+        Target::Main => config.format,
+        Target::Right => config.right_format,
+        Target::Continuation => {
+            return get_continuation_prompt(&context, config.continuation_prompt)
+        }
+    };
+
+    let formatter = if let Ok(formatter) = StringFormatter::new(format) {
         formatter
     } else {
         log::error!("Error parsing `format`");
         buf.push_str(">");
         return buf;
     };
-    let modules = formatter.get_variables();
+    let mut modules = formatter.get_variables();
+    if let Target::Main = context.target {
+        // Modules placed in the right prompt aren't repeated by `$all`
+        if let Ok(right_formatter) = StringFormatter::new(config.right_format) {
+            modules.extend(right_formatter.get_variables());
+        }
+    }
     let formatter = formatter.map_variables_to_segments(|module| {
-        // Make $all display all modules
+        // Make $all display all modules that aren't placed explicitly
         if module == "all" {
             Some(Ok(PROMPT_ORDER
                 .par_iter()
+                .filter(|module| !modules.contains(**module))
                 .flat_map(|module| {
                     handle_module(module, &context, &modules)
                         .into_iter()
@@ -69,6 +113,9 @@ pub fn get_prompt(context: Context) -> String {
             .parse(None)
             .expect("Unexpected error returned in root format variables"),
     );
+    if context.no_color() {
+        root_module.strip_styles();
+    }
 
     let module_strings = root_module.ansi_strings_for_shell(context.shell);
     write!(buf, "{}", ANSIStrings(&module_strings)).unwrap();
@@ -76,6 +123,27 @@ pub fn get_prompt(context: Context) -> String {
     buf
 }
 
+/// Render the continuation prompt, a plain format string without any modules
+fn get_continuation_prompt(context: &Context, continuation_prompt: &str) -> String {
+    let segments =
+        StringFormatter::new(continuation_prompt).and_then(|formatter| formatter.parse(None));
+
+    let mut root_module = Module::new("Starship Continuation", "The continuation prompt", None);
+    match segments {
+        Ok(segments) => root_module.set_segments(segments),
+        Err(error) => {
+            log::warn!("Error parsing `continuation_prompt`:\n{}", error);
+            return String::from("> ");
+        }
+    }
+    if context.no_color() {
+        root_module.strip_styles();
+    }
+
+    let module_strings = root_module.ansi_strings_for_shell(context.shell);
+    ANSIStrings(&module_strings).to_string()
+}
+
 pub fn module(module_name: &str, args: ArgMatches) {
     let context = Context::new(args);
     let module = get_module(module_name, context).unwrap_or_default();
@@ -83,7 +151,12 @@ pub fn module(module_name: &str, args: ArgMatches) {
 }
 
 pub fn get_module(module_name: &str, context: Context) -> Option<String> {
-    modules::handle(module_name, &context).map(|m| m.to_string())
+    modules::handle(module_name, &context).map(|mut module| {
+        if context.no_color() {
+            module.strip_styles();
+        }
+        module.to_string()
+    })
 }
 
 pub fn explain(args: ArgMatches) {
@@ -100,62 +173,47 @@ pub fn explain(args: ArgMatches) {
     let modules = compute_modules(&context)
         .into_iter()
         .filter(|module| !dont_print.contains(&module.get_name().as_str()))
-        .map(|module| {
+        .map(|mut module| {
+            if context.no_color() {
+                module.strip_styles();
+            }
             let value = module.get_segments().join("");
             ModuleInfo {
                 value: ansi_term::ANSIStrings(&module.ansi_strings()).to_string(),
-                value_len: value.chars().count() + count_wide_chars(&value),
+                value_len: utils::display_width(&value),
                 desc: module.get_description().to_owned(),
             }
         })
         .collect::<Vec<ModuleInfo>>();
 
-    let mut max_ansi_module_width = 0;
-    let mut max_module_width = 0;
-
-    for info in &modules {
-        max_ansi_module_width = std::cmp::max(
-            max_ansi_module_width,
-            info.value.chars().count() + count_wide_chars(&info.value),
-        );
-        max_module_width = std::cmp::max(max_module_width, info.value_len);
-    }
+    let max_module_width = modules.iter().map(|info| info.value_len).max().unwrap_or(0);
 
+    // The module values are followed by "  -  " and indented by one column
+    let desc_indent = max_module_width + 6;
     let desc_width = term_size::dimensions()
         .map(|(w, _)| w)
-        .map(|width| width - std::cmp::min(width, max_ansi_module_width));
+        .map(|width| width - std::cmp::min(width, desc_indent));
 
     println!("\n Here's a breakdown of your prompt:");
     for info in modules {
-        let wide_chars = count_wide_chars(&info.value);
+        // The value contains escape sequences, so it's padded by its display width
+        let padding = " ".repeat(max_module_width - info.value_len);
 
         if let Some(desc_width) = desc_width {
             let wrapped = textwrap::fill(&info.desc, desc_width);
             let mut lines = wrapped.split('\n');
-            println!(
-                " {:width$}  -  {}",
-                info.value,
-                lines.next().unwrap(),
-                width = max_ansi_module_width - wide_chars
-            );
+            println!(" {}{}  -  {}", info.value, padding, lines.next().unwrap());
 
             for line in lines {
-                println!("{}{}", " ".repeat(max_module_width + 6), line.trim());
+                println!("{}{}", " ".repeat(desc_indent), line.trim());
             }
         } else {
-            println!(
-                " {:width$}  -  {}",
-                info.value,
-                info.desc,
-                width = max_ansi_module_width - wide_chars
-            );
+            println!(" {}{}  -  {}", info.value, padding, info.desc);
         };
     }
 }
 
 fn compute_modules<'a>(context: &'a Context) -> Vec<Module<'a>> {
-    let mut prompt_order: Vec<Module<'a>> = Vec::new();
-
     let config = context.config.get_root_config();
     let formatter = if let Ok(formatter) = StringFormatter::new(config.format) {
         formatter
@@ -165,20 +223,22 @@ fn compute_modules<'a>(context: &'a Context) -> Vec<Module<'a>> {
     };
     let modules = formatter.get_variables();
 
-    for module in &modules {
-        // Manually add all modules if `$all` is encountered
-        if module == "all" {
-            for module in PROMPT_ORDER.iter() {
-                let modules = handle_module(module, &context, &modules);
-                prompt_order.extend(modules.into_iter());
+    // Modules are rendered in parallel, `collect` keeps them in their original order
+    modules
+        .par_iter()
+        .flat_map(|module| {
+            // Manually add all modules that aren't placed explicitly if `$all` is encountered
+            if module == "all" {
+                PROMPT_ORDER
+                    .par_iter()
+                    .filter(|module| !modules.contains(**module))
+                    .flat_map(|module| handle_module(module, &context, &modules))
+                    .collect::<Vec<Module<'a>>>()
+            } else {
+                handle_module(module, &context, &modules)
             }
-        } else {
-            let modules = handle_module(module, &context, &modules);
-            prompt_order.extend(modules.into_iter());
-        }
-    }
-
-    prompt_order
+        })
+        .collect()
 }
 
 fn handle_module<'a>(
@@ -197,9 +257,29 @@ fn handle_module<'a>(
     let mut modules: Vec<Option<Module>> = Vec::new();
 
     if ALL_MODULES.contains(&module) {
-        // Write out a module if it isn't disabled
-        if !context.is_module_disabled_in_config(module) {
-            modules.push(modules::handle(module, &context));
+        // Write out the module, `modules::handle` skips it if it's disabled
+        modules.push(modules::handle(module, &context));
+
+        // Write out all named env_var modules, except for those that are explicitly set
+        if module == "env_var" {
+            if let Some(env_var_modules) = context.config.get_env_var_modules() {
+                let env_var_modules = env_var_modules
+                    .into_iter()
+                    .map(|(env_var_module, config)| {
+                        if should_add_implicit_module(
+                            "env_var",
+                            env_var_module,
+                            config,
+                            module_list,
+                        ) {
+                            modules::handle(&format!("env_var.{}", env_var_module), context)
+                        } else {
+                            None
+                        }
+                    })
+                    .collect::<Vec<Option<Module<'a>>>>();
+                modules.extend(env_var_modules)
+            }
         }
     } else if module == "custom" {
         // Write out all custom modules, except for those that are explicitly set
@@ -207,7 +287,7 @@ fn handle_module<'a>(
             let custom_modules = custom_modules
                 .iter()
                 .map(|(custom_module, config)| {
-                    if should_add_implicit_custom_module(custom_module, config, &module_list) {
+                    if should_add_implicit_module("custom", custom_module, config, &module_list) {
                         modules::custom::module(custom_module, &context)
                     } else {
                         None
@@ -216,6 +296,9 @@ fn handle_module<'a>(
                 .collect::<Vec<Option<Module<'a>>>>();
             modules.extend(custom_modules)
         }
+    } else if module.starts_with("env_var.") {
+        // Write out a named env_var module; it handles its own `disabled` option
+        modules.push(modules::handle(module, context));
     } else if module.starts_with("custom.") {
         // Write out a custom module if it isn't disabled (and it exists...)
         match context.is_custom_module_disabled_in_config(&module[7..]) {
@@ -244,12 +327,13 @@ fn handle_module<'a>(
     modules.into_iter().flatten().collect()
 }
 
-fn should_add_implicit_custom_module(
-    custom_module: &str,
+fn should_add_implicit_module(
+    parent_module: &str,
+    module: &str,
     config: &toml::Value,
     module_list: &BTreeSet<String>,
 ) -> bool {
-    let explicit_module_name = format!("custom.{}", custom_module);
+    let explicit_module_name = format!("{}.{}", parent_module, module);
     let is_explicitly_specified = module_list.contains(&explicit_module_name);
 
     if is_explicitly_specified {
@@ -266,6 +350,104 @@ fn should_add_implicit_custom_module(
         .unwrap_or(false)
 }
 
-fn count_wide_chars(value: &str) -> usize {
-    value.chars().filter(|c| c.width().unwrap_or(0) > 1).count()
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::StarshipConfig;
+    use std::env;
+    use std::fs::File;
+
+    #[test]
+    fn parallel_and_serial_renders_are_identical() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        for file in &[
+            "package.json",
+            "go.mod",
+            "Makefile.PL",
+            "mix.exs",
+            "pubspec.yaml",
+        ] {
+            File::create(dir.path().join(file))?.sync_all()?;
+        }
+
+        let new_context = || {
+            let mut context = Context::new_with_dir(ArgMatches::default(), dir.path());
+            context.config = StarshipConfig {
+                config: Some(toml::toml! {
+                    format = "$all"
+                    add_newline = false
+                }),
+            };
+            context.shell = Shell::Unknown;
+            context
+        };
+
+        let parallel = get_prompt(new_context());
+
+        let context = new_context();
+        let module_list = ["all".to_owned()].iter().cloned().collect();
+        let segments = PROMPT_ORDER
+            .iter()
+            .flat_map(|module| handle_module(module, &context, &module_list))
+            .flat_map(|module| module.segments)
+            .collect();
+        let mut root_module = Module::new("Starship Root", "The root module", None);
+        root_module.set_segments(segments);
+        let serial = ANSIStrings(&root_module.ansi_strings()).to_string();
+
+        assert!(!serial.is_empty());
+        assert_eq!(parallel, serial);
+        dir.close()
+    }
+
+    fn render_env_var_prompt(config: toml::Value) -> String {
+        let mut context = Context::new_with_dir(ArgMatches::default(), "/");
+        context.config = StarshipConfig {
+            config: Some(config),
+        };
+        context.shell = Shell::Unknown;
+        get_prompt(context)
+    }
+
+    #[test]
+    fn implicit_named_env_var_modules() {
+        env::set_var("STARSHIP_PRINT_TEST_FOO", "foo");
+        env::set_var("STARSHIP_PRINT_TEST_BAR", "bar");
+
+        let actual = render_env_var_prompt(toml::toml! {
+            format = "$env_var"
+            add_newline = false
+
+            [env_var.STARSHIP_PRINT_TEST_FOO]
+            format = "$env_value "
+
+            [env_var.STARSHIP_PRINT_TEST_BAR]
+            format = "$env_value "
+
+            [env_var.STARSHIP_PRINT_TEST_DISABLED]
+            default = "disabled"
+            disabled = true
+        });
+
+        assert_eq!(actual, "foo bar ");
+    }
+
+    #[test]
+    fn explicit_named_env_var_module_is_not_repeated() {
+        env::set_var("STARSHIP_PRINT_TEST_BAZ", "baz");
+        env::set_var("STARSHIP_PRINT_TEST_QUX", "qux");
+
+        let actual = render_env_var_prompt(toml::toml! {
+            format = "${env_var.STARSHIP_PRINT_TEST_BAZ}|$env_var"
+            add_newline = false
+
+            [env_var.STARSHIP_PRINT_TEST_BAZ]
+            format = "$env_value"
+
+            [env_var.STARSHIP_PRINT_TEST_QUX]
+            format = "$env_value"
+        });
+
+        assert_eq!(actual, "baz|qux");
+    }
 }
@@ -1,9 +1,14 @@
+use std::collections::HashMap;
 use std::fs::File;
 use std::io::{Read, Result};
 use std::path::Path;
-use std::process::Command;
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
 
 use crate::context::Shell;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
 
 /// Return the string contents of a file
 pub fn read_file<P: AsRef<Path>>(file_name: P) -> Result<String> {
@@ -14,7 +19,7 @@ pub fn read_file<P: AsRef<Path>>(file_name: P) -> Result<String> {
     Ok(data)
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct CommandOutput {
     pub stdout: String,
     pub stderr: String,
@@ -26,19 +31,122 @@ impl PartialEq for CommandOutput {
     }
 }
 
-/// Execute a command and return the output on stdout and stderr if sucessful
+/// Runs the commands that modules execute through `Context::exec_cmd`
+pub trait CommandRunner: Send + Sync {
+    fn exec_cmd(
+        &self,
+        cmd: &str,
+        args: &[&str],
+        time_limit: Duration,
+        allow_failure: bool,
+    ) -> Option<CommandOutput>;
+}
+
+/// Runs commands as processes on the system
+pub struct SystemCommandRunner;
+
+impl CommandRunner for SystemCommandRunner {
+    fn exec_cmd(
+        &self,
+        cmd: &str,
+        args: &[&str],
+        time_limit: Duration,
+        allow_failure: bool,
+    ) -> Option<CommandOutput> {
+        if allow_failure {
+            exec_cmd_allow_failure(cmd, args, time_limit)
+        } else {
+            exec_cmd(cmd, args, time_limit)
+        }
+    }
+}
+
+/// Answers commands with the outputs it was given, keyed on the full command line
+/// (e.g. `perl --version`), and fails to run any other command
+#[cfg(test)]
+#[derive(Default)]
+pub struct FakeCommandRunner {
+    outputs: std::collections::HashMap<String, CommandOutput>,
+}
+
+#[cfg(test)]
+impl FakeCommandRunner {
+    pub fn with_output(mut self, command_line: &str, stdout: &str) -> Self {
+        let output = CommandOutput {
+            stdout: stdout.to_string(),
+            stderr: String::default(),
+        };
+        self.outputs.insert(command_line.to_string(), output);
+        self
+    }
+}
+
+#[cfg(test)]
+impl CommandRunner for FakeCommandRunner {
+    fn exec_cmd(
+        &self,
+        cmd: &str,
+        args: &[&str],
+        _time_limit: Duration,
+        _allow_failure: bool,
+    ) -> Option<CommandOutput> {
+        let command_line = std::iter::once(cmd)
+            .chain(args.iter().copied())
+            .collect::<Vec<&str>>()
+            .join(" ");
+        self.outputs.get(&command_line).cloned()
+    }
+}
+
+/// Execute a command and return the output on stdout and stderr if sucessful.
+///
+/// The command is killed, and `None` returned, if it hasn't exited within `time_limit`.
+#[cfg(not(test))]
+pub fn exec_cmd(cmd: &str, args: &[&str], time_limit: Duration) -> Option<CommandOutput> {
+    internal_exec_cmd(cmd, args, time_limit, false)
+}
+
+/// Like `exec_cmd`, but also returns the output of a command that exits with a non-zero status,
+/// for tools that report their version on stderr while doing so.
 #[cfg(not(test))]
-pub fn exec_cmd(cmd: &str, args: &[&str]) -> Option<CommandOutput> {
-    internal_exec_cmd(&cmd, &args)
+pub fn exec_cmd_allow_failure(
+    cmd: &str,
+    args: &[&str],
+    time_limit: Duration,
+) -> Option<CommandOutput> {
+    internal_exec_cmd(cmd, args, time_limit, true)
 }
 
 #[cfg(test)]
-pub fn exec_cmd(cmd: &str, args: &[&str]) -> Option<CommandOutput> {
+pub fn exec_cmd(cmd: &str, args: &[&str], time_limit: Duration) -> Option<CommandOutput> {
+    mock_exec_cmd(cmd, args, time_limit, false)
+}
+
+#[cfg(test)]
+pub fn exec_cmd_allow_failure(
+    cmd: &str,
+    args: &[&str],
+    time_limit: Duration,
+) -> Option<CommandOutput> {
+    mock_exec_cmd(cmd, args, time_limit, true)
+}
+
+#[cfg(test)]
+fn mock_exec_cmd(
+    cmd: &str,
+    args: &[&str],
+    time_limit: Duration,
+    allow_failure: bool,
+) -> Option<CommandOutput> {
     let command = match args.len() {
         0 => String::from(cmd),
         _ => format!("{} {}", cmd, args.join(" ")),
     };
     match command.as_str() {
+        "buf --version" => Some(CommandOutput {
+            stdout: String::from("1.0.0\n"),
+            stderr: String::default(),
+        }),
         "crystal --version" => Some(CommandOutput {
             stdout: String::from(
                 "\
@@ -55,6 +163,27 @@ Default target: x86_64-apple-macosx\n",
                 "Dart VM version: 2.8.4 (stable) (Wed Jun 3 12:26:04 2020 +0200) on \"macos_x64\"",
             ),
         }),
+        "deno --version" => Some(CommandOutput {
+            stdout: String::from(
+                "\
+deno 1.8.3 (release, x86_64-unknown-linux-gnu)
+v8 9.0.257.3
+typescript 4.2.2\n",
+            ),
+            stderr: String::default(),
+        }),
+        "dotnet --list-sdks" => Some(CommandOutput {
+            stdout: String::from(
+                "\
+2.2.402 [/usr/share/dotnet/sdk]
+3.1.103 [/usr/share/dotnet/sdk]\n",
+            ),
+            stderr: String::default(),
+        }),
+        "dotnet --version" => Some(CommandOutput {
+            stdout: String::from("3.1.103\n"),
+            stderr: String::default(),
+        }),
         "dummy_command" => Some(CommandOutput {
             stdout: String::from("stdout ok!\n"),
             stderr: String::from("stderr ok!\n"),
@@ -72,6 +201,10 @@ Elixir 1.10 (compiled with Erlang/OTP 22)\n",
             stdout: String::from("0.19.1\n"),
             stderr: String::default(),
         }),
+        "ghc --numeric-version" => Some(CommandOutput {
+            stdout: String::from("8.8.4\n"),
+            stderr: String::default(),
+        }),
         "go version" => Some(CommandOutput {
             stdout: String::from("go version go1.12.1 linux/amd64\n"),
             stderr: String::default(),
@@ -88,15 +221,16 @@ Elixir 1.10 (compiled with Erlang/OTP 22)\n",
             stdout: String::from("julia version 1.4.0\n"),
             stderr: String::default(),
         }),
-        "nim --version" => Some(CommandOutput {
-            stdout: String::from(
-                "\
-Nim Compiler Version 1.2.0 [Linux: amd64]
-Compiled at 2020-04-03
-Copyright (c) 2006-2020 by Andreas Rumpf
-git hash: 7e83adff84be5d0c401a213eccb61e321a3fb1ff
-active boot switches: -d:release\n",
-            ),
+        "kotlin -version" => Some(CommandOutput {
+            stdout: String::default(),
+            stderr: String::from("Kotlin version 1.5.31-release-548 (JRE 11.0.12+7)\n"),
+        }),
+        "kotlinc -version" => Some(CommandOutput {
+            stdout: String::default(),
+            stderr: String::from("info: kotlinc-jvm 1.5.30 (JRE 11.0.12+7)\n"),
+        }),
+        "lua -v" => Some(CommandOutput {
+            stdout: String::from("Lua 5.4.2  Copyright (C) 1994-2020 Lua.org, PUC-Rio\n"),
             stderr: String::default(),
         }),
         "node --version" => Some(CommandOutput {
@@ -115,7 +249,7 @@ active boot switches: -d:release\n",
             stdout: String::from("5.26.1"),
             stderr: String::default(),
         }),
-        "php -nr 'echo PHP_MAJOR_VERSION.\".\".PHP_MINOR_VERSION.\".\".PHP_RELEASE_VERSION;'" => {
+        "php -nr echo PHP_MAJOR_VERSION.\".\".PHP_MINOR_VERSION.\".\".PHP_RELEASE_VERSION;" => {
             Some(CommandOutput {
                 stdout: String::from("7.3.8"),
                 stderr: String::default(),
@@ -125,6 +259,10 @@ active boot switches: -d:release\n",
             stdout: String::from("0.13.5\n"),
             stderr: String::default(),
         }),
+        "pyenv version-name" => Some(CommandOutput {
+            stdout: String::from("my_pyenv\n"),
+            stderr: String::default(),
+        }),
         "python --version" => Some(CommandOutput {
             stdout: String::default(),
             stderr: String::from("Python 2.7.17\n"),
@@ -137,6 +275,16 @@ active boot switches: -d:release\n",
             stdout: String::from("ruby 2.5.1p57 (2018-03-29 revision 63029) [x86_64-linux-gnu]\n"),
             stderr: String::default(),
         }),
+        "scalac -version" => Some(CommandOutput {
+            stdout: String::default(),
+            stderr: String::from(
+                "Scala compiler version 2.13.5 -- Copyright 2002-2020, LAMP/EPFL and Lightbend, Inc.\n",
+            ),
+        }),
+        "stack ghc -- --numeric-version" => Some(CommandOutput {
+            stdout: String::from("8.10.4\n"),
+            stderr: String::default(),
+        }),
         "swift --version" => Some(CommandOutput {
             stdout: String::from(
                 "\
@@ -145,6 +293,10 @@ Target: x86_64-apple-darwin19.4.0\n",
             ),
             stderr: String::default(),
         }),
+        "vagrant --version" => Some(CommandOutput {
+            stdout: String::from("Vagrant 2.2.10\n"),
+            stderr: String::default(),
+        }),
         "zig version" => Some(CommandOutput {
             stdout: String::from("0.6.0\n"),
             stderr: String::default(),
@@ -167,7 +319,61 @@ CMake suite maintained and supported by Kitware (kitware.com/cmake).\n",
             stderr: String::default(),
         }),
         // If we don't have a mocked command fall back to executing the command
-        _ => internal_exec_cmd(&cmd, &args),
+        _ => internal_exec_cmd(cmd, args, time_limit, allow_failure),
+    }
+}
+
+/// Return whether `version` is a plain version number such as `2.7.1`: one or more dot-separated
+/// parts made of ASCII digits only
+pub fn is_dotted_version(version: &str) -> bool {
+    !version.is_empty()
+        && version
+            .split('.')
+            .all(|part| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit()))
+}
+
+/// Return the display string of an identifier such as a kubernetes context or a cloud region.
+///
+/// The longest key of `aliases` that the identifier starts with is replaced by its alias, so an
+/// alias of the full identifier always wins over the aliases of its prefixes. Identifiers
+/// without an alias are truncated to `truncation_length` graphemes followed by `…`, if given.
+pub fn alias_or_truncate<V: AsRef<str>>(
+    identifier: &str,
+    aliases: &HashMap<String, V>,
+    truncation_length: Option<usize>,
+) -> String {
+    let longest_alias = aliases
+        .iter()
+        .filter(|(key, _)| !key.is_empty() && identifier.starts_with(key.as_str()))
+        .max_by_key(|(key, _)| key.len());
+    if let Some((key, alias)) = longest_alias {
+        return format!("{}{}", alias.as_ref(), &identifier[key.len()..]);
+    }
+
+    match truncation_length {
+        Some(length) => truncate_graphemes(identifier, length, "…"),
+        None => identifier.to_owned(),
+    }
+}
+
+/// Return the first grapheme cluster of `text`, or an empty string if it's empty
+pub fn first_grapheme(text: &str) -> &str {
+    text.graphemes(true).next().unwrap_or("")
+}
+
+/// Return the number of terminal columns `text` takes up, where wide characters such as CJK
+/// take two columns and combining marks none
+pub fn display_width(text: &str) -> usize {
+    UnicodeWidthStr::width(text)
+}
+
+/// Keep the first `length` grapheme clusters of `text`, followed by `truncation_symbol` if
+/// anything was cut off. Grapheme clusters are the characters a user sees, so that a letter
+/// followed by combining marks counts once.
+pub fn truncate_graphemes(text: &str, length: usize, truncation_symbol: &str) -> String {
+    match text.grapheme_indices(true).nth(length) {
+        Some((end, _)) => format!("{}{}", &text[..end], truncation_symbol),
+        None => text.to_owned(),
     }
 }
 
@@ -202,14 +408,16 @@ pub fn wrap_seq_for_shell(
                 escaped = true;
                 match shell {
                     Shell::Bash => format!("{}{}", BASH_BEG, escape_begin),
-                    Shell::Zsh => format!("{}{}", ZSH_BEG, escape_begin),
+                    // tcsh uses the same escapes as zsh
+                    Shell::Zsh | Shell::Tcsh => format!("{}{}", ZSH_BEG, escape_begin),
                     _ => x.to_string(),
                 }
             } else if x == escape_end && escaped {
                 escaped = false;
                 match shell {
                     Shell::Bash => format!("{}{}", escape_end, BASH_END),
-                    Shell::Zsh => format!("{}{}", escape_end, ZSH_END),
+                    // tcsh uses the same escapes as zsh
+                    Shell::Zsh | Shell::Tcsh => format!("{}{}", escape_end, ZSH_END),
                     _ => x.to_string(),
                 }
             } else {
@@ -220,33 +428,95 @@ pub fn wrap_seq_for_shell(
     final_string
 }
 
-fn internal_exec_cmd(cmd: &str, args: &[&str]) -> Option<CommandOutput> {
+fn internal_exec_cmd(
+    cmd: &str,
+    args: &[&str],
+    time_limit: Duration,
+    allow_failure: bool,
+) -> Option<CommandOutput> {
     log::trace!("Executing command {:?} with args {:?}", cmd, args);
-    match Command::new(cmd).args(args).output() {
-        Ok(output) => {
-            let stdout_string = String::from_utf8(output.stdout).unwrap();
-            let stderr_string = String::from_utf8(output.stderr).unwrap();
-
-            log::trace!("stdout: {:?}", stdout_string);
-            log::trace!("stderr: {:?}", stderr_string);
-            log::trace!("exit code: \"{:?}\"", output.status.code());
+    let start = Instant::now();
+
+    let mut child = match Command::new(cmd)
+        .args(args)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+    {
+        Ok(child) => child,
+        Err(error) => {
+            log::trace!("Executing command {:?} failed by: {:?}", cmd, error);
+            return None;
+        }
+    };
 
-            if !output.status.success() {
+    // Drain both pipes in the background so the child can't block on a full pipe
+    let stdout = read_pipe_in_background(child.stdout.take());
+    let stderr = read_pipe_in_background(child.stderr.take());
+
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break status,
+            Ok(None) if start.elapsed() < time_limit => thread::sleep(Duration::from_millis(1)),
+            Ok(None) => {
+                log::warn!(
+                    "Executing command {:?} timed out after {:?}",
+                    cmd,
+                    time_limit
+                );
+                child.kill().ok();
+                child.wait().ok();
+                return None;
+            }
+            Err(error) => {
+                log::trace!("Executing command {:?} failed by: {:?}", cmd, error);
                 return None;
             }
-
-            Some(CommandOutput {
-                stdout: stdout_string,
-                stderr: stderr_string,
-            })
-        }
-        Err(error) => {
-            log::trace!("Executing command {:?} failed by: {:?}", cmd, error);
-            None
         }
+    };
+
+    let stdout_string = normalize_line_endings(String::from_utf8(stdout.join().ok()?).unwrap());
+    let stderr_string = normalize_line_endings(String::from_utf8(stderr.join().ok()?).unwrap());
+
+    log::trace!("stdout: {:?}", stdout_string);
+    log::trace!("stderr: {:?}", stderr_string);
+    log::trace!("exit code: \"{:?}\"", status.code());
+    log::trace!("took: {:?}", start.elapsed());
+
+    if !status.success() && !allow_failure {
+        return None;
+    }
+
+    Some(CommandOutput {
+        stdout: stdout_string,
+        stderr: stderr_string,
+    })
+}
+
+/// Converts Windows `\r\n` and old Mac `\r` line endings to `\n`, so that no `\r` leaks into
+/// module output
+fn normalize_line_endings(output: String) -> String {
+    if output.contains('\r') {
+        output.replace("\r\n", "\n").replace('\r', "\n")
+    } else {
+        output
     }
 }
 
+fn read_pipe_in_background<R>(pipe: Option<R>) -> thread::JoinHandle<Vec<u8>>
+where
+    R: Read + Send + 'static,
+{
+    thread::spawn(move || {
+        let mut buf = Vec::new();
+        if let Some(mut pipe) = pipe {
+            pipe.read_to_end(&mut buf).ok();
+        }
+        buf
+    })
+}
+
 #[cfg(test)]
 #[cfg(not(windows))] // While the exec_cmd should work on Windows these tests assume a Unix-like environment.
 mod tests {
@@ -254,7 +524,7 @@ mod tests {
 
     #[test]
     fn exec_mocked_command() {
-        let result = exec_cmd("dummy_command", &[]);
+        let result = exec_cmd("dummy_command", &[], Duration::from_millis(500));
         let expected = Some(CommandOutput {
             stdout: String::from("stdout ok!\n"),
             stderr: String::from("stderr ok!\n"),
@@ -265,7 +535,7 @@ mod tests {
 
     #[test]
     fn exec_no_output() {
-        let result = internal_exec_cmd("true", &[]);
+        let result = internal_exec_cmd("true", &[], Duration::from_millis(500), false);
         let expected = Some(CommandOutput {
             stdout: String::from(""),
             stderr: String::from(""),
@@ -274,9 +544,44 @@ mod tests {
         assert_eq!(result, expected)
     }
 
+    #[test]
+    fn test_normalize_line_endings() {
+        assert_eq!(normalize_line_endings("v1.2.3\r\n".to_string()), "v1.2.3\n");
+        assert_eq!(
+            normalize_line_endings("line one\r\nline two\r\n".to_string()),
+            "line one\nline two\n"
+        );
+        assert_eq!(normalize_line_endings("v1.2.3\n".to_string()), "v1.2.3\n");
+        assert_eq!(
+            normalize_line_endings("line one\rline two\r".to_string()),
+            "line one\nline two\n"
+        );
+    }
+
+    #[test]
+    fn exec_with_crlf_output() {
+        let result = internal_exec_cmd(
+            "/bin/sh",
+            &["-c", "printf 'v1.2.3\\r\\n'"],
+            Duration::from_millis(500),
+            false,
+        );
+        let expected = Some(CommandOutput {
+            stdout: String::from("v1.2.3\n"),
+            stderr: String::from(""),
+        });
+
+        assert_eq!(result, expected)
+    }
+
     #[test]
     fn exec_with_output_stdout() {
-        let result = internal_exec_cmd("/bin/sh", &["-c", "echo hello"]);
+        let result = internal_exec_cmd(
+            "/bin/sh",
+            &["-c", "echo hello"],
+            Duration::from_millis(500),
+            false,
+        );
         let expected = Some(CommandOutput {
             stdout: String::from("hello\n"),
             stderr: String::from(""),
@@ -287,7 +592,12 @@ mod tests {
 
     #[test]
     fn exec_with_output_stderr() {
-        let result = internal_exec_cmd("/bin/sh", &["-c", "echo hello >&2"]);
+        let result = internal_exec_cmd(
+            "/bin/sh",
+            &["-c", "echo hello >&2"],
+            Duration::from_millis(500),
+            false,
+        );
         let expected = Some(CommandOutput {
             stdout: String::from(""),
             stderr: String::from("hello\n"),
@@ -298,7 +608,12 @@ mod tests {
 
     #[test]
     fn exec_with_output_both() {
-        let result = internal_exec_cmd("/bin/sh", &["-c", "echo hello; echo world >&2"]);
+        let result = internal_exec_cmd(
+            "/bin/sh",
+            &["-c", "echo hello; echo world >&2"],
+            Duration::from_millis(500),
+            false,
+        );
         let expected = Some(CommandOutput {
             stdout: String::from("hello\n"),
             stderr: String::from("world\n"),
@@ -309,12 +624,163 @@ mod tests {
 
     #[test]
     fn exec_with_non_zero_exit_code() {
-        let result = internal_exec_cmd("false", &[]);
+        let result = internal_exec_cmd("false", &[], Duration::from_millis(500), false);
+        let expected = None;
+
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn exec_with_non_zero_exit_code_allowing_failure() {
+        let result = internal_exec_cmd(
+            "/bin/sh",
+            &["-c", "echo 'version 1.2.3' >&2; exit 1"],
+            Duration::from_millis(500),
+            true,
+        );
+        let expected = Some(CommandOutput {
+            stdout: String::from(""),
+            stderr: String::from("version 1.2.3\n"),
+        });
+
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn exec_with_timeout() {
+        let start = Instant::now();
+        let result = internal_exec_cmd("sleep", &["5"], Duration::from_millis(100), false);
         let expected = None;
 
+        assert_eq!(result, expected);
+        assert!(start.elapsed() < Duration::from_secs(1));
+    }
+
+    #[test]
+    fn exec_within_timeout() {
+        let result = internal_exec_cmd(
+            "/bin/sh",
+            &["-c", "sleep 0.01; echo hello"],
+            Duration::from_secs(5),
+            false,
+        );
+        let expected = Some(CommandOutput {
+            stdout: String::from("hello\n"),
+            stderr: String::from(""),
+        });
+
         assert_eq!(result, expected)
     }
 
+    #[test]
+    fn display_width_of_cjk() {
+        assert_eq!(display_width("日本語"), 6);
+        assert_eq!(display_width("ab日本"), 6);
+    }
+
+    #[test]
+    fn display_width_of_combining_marks() {
+        // "é" written as an "e" followed by a combining acute accent
+        let text = "cafe\u{301}";
+        assert_eq!(text.chars().count(), 5);
+        assert_eq!(display_width(text), 4);
+        assert_eq!(first_grapheme("e\u{301}tude"), "e\u{301}");
+        assert_eq!(first_grapheme(""), "");
+    }
+
+    #[test]
+    fn truncate_graphemes_of_cjk_and_combining_marks() {
+        assert_eq!(truncate_graphemes("日本語のブランチ", 3, "…"), "日本語…");
+        assert_eq!(truncate_graphemes("日本語", 3, "…"), "日本語");
+        assert_eq!(
+            truncate_graphemes("cafe\u{301}-au-lait", 4, "…"),
+            "cafe\u{301}…"
+        );
+        assert_eq!(
+            truncate_graphemes("e\u{301}\u{302}a", 1, ""),
+            "e\u{301}\u{302}"
+        );
+        assert_eq!(truncate_graphemes("main", 0, "…"), "…");
+    }
+
+    #[test]
+    fn test_is_dotted_version() {
+        assert!(is_dotted_version("2"));
+        assert!(is_dotted_version("2.7.1"));
+        assert!(is_dotted_version("11.0.10"));
+        assert!(!is_dotted_version(""));
+        assert!(!is_dotted_version("2..1"));
+        assert!(!is_dotted_version("2.7."));
+        assert!(!is_dotted_version("v2.7.1"));
+        assert!(!is_dotted_version("2.7.1-rc1"));
+        assert!(!is_dotted_version("system"));
+        assert!(!is_dotted_version("٣.٢"));
+    }
+
+    #[test]
+    fn alias_or_truncate_exact_match() {
+        let mut aliases = HashMap::new();
+        aliases.insert("us-east-1".to_owned(), "va".to_owned());
+
+        assert_eq!(alias_or_truncate("us-east-1", &aliases, None), "va");
+        assert_eq!(alias_or_truncate("us-east-1", &aliases, Some(2)), "va");
+    }
+
+    #[test]
+    fn alias_or_truncate_without_match() {
+        let mut aliases = HashMap::new();
+        aliases.insert("us-east-1".to_owned(), "va".to_owned());
+
+        assert_eq!(alias_or_truncate("eu-west-1", &aliases, None), "eu-west-1");
+        assert_eq!(
+            alias_or_truncate("eu-west-1", &aliases, Some(7)),
+            "eu-west…"
+        );
+        assert_eq!(
+            alias_or_truncate("eu-west-1", &aliases, Some(9)),
+            "eu-west-1"
+        );
+        assert_eq!(
+            alias_or_truncate("eu-west-1", &HashMap::<String, &str>::new(), Some(0)),
+            "…"
+        );
+    }
+
+    #[test]
+    fn alias_or_truncate_longest_prefix() {
+        let aliases: HashMap<String, &str> = vec![
+            ("arn:aws:eks:".to_owned(), "eks:"),
+            (
+                "arn:aws:eks:us-east-1:123456789012:cluster/".to_owned(),
+                "eks/",
+            ),
+            ("gke_".to_owned(), "gke:"),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(
+            alias_or_truncate(
+                "arn:aws:eks:us-east-1:123456789012:cluster/prod",
+                &aliases,
+                None
+            ),
+            "eks/prod"
+        );
+        assert_eq!(
+            alias_or_truncate(
+                "arn:aws:eks:eu-west-1:123456789012:cluster/dev",
+                &aliases,
+                None
+            ),
+            "eks:eu-west-1:123456789012:cluster/dev"
+        );
+        assert_eq!(
+            alias_or_truncate("gke_project_zone_cluster", &aliases, Some(3)),
+            "gke:project_zone_cluster"
+        );
+    }
+
     #[test]
     fn test_color_sequence_wrappers() {
         let test0 = "\x1b2mhellomynamekeyes\x1b2m"; // BEGIN: \x1b     END: m
@@ -351,5 +817,11 @@ mod tests {
         assert_eq!(&bresult3, "\\[OH NO\\]");
         assert_eq!(&bresult4, "herpaderp");
         assert_eq!(&bresult5, "");
+
+        let tresult0 = wrap_seq_for_shell(test0.to_string(), Shell::Tcsh, '\x1b', 'm');
+        let tresult4 = wrap_seq_for_shell(test4.to_string(), Shell::Tcsh, '\x1b', 'm');
+
+        assert_eq!(&tresult0, "%{\x1b2m%}hellomynamekeyes%{\x1b2m%}");
+        assert_eq!(&tresult4, "herpaderp");
     }
 }
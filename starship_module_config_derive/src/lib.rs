@@ -17,11 +17,13 @@ fn impl_module_config(dinput: DeriveInput) -> proc_macro::TokenStream {
 
     let mut from_config = quote! {};
     let mut load_config = quote! {};
+    let mut unknown_keys = quote! {};
 
     if let syn::Data::Struct(data) = dinput.data {
         if let syn::Fields::Named(fields_named) = data.fields {
             let mut load_tokens = quote! {};
             let mut from_tokens = quote! {};
+            let mut key_tokens = quote! {};
 
             for field in fields_named.named.iter() {
                 let ident = field.ident.as_ref().unwrap();
@@ -43,7 +45,11 @@ fn impl_module_config(dinput: DeriveInput) -> proc_macro::TokenStream {
                 from_tokens = quote! {
                     #from_tokens
                     #new_from_tokens
-                }
+                };
+                key_tokens = quote! {
+                    #key_tokens
+                    stringify!(#ident),
+                };
             }
 
             load_config = quote! {
@@ -55,6 +61,19 @@ fn impl_module_config(dinput: DeriveInput) -> proc_macro::TokenStream {
                     new_module_config
                 }
             };
+            unknown_keys = quote! {
+                fn unknown_keys(config: &toml::Value) -> Vec<String> {
+                    const KEYS: &[&str] = &[#key_tokens];
+                    match config {
+                        toml::Value::Table(config) => config
+                            .keys()
+                            .filter(|key| !KEYS.contains(&key.as_str()))
+                            .cloned()
+                            .collect(),
+                        _ => Vec::new(),
+                    }
+                }
+            };
             from_config = quote! {
                 fn from_config(config: &'a toml::Value) -> Option<Self> {
                     let config = config.as_table()?;
@@ -71,6 +90,7 @@ fn impl_module_config(dinput: DeriveInput) -> proc_macro::TokenStream {
         impl<'a> ModuleConfig<'a> for #struct_ident #ty_generics #where_clause {
             #from_config
             #load_config
+            #unknown_keys
         }
     })
 }
@@ -43,6 +43,34 @@ fn region_set_with_alias() -> io::Result<()> {
     Ok(())
 }
 
+#[test]
+fn region_set_with_alias_prefix_and_truncation() -> io::Result<()> {
+    let output = common::render_module("aws")
+        .env("AWS_REGION", "us-east-1-bos-1a")
+        .use_config(toml::toml! {
+            [aws]
+            truncation_length = 4
+            [aws.region_aliases]
+            us-east-1 = "va"
+        })
+        .output()?;
+    let expected = format!("on {} ", Color::Yellow.bold().paint("☁️  (va-bos-1a)"));
+    let actual = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(expected, actual);
+
+    let output = common::render_module("aws")
+        .env("AWS_REGION", "ap-southeast-2")
+        .use_config(toml::toml! {
+            [aws]
+            truncation_length = 4
+        })
+        .output()?;
+    let expected = format!("on {} ", Color::Yellow.bold().paint("☁️  (ap-s…)"));
+    let actual = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
 #[test]
 fn default_region_set() -> io::Result<()> {
     let output = common::render_module("aws")
@@ -118,6 +146,65 @@ region = us-east-2
     dir.close()
 }
 
+#[test]
+fn default_profile_set_explicitly() -> io::Result<()> {
+    let dir = tempfile::tempdir()?;
+    let config_path = dir.path().join("config");
+    let mut file = File::create(&config_path)?;
+
+    file.write_all(
+        "[default]
+region_alias = eu-west-1
+region = us-east-1
+
+[profile astronauts]
+region = us-east-2
+"
+        .as_bytes(),
+    )?;
+
+    let output = common::render_module("aws")
+        .env("AWS_CONFIG_FILE", config_path.to_string_lossy().as_ref())
+        .env("AWS_PROFILE", "default")
+        .output()?;
+    let expected = format!(
+        "on {} ",
+        Color::Yellow.bold().paint("☁️  default(us-east-1)")
+    );
+    let actual = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(expected, actual);
+    dir.close()
+}
+
+#[test]
+fn profile_and_indented_config_set() -> io::Result<()> {
+    let dir = tempfile::tempdir()?;
+    let config_path = dir.path().join("config");
+    let mut file = File::create(&config_path)?;
+
+    file.write_all(
+        "[default]
+  region = us-east-1
+
+[profile astronauts]
+  region=us-east-2
+"
+        .as_bytes(),
+    )?;
+
+    let output = common::render_module("aws")
+        .env("AWS_CONFIG_FILE", config_path.to_string_lossy().as_ref())
+        .env("AWS_PROFILE", "astronauts")
+        .output()?;
+    let expected = format!(
+        "on {} ",
+        Color::Yellow.bold().paint("☁️  astronauts(us-east-2)")
+    );
+    let actual = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(expected, actual);
+    dir.close()
+}
+
 #[test]
 fn profile_and_config_set() -> io::Result<()> {
     let dir = tempfile::tempdir()?;
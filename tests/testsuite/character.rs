@@ -76,6 +76,32 @@ fn custom_symbol() -> io::Result<()> {
     Ok(())
 }
 
+#[test]
+fn custom_styles_and_format() -> io::Result<()> {
+    let config = toml::toml! {
+        [character]
+        format = "$symbol"
+        success_symbol = "[\\$](purple)"
+        error_symbol = "[\\$](underline yellow)"
+    };
+
+    let output = common::render_module("character")
+        .use_config(config.clone())
+        .arg("--status=0")
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(Color::Purple.paint("$").to_string(), actual);
+
+    let output = common::render_module("character")
+        .use_config(config)
+        .arg("--status=127")
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(Color::Yellow.underline().paint("$").to_string(), actual);
+
+    Ok(())
+}
+
 #[test]
 fn zsh_keymap() -> io::Result<()> {
     let expected_vicmd = format!("{} ", Color::Green.bold().paint("❮"));
@@ -149,3 +175,30 @@ fn fish_keymap() -> io::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn vicmd_keymap_from_any_shell() -> io::Result<()> {
+    let expected_vicmd = format!("{} ", Color::Green.bold().paint("❮"));
+
+    // the vicmd keymap is honored without a known shell
+    let output = common::render_module("character")
+        .arg("--keymap=vicmd")
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(expected_vicmd, actual);
+
+    // the vicmd symbol takes precedence over the error symbol
+    let output = common::render_module("character")
+        .use_config(toml::toml! {
+            [character]
+            vicmd_symbol = "[N](bold yellow)"
+        })
+        .env("STARSHIP_SHELL", "bash")
+        .arg("--keymap=vicmd")
+        .arg("--status=1")
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(format!("{} ", Color::Yellow.bold().paint("N")), actual);
+
+    Ok(())
+}
@@ -27,6 +27,62 @@ fn config_blank_duration_5s() -> io::Result<()> {
     Ok(())
 }
 
+#[test]
+fn config_blank_duration_just_below_min_time() -> io::Result<()> {
+    let output = common::render_module("cmd_duration")
+        .arg("--cmd-duration=1999")
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = "";
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
+#[test]
+fn config_blank_duration_equal_to_min_time() -> io::Result<()> {
+    let output = common::render_module("cmd_duration")
+        .arg("--cmd-duration=2000")
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = format!("took {} ", Color::Yellow.bold().paint("2s"));
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
+#[test]
+fn config_show_milliseconds_duration_2500ms() -> io::Result<()> {
+    let output = common::render_module("cmd_duration")
+        .use_config(toml::toml! {
+            [cmd_duration]
+            show_milliseconds = true
+        })
+        .arg("--cmd-duration=2500")
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = format!("took {} ", Color::Yellow.bold().paint("2s500ms"));
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
+#[test]
+fn config_0ms_duration_0ms() -> io::Result<()> {
+    let output = common::render_module("cmd_duration")
+        .use_config(toml::toml! {
+            [cmd_duration]
+            min_time = 0
+        })
+        .arg("--cmd-duration=0")
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = format!("took {} ", Color::Yellow.bold().paint("0ms"));
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
 #[test]
 fn config_5s_duration_3s() -> io::Result<()> {
     let output = common::render_module("cmd_duration")
@@ -16,7 +16,7 @@ const EXE_PATH: &str = "./target/debug/starship.exe";
 const EXE_PATH: &str = "./target/debug/starship";
 
 /// Render the full starship prompt
-pub fn _render_prompt() -> process::Command {
+pub fn render_prompt() -> process::Command {
     let mut command = process::Command::new(EXE_PATH);
 
     command
@@ -43,6 +43,47 @@ pub fn render_module(module_name: &str) -> process::Command {
     command
 }
 
+/// Print the init script of starship for a shell, add `--print-full-init` to get the main script
+pub fn render_init(shell_name: &str) -> process::Command {
+    let mut command = process::Command::new(EXE_PATH);
+
+    command
+        .arg("init")
+        .arg(shell_name)
+        .env_clear()
+        .env("PATH", env!("PATH"))
+        .env("STARSHIP_CONFIG", EMPTY_CONFIG.as_os_str());
+
+    command
+}
+
+/// Check the configuration of starship for keys it doesn't recognize
+pub fn check_config() -> process::Command {
+    let mut command = process::Command::new(EXE_PATH);
+
+    command
+        .arg("config")
+        .arg("--check")
+        .env_clear()
+        .env("PATH", env!("PATH"))
+        .env("STARSHIP_CONFIG", EMPTY_CONFIG.as_os_str());
+
+    command
+}
+
+/// Print the computed configuration of starship
+pub fn print_config() -> process::Command {
+    let mut command = process::Command::new(EXE_PATH);
+
+    command
+        .arg("print-config")
+        .env_clear()
+        .env("PATH", env!("PATH"))
+        .env("STARSHIP_CONFIG", EMPTY_CONFIG.as_os_str());
+
+    command
+}
+
 /// Create a repo from the fixture to be used in git module tests
 /// Please delete the returned directory manually after usage with `remove_dir_all::remove_dir_all`
 pub fn create_fixture_repo() -> io::Result<PathBuf> {
@@ -32,12 +32,12 @@ fn ignore_base() -> io::Result<()> {
 }
 
 #[test]
-fn env_set() -> io::Result<()> {
+fn truncate() -> io::Result<()> {
     let output = common::render_module("conda")
-        .env("CONDA_DEFAULT_ENV", "astronauts")
+        .env("CONDA_DEFAULT_ENV", "/some/really/long/and/really/annoying/path/that/shouldnt/be/displayed/fully/conda/my_env")
         .output()?;
 
-    let expected = format!("via {} ", Color::Green.bold().paint("🅒 astronauts"));
+    let expected = format!("via {} ", Color::Green.bold().paint("🅒 my_env"));
     let actual = String::from_utf8(output.stdout).unwrap();
 
     assert_eq!(expected, actual);
@@ -45,12 +45,55 @@ fn env_set() -> io::Result<()> {
 }
 
 #[test]
-fn truncate() -> io::Result<()> {
+fn show_base() -> io::Result<()> {
     let output = common::render_module("conda")
-        .env("CONDA_DEFAULT_ENV", "/some/really/long/and/really/annoying/path/that/shouldnt/be/displayed/fully/conda/my_env")
+        .env("CONDA_DEFAULT_ENV", "base")
+        .use_config(toml::toml! {
+            [conda]
+            ignore_base = false
+        })
         .output()?;
 
-    let expected = format!("via {} ", Color::Green.bold().paint("🅒 my_env"));
+    let expected = format!("via {} ", Color::Green.bold().paint("🅒 base"));
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
+#[test]
+fn truncate_to_two_components() -> io::Result<()> {
+    let output = common::render_module("conda")
+        .env("CONDA_DEFAULT_ENV", "/home/astronaut/conda/envs/my_env")
+        .use_config(toml::toml! {
+            [conda]
+            truncation_length = 2
+        })
+        .output()?;
+
+    let expected = format!("via {} ", Color::Green.bold().paint("🅒 envs/my_env"));
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
+#[test]
+fn no_truncation() -> io::Result<()> {
+    let output = common::render_module("conda")
+        .env("CONDA_DEFAULT_ENV", "/home/astronaut/conda/envs/my_env")
+        .use_config(toml::toml! {
+            [conda]
+            truncation_length = 0
+        })
+        .output()?;
+
+    let expected = format!(
+        "via {} ",
+        Color::Green
+            .bold()
+            .paint("🅒 /home/astronaut/conda/envs/my_env")
+    );
     let actual = String::from_utf8(output.stdout).unwrap();
 
     assert_eq!(expected, actual);
@@ -1,5 +1,6 @@
 use ansi_term::Color;
 use std::io;
+use std::path::Path;
 
 use crate::common::{self, TestCommand};
 
@@ -18,3 +19,120 @@ fn char_symbol_configuration() -> io::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn config_check_reports_unknown_keys() -> io::Result<()> {
+    let fixture =
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/unknown_key_config.toml");
+
+    let output = common::check_config()
+        .env("STARSHIP_CONFIG", fixture)
+        .output()?;
+    let actual = String::from_utf8(output.stderr).unwrap();
+
+    let expected = "\
+[WARN] Unknown config key `bogus_root_key`
+[WARN] Unknown config key `perl.symbl`
+[WARN] Unknown config key `env_var.USER.formatt`
+[WARN] Unknown config key `custom.foo.whn`
+";
+    assert_eq!(expected, actual);
+    assert!(!output.status.success());
+    Ok(())
+}
+
+#[test]
+fn config_check_accepts_known_keys() -> io::Result<()> {
+    let output = common::check_config()
+        .use_config(toml::toml! {
+            command_timeout = 500
+
+            [perl]
+            symbol = "🐪 "
+
+            [line_break]
+            disabled = true
+        })
+        .output()?;
+    let actual = String::from_utf8(output.stderr).unwrap();
+
+    assert_eq!("", actual);
+    assert!(output.status.success());
+    Ok(())
+}
+
+#[test]
+fn print_config_merges_defaults() -> io::Result<()> {
+    let output = common::print_config()
+        .use_config(toml::toml! {
+            command_timeout = 100
+
+            [perl]
+            symbol = "P "
+
+            [custom.foo]
+            command = "echo foo"
+        })
+        .output()?;
+    let actual: toml::Value = toml::from_str(&String::from_utf8(output.stdout).unwrap()).unwrap();
+
+    // Configured values are kept
+    assert_eq!(actual["command_timeout"].as_integer(), Some(100));
+    assert_eq!(actual["perl"]["symbol"].as_str(), Some("P "));
+    assert_eq!(
+        actual["custom"]["foo"]["command"].as_str(),
+        Some("echo foo")
+    );
+    // and everything else is filled in with the defaults
+    assert_eq!(actual["scan_timeout"].as_integer(), Some(30));
+    assert_eq!(actual["perl"]["style"].as_str(), Some("149 bold"));
+    assert_eq!(
+        actual["custom"]["foo"]["style"].as_str(),
+        Some("green bold")
+    );
+    assert_eq!(
+        actual["character"]["success_symbol"].as_str(),
+        Some("[❯](bold green)")
+    );
+    assert_eq!(actual["time"]["disabled"].as_bool(), Some(true));
+    assert_eq!(actual["line_break"]["disabled"].as_bool(), Some(false));
+    Ok(())
+}
+
+#[test]
+fn config_file_argument_is_used() -> io::Result<()> {
+    let fixture =
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/unknown_key_config.toml");
+
+    let output = common::check_config()
+        .arg("--config")
+        .arg(&fixture)
+        .output()?;
+    let actual = String::from_utf8(output.stderr).unwrap();
+    assert!(actual.contains("[WARN] Unknown config key `perl.symbl`"));
+    assert!(!output.status.success());
+
+    let output = common::print_config()
+        .arg("--config")
+        .arg(&fixture)
+        .output()?;
+    let actual: toml::Value = toml::from_str(&String::from_utf8(output.stdout).unwrap()).unwrap();
+    assert_eq!(actual["scan_timeout"].as_integer(), Some(10));
+    assert_eq!(actual["perl"]["style"].as_str(), Some("bold blue"));
+    Ok(())
+}
+
+#[test]
+fn config_check_reports_unknown_line_break_keys() -> io::Result<()> {
+    let output = common::check_config()
+        .use_config(toml::toml! {
+            [line_break]
+            disabld = true
+        })
+        .output()?;
+    let actual = String::from_utf8(output.stderr).unwrap();
+
+    assert_eq!("[WARN] Unknown config key `line_break.disabld`\n", actual);
+    assert!(!output.status.success());
+    Ok(())
+}
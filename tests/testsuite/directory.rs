@@ -1,6 +1,7 @@
 use ansi_term::Color;
 use dirs_next::home_dir;
 use git2::Repository;
+use remove_dir_all::remove_dir_all;
 use std::fs;
 use std::io;
 #[cfg(not(target_os = "windows"))]
@@ -28,6 +29,41 @@ fn home_directory() -> io::Result<()> {
     Ok(())
 }
 
+#[test]
+fn home_directory_custom_home_symbol() -> io::Result<()> {
+    let output = common::render_module("directory")
+        .arg("--path=~/path/with/~/tilde")
+        .use_config(toml::toml! {
+            [directory]
+            truncate_to_repo = false
+            truncation_length = 0
+            home_symbol = "🏠"
+        })
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = format!("{} ", Color::Cyan.bold().paint("🏠/path/with/~/tilde"));
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
+#[test]
+fn custom_home_symbol_outside_home() -> io::Result<()> {
+    let output = common::render_module("directory")
+        .arg("--path=/some/~/path")
+        .use_config(toml::toml! {
+            [directory]
+            truncation_length = 0
+            home_symbol = "🏠"
+        })
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = format!("{} ", Color::Cyan.bold().paint("/some/~/path"));
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
 #[test]
 fn substituted_truncated_path() -> io::Result<()> {
     let output = common::render_module("directory")
@@ -47,6 +83,59 @@ fn substituted_truncated_path() -> io::Result<()> {
     Ok(())
 }
 
+#[test]
+fn truncation_symbol_truncated_path() -> io::Result<()> {
+    let output = common::render_module("directory")
+        .arg("--path=/some/long/network/path/workspace")
+        .use_config(toml::toml! {
+            [directory]
+            truncation_length = 3
+            truncation_symbol = "…/"
+        })
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = format!("{} ", Color::Cyan.bold().paint("…/network/path/workspace"));
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
+#[test]
+fn truncation_symbol_not_truncated_path() -> io::Result<()> {
+    let output = common::render_module("directory")
+        .arg("--path=/some/path")
+        .use_config(toml::toml! {
+            [directory]
+            truncation_length = 3
+            truncation_symbol = "…/"
+        })
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = format!("{} ", Color::Cyan.bold().paint("/some/path"));
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
+#[test]
+fn substituted_overlapping_prefixes() -> io::Result<()> {
+    let output = common::render_module("directory")
+        .arg("--path=/home/user/Documents/projects/starship")
+        .use_config(toml::toml! {
+            [directory]
+            truncation_length = 3
+            [directory.substitutions]
+            "/home/user/Documents" = "docs"
+            "/home/user/Documents/projects" = "proj"
+        })
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = format!("{} ", Color::Cyan.bold().paint("proj/starship"));
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
 #[test]
 fn strange_substitution() -> io::Result<()> {
     let strange_sub = "/\\/;,!";
@@ -72,6 +161,96 @@ fn strange_substitution() -> io::Result<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(not(target_os = "windows"))]
+fn read_only_directory() -> io::Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let tmp_dir = TempDir::new()?;
+    let dir = tmp_dir.path().join("readonly");
+    fs::create_dir(&dir)?;
+    fs::set_permissions(&dir, fs::Permissions::from_mode(0o555))?;
+
+    let output = common::render_module("directory")
+        .arg("--path")
+        .arg(&dir)
+        .use_config(toml::toml! {
+            [directory]
+            truncation_length = 1
+        })
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    // The superuser is allowed to write to any directory
+    let lock = if nix::unistd::Uid::effective().is_root() {
+        String::new()
+    } else {
+        Color::Red.paint("🔒").to_string()
+    };
+    let expected = format!("{}{} ", Color::Cyan.bold().paint("readonly"), lock);
+    assert_eq!(expected, actual);
+
+    fs::set_permissions(&dir, fs::Permissions::from_mode(0o755))?;
+    tmp_dir.close()
+}
+
+#[test]
+#[cfg(not(target_os = "windows"))]
+fn read_only_directory_with_deprecated_options() -> io::Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let tmp_dir = TempDir::new()?;
+    let dir = tmp_dir.path().join("readonly");
+    fs::create_dir(&dir)?;
+    fs::set_permissions(&dir, fs::Permissions::from_mode(0o555))?;
+
+    let output = common::render_module("directory")
+        .arg("--path")
+        .arg(&dir)
+        .use_config(toml::toml! {
+            [directory]
+            truncation_length = 1
+            read_only_symbol = "RO"
+            read_only_symbol_style = "blue"
+        })
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    // The superuser is allowed to write to any directory
+    let lock = if nix::unistd::Uid::effective().is_root() {
+        String::new()
+    } else {
+        Color::Blue.paint("RO").to_string()
+    };
+    let expected = format!("{}{} ", Color::Cyan.bold().paint("readonly"), lock);
+    assert_eq!(expected, actual);
+
+    fs::set_permissions(&dir, fs::Permissions::from_mode(0o755))?;
+    tmp_dir.close()
+}
+
+#[test]
+fn writable_directory() -> io::Result<()> {
+    let tmp_dir = TempDir::new()?;
+    let dir = tmp_dir.path().join("writable");
+    fs::create_dir(&dir)?;
+
+    let output = common::render_module("directory")
+        .arg("--path")
+        .arg(&dir)
+        .use_config(toml::toml! {
+            [directory]
+            truncation_length = 1
+            read_only = "RO"
+        })
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = format!("{} ", Color::Cyan.bold().paint("writable"));
+    assert_eq!(expected, actual);
+    tmp_dir.close()
+}
+
 #[test]
 #[ignore]
 fn directory_in_home() -> io::Result<()> {
@@ -289,6 +468,23 @@ fn fish_directory_config_small() -> io::Result<()> {
     Ok(())
 }
 
+#[test]
+fn fish_directory_usr_local_bin() -> io::Result<()> {
+    let output = common::render_module("directory")
+        .use_config(toml::toml! {
+            [directory]
+            truncation_length = 1
+            fish_style_pwd_dir_length = 1
+        })
+        .arg("--path=/usr/local/bin")
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = format!("{} ", Color::Cyan.bold().paint("/u/l/bin"));
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
 #[test]
 #[ignore]
 fn git_repo_root() -> io::Result<()> {
@@ -779,3 +975,30 @@ fn symlinked_subdirectory_git_repo_out_of_tree() -> io::Result<()> {
     assert_eq!(expected, actual);
     tmp_dir.close()
 }
+
+#[test]
+fn nested_directory_in_fixture_repo() -> io::Result<()> {
+    let repo_dir = common::create_fixture_repo()?;
+    let dir = repo_dir.join("src/meters");
+    fs::create_dir_all(&dir)?;
+    let repo_name = repo_dir.file_name().unwrap().to_str().unwrap();
+
+    let output = common::render_module("directory")
+        .arg("--path")
+        .arg(&dir)
+        .use_config(toml::toml! {
+            [directory]
+            truncation_length = 5
+        })
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = format!(
+        "{} ",
+        Color::Cyan
+            .bold()
+            .paint(format!("{}/src/meters", repo_name))
+    );
+    assert_eq!(expected, actual);
+    remove_dir_all(repo_dir)
+}
@@ -0,0 +1,150 @@
+use ansi_term::Color;
+use std::fs::{self, File};
+use std::io;
+
+use crate::common::{self, TestCommand};
+
+fn docker_config(current_context: &str) -> io::Result<tempfile::TempDir> {
+    let dir = tempfile::tempdir()?;
+    fs::write(
+        dir.path().join("config.json"),
+        format!(
+            r#"{{ "auths": {{}}, "currentContext": "{}" }}"#,
+            current_context
+        ),
+    )?;
+    Ok(dir)
+}
+
+fn docker_project() -> io::Result<tempfile::TempDir> {
+    let dir = tempfile::tempdir()?;
+    File::create(dir.path().join("Dockerfile"))?.sync_all()?;
+    Ok(dir)
+}
+
+#[test]
+fn context_from_config_file() -> io::Result<()> {
+    let config_dir = docker_config("starship")?;
+    let project_dir = docker_project()?;
+
+    let output = common::render_module("docker_context")
+        .env("DOCKER_CONFIG", config_dir.path())
+        .arg("--path")
+        .arg(project_dir.path())
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    let expected = format!("via {} ", Color::Blue.bold().paint("🐳 starship"));
+    assert_eq!(expected, actual);
+
+    config_dir.close()?;
+    project_dir.close()
+}
+
+#[test]
+fn default_context_is_hidden() -> io::Result<()> {
+    let config_dir = docker_config("default")?;
+    let project_dir = docker_project()?;
+
+    let output = common::render_module("docker_context")
+        .env("DOCKER_CONFIG", config_dir.path())
+        .arg("--path")
+        .arg(project_dir.path())
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    assert_eq!("", actual);
+
+    config_dir.close()?;
+    project_dir.close()
+}
+
+#[test]
+fn env_vars_take_precedence_over_config_file() -> io::Result<()> {
+    let config_dir = docker_config("starship")?;
+    let project_dir = docker_project()?;
+
+    let output = common::render_module("docker_context")
+        .env("DOCKER_CONFIG", config_dir.path())
+        .env("DOCKER_CONTEXT", "remote")
+        .arg("--path")
+        .arg(project_dir.path())
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    let expected = format!("via {} ", Color::Blue.bold().paint("🐳 remote"));
+    assert_eq!(expected, actual);
+
+    let output = common::render_module("docker_context")
+        .env("DOCKER_CONFIG", config_dir.path())
+        .env("DOCKER_CONTEXT", "remote")
+        .env("DOCKER_HOST", "tcp://10.0.0.1:2376")
+        .arg("--path")
+        .arg(project_dir.path())
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    let expected = format!(
+        "via {} ",
+        Color::Blue.bold().paint("🐳 tcp://10.0.0.1:2376")
+    );
+    assert_eq!(expected, actual);
+
+    config_dir.close()?;
+    project_dir.close()
+}
+
+#[test]
+fn empty_env_vars_fall_back_to_config_file() -> io::Result<()> {
+    let config_dir = docker_config("starship")?;
+    let project_dir = docker_project()?;
+
+    let output = common::render_module("docker_context")
+        .env("DOCKER_CONFIG", config_dir.path())
+        .env("DOCKER_HOST", "")
+        .env("DOCKER_CONTEXT", "")
+        .arg("--path")
+        .arg(project_dir.path())
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    let expected = format!("via {} ", Color::Blue.bold().paint("🐳 starship"));
+    assert_eq!(expected, actual);
+
+    config_dir.close()?;
+    project_dir.close()
+}
+
+#[test]
+fn only_with_files() -> io::Result<()> {
+    let config_dir = docker_config("starship")?;
+    let empty_dir = tempfile::tempdir()?;
+
+    let output = common::render_module("docker_context")
+        .env("DOCKER_CONFIG", config_dir.path())
+        .arg("--path")
+        .arg(empty_dir.path())
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    assert_eq!("", actual);
+
+    let output = common::render_module("docker_context")
+        .env("DOCKER_CONFIG", config_dir.path())
+        .arg("--path")
+        .arg(empty_dir.path())
+        .use_config(toml::toml! {
+            [docker_context]
+            only_with_files = false
+        })
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    let expected = format!("via {} ", Color::Blue.bold().paint("🐳 starship"));
+    assert_eq!(expected, actual);
+
+    File::create(empty_dir.path().join(".dockerignore"))?.sync_all()?;
+    let output = common::render_module("docker_context")
+        .env("DOCKER_CONFIG", config_dir.path())
+        .arg("--path")
+        .arg(empty_dir.path())
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(expected, actual);
+
+    config_dir.close()?;
+    empty_dir.close()
+}
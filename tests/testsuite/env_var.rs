@@ -135,6 +135,87 @@ fn suffix() -> io::Result<()> {
     Ok(())
 }
 
+#[test]
+fn named_module_defaults_to_its_own_name() -> io::Result<()> {
+    let output = common::render_module("env_var.TEST_VAR")
+        .env_clear()
+        .use_config(toml::toml! {
+            [env_var.TEST_VAR]
+        })
+        .env("TEST_VAR", TEST_VAR_VALUE)
+        .output()?;
+    let expected = format!("with {} ", style().paint(TEST_VAR_VALUE));
+    let actual = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
+#[test]
+fn named_modules_are_independent() -> io::Result<()> {
+    let config = toml::toml! {
+        [env_var.FOO]
+        variable = "TEST_FOO"
+        symbol = "foo:"
+        format = "[$symbol$env_value]($style) "
+        style = "red"
+
+        [env_var.BAR]
+        variable = "TEST_BAR"
+        default = "no bar"
+        format = "[$env_value]($style) "
+        style = "blue"
+    };
+
+    let output = common::render_module("env_var.FOO")
+        .env_clear()
+        .use_config(config.clone())
+        .env("TEST_FOO", TEST_VAR_VALUE)
+        .output()?;
+    let expected = format!("{} ", Color::Red.paint(format!("foo:{}", TEST_VAR_VALUE)));
+    let actual = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(expected, actual);
+
+    let output = common::render_module("env_var.BAR")
+        .env_clear()
+        .use_config(config)
+        .env("TEST_FOO", TEST_VAR_VALUE)
+        .output()?;
+    let expected = format!("{} ", Color::Blue.paint("no bar"));
+    let actual = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
+#[test]
+fn named_module_undefined_variable() -> io::Result<()> {
+    let output = common::render_module("env_var.TEST_VAR")
+        .env_clear()
+        .use_config(toml::toml! {
+            [env_var.TEST_VAR]
+        })
+        .output()?;
+    let expected = "";
+    let actual = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
+#[test]
+fn named_module_disabled() -> io::Result<()> {
+    let output = common::render_module("env_var.TEST_VAR")
+        .env_clear()
+        .use_config(toml::toml! {
+            [env_var.TEST_VAR]
+            disabled = true
+        })
+        .env("TEST_VAR", TEST_VAR_VALUE)
+        .output()?;
+    let expected = "";
+    let actual = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
 fn style() -> Style {
     // default style
     Color::Black.bold().dimmed()
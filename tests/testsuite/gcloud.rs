@@ -157,3 +157,69 @@ fn region_not_set_with_display_region() -> io::Result<()> {
     assert_eq!(expected, actual);
     dir.close()
 }
+
+#[test]
+fn active_config_from_env() -> io::Result<()> {
+    let dir = tempfile::tempdir()?;
+    let active_config_path = dir.path().join("active_config");
+    let mut active_config_file = File::create(&active_config_path)?;
+    active_config_file.write_all(b"default")?;
+
+    create_dir(dir.path().join("configurations"))?;
+    let config_work_path = dir.path().join("configurations/config_work");
+    let mut config_work_file = File::create(&config_work_path)?;
+    config_work_file.write_all(
+        b"[core]
+account = bar@example.com
+project = work-project
+",
+    )?;
+
+    let output = common::render_module("gcloud")
+        .env("CLOUDSDK_CONFIG", dir.path().to_string_lossy().as_ref())
+        .env("CLOUDSDK_ACTIVE_CONFIG_NAME", "work")
+        .use_config(toml::toml! {
+            [gcloud]
+            format = "on [$symbol$account(\\($project\\)) $active]($style) "
+        })
+        .output()?;
+    let expected = format!(
+        "on {} ",
+        Color::Blue
+            .bold()
+            .paint("☁️ bar@example.com(work-project) work")
+    );
+    let actual = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(actual, expected);
+    dir.close()
+}
+
+#[test]
+fn project_matches_exact_key() -> io::Result<()> {
+    let dir = tempfile::tempdir()?;
+    let active_config_path = dir.path().join("active_config");
+    let mut active_config_file = File::create(&active_config_path)?;
+    active_config_file.write_all(b"default\n")?;
+
+    create_dir(dir.path().join("configurations"))?;
+    let config_default_path = dir.path().join("configurations/config_default");
+    let mut config_default_file = File::create(&config_default_path)?;
+    config_default_file.write_all(
+        b"[core]
+project_number = 123
+project=abc
+",
+    )?;
+
+    let output = common::render_module("gcloud")
+        .env("CLOUDSDK_CONFIG", dir.path().to_string_lossy().as_ref())
+        .use_config(toml::toml! {
+            [gcloud]
+            format = "on [$symbol$project]($style) "
+        })
+        .output()?;
+    let expected = format!("on {} ", Color::Blue.bold().paint("☁️ abc"));
+    let actual = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(actual, expected);
+    dir.close()
+}
@@ -99,6 +99,22 @@ fn test_japanese_truncation() -> io::Result<()> {
     test_truncate_length("がんばってね", 4, "がんばっ", "…")
 }
 
+#[test]
+fn test_feature_branch_truncation() -> io::Result<()> {
+    test_truncate_length(
+        "feature/some-really-long-branch",
+        16,
+        "feature/some-rea",
+        "…",
+    )
+}
+
+#[test]
+fn test_emoji_truncation() -> io::Result<()> {
+    // 👩‍🚀 is a single grapheme made of several code points
+    test_truncate_length("🚀👩‍🚀-launch", 2, "🚀👩‍🚀", "…")
+}
+
 #[test]
 fn test_format_no_branch() -> io::Result<()> {
     test_format("1337_hello_world", "no_branch", "", "no_branch")
@@ -178,6 +194,155 @@ fn test_works_with_unborn_default_branch() -> io::Result<()> {
     remove_dir_all(repo_dir)
 }
 
+#[test]
+fn test_upstream_gone() -> io::Result<()> {
+    let repo_dir = common::create_fixture_repo()?;
+
+    // `master` tracks `origin/master`, remove the remote-tracking branch
+    Command::new("git")
+        .args(["update-ref", "-d", "refs/remotes/origin/master"])
+        .current_dir(&repo_dir)
+        .output()?;
+
+    let output = common::render_module("git_branch")
+        .arg("--path")
+        .arg(&repo_dir)
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = format!(
+        "on {} ",
+        Color::Purple
+            .bold()
+            .paint(format!("\u{e0a0} {}", "master (gone)")),
+    );
+    assert_eq!(expected, actual);
+    remove_dir_all(repo_dir)
+}
+
+#[test]
+fn test_upstream_present() -> io::Result<()> {
+    let repo_dir = common::create_fixture_repo()?;
+
+    let output = common::render_module("git_branch")
+        .use_config(toml::toml! {
+            [git_branch]
+            upstream_gone = " ✗"
+        })
+        .arg("--path")
+        .arg(&repo_dir)
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = format!(
+        "on {} ",
+        Color::Purple.bold().paint(format!("\u{e0a0} {}", "master")),
+    );
+    assert_eq!(expected, actual);
+    remove_dir_all(repo_dir)
+}
+
+#[test]
+fn test_show_remote_matching_name() -> io::Result<()> {
+    let repo_dir = common::create_fixture_repo()?;
+
+    let output = common::render_module("git_branch")
+        .use_config(toml::toml! {
+            [git_branch]
+            show_remote = true
+            hide_matching = false
+        })
+        .arg("--path")
+        .arg(&repo_dir)
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = format!(
+        "on {} ",
+        Color::Purple
+            .bold()
+            .paint(format!("\u{e0a0} {}", "master → origin/master")),
+    );
+    assert_eq!(expected, actual);
+
+    // The default `hide_matching = true` leaves out an upstream with the same name
+    let output = common::render_module("git_branch")
+        .use_config(toml::toml! {
+            [git_branch]
+            show_remote = true
+        })
+        .arg("--path")
+        .arg(&repo_dir)
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = format!(
+        "on {} ",
+        Color::Purple.bold().paint(format!("\u{e0a0} {}", "master")),
+    );
+    assert_eq!(expected, actual);
+    remove_dir_all(repo_dir)
+}
+
+#[test]
+fn test_show_remote_differing_name() -> io::Result<()> {
+    let repo_dir = common::create_fixture_repo()?;
+
+    Command::new("git")
+        .args(["checkout", "-b", "feature", "--track", "origin/master"])
+        .current_dir(&repo_dir)
+        .output()?;
+
+    let output = common::render_module("git_branch")
+        .use_config(toml::toml! {
+            [git_branch]
+            show_remote = true
+        })
+        .arg("--path")
+        .arg(&repo_dir)
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = format!(
+        "on {} ",
+        Color::Purple
+            .bold()
+            .paint(format!("\u{e0a0} {}", "feature → origin/master")),
+    );
+    assert_eq!(expected, actual);
+    remove_dir_all(repo_dir)
+}
+
+#[test]
+fn test_show_remote_without_upstream() -> io::Result<()> {
+    let repo_dir = common::create_fixture_repo()?;
+
+    Command::new("git")
+        .args(["checkout", "-b", "feature", "--no-track"])
+        .current_dir(&repo_dir)
+        .output()?;
+
+    let output = common::render_module("git_branch")
+        .use_config(toml::toml! {
+            [git_branch]
+            show_remote = true
+            hide_matching = false
+        })
+        .arg("--path")
+        .arg(&repo_dir)
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = format!(
+        "on {} ",
+        Color::Purple
+            .bold()
+            .paint(format!("\u{e0a0} {}", "feature")),
+    );
+    assert_eq!(expected, actual);
+    remove_dir_all(repo_dir)
+}
+
 #[test]
 fn test_git_dir_env_variable() -> io::Result<()> {
     let repo_dir = tempfile::tempdir()?.into_path();
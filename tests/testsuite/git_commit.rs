@@ -20,6 +20,30 @@ fn show_nothing_on_empty_dir() -> io::Result<()> {
     repo_dir.close()
 }
 
+#[test]
+fn show_nothing_on_repo_without_commits() -> io::Result<()> {
+    let repo_dir = tempfile::tempdir()?;
+
+    Command::new("git")
+        .args(&["init"])
+        .current_dir(repo_dir.path())
+        .output()?;
+
+    let output = common::render_module("git_commit")
+        .use_config(toml::toml! {
+            [git_commit]
+                only_detached = false
+        })
+        .arg("--path")
+        .arg(repo_dir.path())
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = "";
+    assert_eq!(expected, actual);
+    repo_dir.close()
+}
+
 #[test]
 fn test_render_commit_hash() -> io::Result<()> {
     let repo_dir = common::create_fixture_repo()?;
@@ -85,6 +109,38 @@ fn test_render_commit_hash_len_override() -> io::Result<()> {
     remove_dir_all(repo_dir)
 }
 
+#[test]
+fn test_render_commit_hash_len_longer_than_hash() -> io::Result<()> {
+    let repo_dir = common::create_fixture_repo()?;
+
+    let git_output = Command::new("git")
+        .args(&["rev-parse", "HEAD"])
+        .current_dir(repo_dir.as_path())
+        .output()?
+        .stdout;
+    let expected_hash = str::from_utf8(&git_output).unwrap().trim();
+
+    let output = common::render_module("git_commit")
+        .use_config(toml::toml! {
+            [git_commit]
+                only_detached = false
+                commit_hash_length = 64
+        })
+        .arg("--path")
+        .arg(&repo_dir)
+        .output()?;
+
+    let actual = String::from_utf8(output.stdout).unwrap();
+    let mut expected = Color::Green
+        .bold()
+        .paint(format!("({})", expected_hash))
+        .to_string();
+    expected.push(' ');
+
+    assert_eq!(expected, actual);
+    remove_dir_all(repo_dir)
+}
+
 #[test]
 fn test_render_commit_hash_only_detached_on_branch() -> io::Result<()> {
     let repo_dir = common::create_fixture_repo()?;
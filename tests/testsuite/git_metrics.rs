@@ -0,0 +1,156 @@
+use ansi_term::Color;
+use remove_dir_all::remove_dir_all;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use crate::common::{self, TestCommand};
+
+#[test]
+fn show_nothing_on_empty_dir() -> io::Result<()> {
+    let repo_dir = tempfile::tempdir()?;
+
+    let output = common::render_module("git_metrics")
+        .use_config(toml::toml! {
+            [git_metrics]
+            disabled = false
+        })
+        .arg("--path")
+        .arg(repo_dir.path())
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = "";
+    assert_eq!(expected, actual);
+    repo_dir.close()
+}
+
+#[test]
+fn disabled_by_default() -> io::Result<()> {
+    let repo_dir = common::create_fixture_repo()?;
+    modify_readme(&repo_dir)?;
+
+    let output = common::render_module("git_metrics")
+        .arg("--path")
+        .arg(&repo_dir)
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    assert_eq!("", actual);
+    remove_dir_all(repo_dir)
+}
+
+#[test]
+fn show_nothing_on_clean_repo() -> io::Result<()> {
+    let repo_dir = common::create_fixture_repo()?;
+
+    let output = common::render_module("git_metrics")
+        .use_config(toml::toml! {
+            [git_metrics]
+            disabled = false
+        })
+        .arg("--path")
+        .arg(&repo_dir)
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    assert_eq!("", actual);
+    remove_dir_all(repo_dir)
+}
+
+#[test]
+fn shows_added_and_deleted_lines() -> io::Result<()> {
+    let repo_dir = common::create_fixture_repo()?;
+    modify_readme(&repo_dir)?;
+
+    let output = common::render_module("git_metrics")
+        .use_config(toml::toml! {
+            [git_metrics]
+            disabled = false
+        })
+        .arg("--path")
+        .arg(&repo_dir)
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = format!(
+        "{} {} ",
+        Color::Green.bold().paint("+2"),
+        Color::Red.bold().paint("-1")
+    );
+    assert_eq!(expected, actual);
+    remove_dir_all(repo_dir)
+}
+
+#[test]
+fn shows_only_added_lines() -> io::Result<()> {
+    let repo_dir = common::create_fixture_repo()?;
+    let readme = repo_dir.join("readme.md");
+    let mut contents = fs::read_to_string(&readme)?;
+    contents.push_str("One more line\n");
+    fs::write(&readme, contents)?;
+
+    let output = common::render_module("git_metrics")
+        .use_config(toml::toml! {
+            [git_metrics]
+            disabled = false
+        })
+        .arg("--path")
+        .arg(&repo_dir)
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = format!("{} ", Color::Green.bold().paint("+1"));
+    assert_eq!(expected, actual);
+    remove_dir_all(repo_dir)
+}
+
+#[test]
+fn show_nothing_above_max_files() -> io::Result<()> {
+    let repo_dir = common::create_fixture_repo()?;
+    modify_readme(&repo_dir)?;
+
+    let output = common::render_module("git_metrics")
+        .use_config(toml::toml! {
+            [git_metrics]
+            disabled = false
+            max_files = 1
+        })
+        .arg("--path")
+        .arg(&repo_dir)
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    assert_eq!("", actual);
+    remove_dir_all(repo_dir)
+}
+
+#[test]
+fn zero_max_files_means_no_limit() -> io::Result<()> {
+    let repo_dir = common::create_fixture_repo()?;
+    modify_readme(&repo_dir)?;
+
+    let output = common::render_module("git_metrics")
+        .use_config(toml::toml! {
+            [git_metrics]
+            disabled = false
+            max_files = 0
+        })
+        .arg("--path")
+        .arg(&repo_dir)
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = format!(
+        "{} {} ",
+        Color::Green.bold().paint("+2"),
+        Color::Red.bold().paint("-1")
+    );
+    assert_eq!(expected, actual);
+    remove_dir_all(repo_dir)
+}
+
+/// Replaces the contents of the readme with two new lines
+fn modify_readme(repo_dir: &PathBuf) -> io::Result<()> {
+    fs::write(repo_dir.join("readme.md"), "First line\nSecond line\n")
+}
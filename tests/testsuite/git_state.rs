@@ -1,7 +1,7 @@
 use super::common;
 use ansi_term::Color;
 use std::ffi::OsStr;
-use std::fs::OpenOptions;
+use std::fs::{self, OpenOptions};
 use std::io::{self, Error, ErrorKind, Write};
 use std::process::{Command, Stdio};
 
@@ -42,6 +42,53 @@ fn shows_rebasing() -> io::Result<()> {
     Ok(())
 }
 
+#[test]
+fn shows_rebasing_progress_from_rebase_merge_dir() -> io::Result<()> {
+    let repo_dir = create_repo_with_conflict()?;
+    let path = path_str(&repo_dir)?;
+
+    let rebase_merge_dir = repo_dir.path().join(".git").join("rebase-merge");
+    fs::create_dir(&rebase_merge_dir)?;
+    fs::write(rebase_merge_dir.join("msgnum"), "2\n")?;
+    fs::write(rebase_merge_dir.join("end"), "5\n")?;
+
+    let output = common::render_module("git_state")
+        .arg("--path")
+        .arg(&path)
+        .output()?;
+
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let mut expected = Color::Yellow.bold().paint("(REBASING 2/5)").to_string();
+    expected.push(' ');
+
+    assert_eq!(expected, actual);
+
+    Ok(())
+}
+
+#[test]
+fn shows_rebasing_without_progress() -> io::Result<()> {
+    let repo_dir = create_repo_with_conflict()?;
+    let path = path_str(&repo_dir)?;
+
+    fs::create_dir(repo_dir.path().join(".git").join("rebase-merge"))?;
+
+    let output = common::render_module("git_state")
+        .arg("--path")
+        .arg(&path)
+        .output()?;
+
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let mut expected = Color::Yellow.bold().paint("(REBASING)").to_string();
+    expected.push(' ');
+
+    assert_eq!(expected, actual);
+
+    Ok(())
+}
+
 #[test]
 fn shows_merging() -> io::Result<()> {
     let repo_dir = create_repo_with_conflict()?;
@@ -2,7 +2,7 @@ use ansi_term::{ANSIStrings, Color};
 use remove_dir_all::remove_dir_all;
 use std::fs::{self, File};
 use std::io;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 
 use crate::common::{self, TestCommand};
@@ -165,6 +165,32 @@ fn shows_diverged_with_count() -> io::Result<()> {
     remove_dir_all(repo_dir)
 }
 
+#[test]
+fn doesnt_show_ahead_behind_without_upstream() -> io::Result<()> {
+    let repo_dir = common::create_fixture_repo()?;
+
+    Command::new("git")
+        .args(&["checkout", "-b", "local-branch"])
+        .current_dir(repo_dir.as_path())
+        .output()?;
+    barrier();
+
+    ahead(&repo_dir)?;
+
+    let output = common::render_module("git_status")
+        .env("STARSHIP_LOG", "warn")
+        .arg("--path")
+        .arg(&repo_dir)
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    let stderr = String::from_utf8(output.stderr).unwrap();
+
+    assert_eq!("", actual);
+    assert!(!stderr.contains("get_ahead_behind"));
+
+    remove_dir_all(repo_dir)
+}
+
 #[test]
 #[ignore]
 fn shows_conflicted() -> io::Result<()> {
@@ -207,6 +233,30 @@ fn shows_conflicted_with_count() -> io::Result<()> {
     remove_dir_all(repo_dir)
 }
 
+#[test]
+#[ignore]
+fn shows_conflicted_in_large_repos() -> io::Result<()> {
+    let repo_dir = common::create_fixture_repo()?;
+
+    create_conflict(&repo_dir)?;
+
+    let output = common::render_module("git_status")
+        .use_config(toml::toml! {
+            [git_status]
+            conflicted = "=$count"
+            max_files = 1
+        })
+        .arg("--path")
+        .arg(&repo_dir)
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    let expected = format_output("=1");
+
+    assert_eq!(expected, actual);
+
+    remove_dir_all(repo_dir)
+}
+
 #[test]
 #[ignore]
 fn shows_untracked_file() -> io::Result<()> {
@@ -329,6 +379,60 @@ fn shows_stashed_with_count() -> io::Result<()> {
     remove_dir_all(repo_dir)
 }
 
+#[test]
+fn shows_stashed_with_count_of_two() -> io::Result<()> {
+    let repo_dir = common::create_fixture_repo()?;
+    barrier();
+
+    create_stash(&repo_dir)?;
+
+    // A second stash with different contents, so that it gets its own entry
+    fs::write(repo_dir.join("readme.md"), "# Stashed changes")?;
+    barrier();
+
+    Command::new("git")
+        .args(&["stash", "--all"])
+        .current_dir(repo_dir.as_path())
+        .output()?;
+    barrier();
+
+    let output = common::render_module("git_status")
+        .use_config(toml::toml! {
+            [git_status]
+            stashed = r"\$$count"
+        })
+        .arg("--path")
+        .arg(&repo_dir)
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    let expected = format_output("$2");
+
+    assert_eq!(expected, actual);
+    remove_dir_all(repo_dir)
+}
+
+#[test]
+fn doesnt_show_stashed_without_stashes() -> io::Result<()> {
+    let repo_dir = common::create_fixture_repo()?;
+    barrier();
+
+    create_untracked(&repo_dir)?;
+
+    let output = common::render_module("git_status")
+        .use_config(toml::toml! {
+            [git_status]
+            stashed = r"\$$count"
+        })
+        .arg("--path")
+        .arg(&repo_dir)
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    let expected = format_output("?");
+
+    assert_eq!(expected, actual);
+    remove_dir_all(repo_dir)
+}
+
 #[test]
 #[ignore]
 fn shows_modified() -> io::Result<()> {
@@ -347,6 +451,88 @@ fn shows_modified() -> io::Result<()> {
     remove_dir_all(repo_dir)
 }
 
+#[test]
+#[ignore]
+fn skips_status_of_large_repos() -> io::Result<()> {
+    let repo_dir = common::create_fixture_repo()?;
+
+    create_modified(&repo_dir)?;
+    create_untracked(&repo_dir)?;
+
+    // The fixture repo tracks a few dozen files, far more than one
+    let output = common::render_module("git_status")
+        .use_config(toml::toml! {
+            [git_status]
+            max_files = 1
+        })
+        .arg("--path")
+        .arg(&repo_dir)
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    assert_eq!("", actual);
+
+    let output = common::render_module("git_status")
+        .use_config(toml::toml! {
+            [git_status]
+            max_files = 100_000
+        })
+        .arg("--path")
+        .arg(&repo_dir)
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(format_output("!?"), actual);
+
+    remove_dir_all(repo_dir)
+}
+
+#[test]
+#[ignore]
+fn ignores_dirty_submodule() -> io::Result<()> {
+    let repo_dir = common::create_fixture_repo()?;
+    let submodule_dir = common::create_fixture_repo()?;
+
+    Command::new("git")
+        .args(["-c", "protocol.file.allow=always", "submodule", "add"])
+        .arg(&submodule_dir)
+        .arg("submodule")
+        .current_dir(&repo_dir)
+        .output()?;
+    Command::new("git")
+        .args(["commit", "-m", "Add submodule"])
+        .current_dir(&repo_dir)
+        .output()?;
+    barrier();
+
+    fs::write(repo_dir.join("submodule/readme.md"), "modified")?;
+
+    // Leave out `ahead_behind`, the commit of the submodule isn't pushed
+    let output = common::render_module("git_status")
+        .use_config(toml::toml! {
+            [git_status]
+            format = "$all_status"
+        })
+        .arg("--path")
+        .arg(&repo_dir)
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    assert_eq!("!", actual);
+
+    let output = common::render_module("git_status")
+        .use_config(toml::toml! {
+            [git_status]
+            format = "$all_status"
+            ignore_submodules = true
+        })
+        .arg("--path")
+        .arg(&repo_dir)
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    assert_eq!("", actual);
+
+    remove_dir_all(submodule_dir)?;
+    remove_dir_all(repo_dir)
+}
+
 #[test]
 #[ignore]
 fn shows_modified_with_count() -> io::Result<()> {
@@ -461,6 +647,30 @@ fn shows_renamed_file_with_count() -> io::Result<()> {
     remove_dir_all(repo_dir)
 }
 
+#[test]
+#[ignore]
+fn shows_copied_file_with_count() -> io::Result<()> {
+    let repo_dir = common::create_fixture_repo()?;
+
+    create_copied(&repo_dir)?;
+
+    let output = common::render_module("git_status")
+        .use_config(toml::toml! {
+            [git_status]
+            format = "$renamed$copied"
+            copied = "⧉$count"
+        })
+        .arg("--path")
+        .arg(&repo_dir)
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    let expected = "⧉1";
+
+    assert_eq!(expected, actual);
+
+    remove_dir_all(repo_dir)
+}
+
 #[test]
 #[ignore]
 fn shows_deleted_file() -> io::Result<()> {
@@ -673,6 +883,21 @@ fn create_renamed(repo_dir: &PathBuf) -> io::Result<()> {
     Ok(())
 }
 
+fn create_copied(repo_dir: &Path) -> io::Result<()> {
+    // Only copies of files that are modified in the same change are detected
+    let cargo_toml = fs::read_to_string(repo_dir.join("Cargo.toml"))?;
+    fs::write(repo_dir.join("Cargo.toml.bak"), &cargo_toml)?;
+    fs::write(repo_dir.join("Cargo.toml"), cargo_toml + "\n[features]\n")?;
+
+    Command::new("git")
+        .args(["add", "-A"])
+        .current_dir(repo_dir)
+        .output()?;
+    barrier();
+
+    Ok(())
+}
+
 fn create_deleted(repo_dir: &PathBuf) -> io::Result<()> {
     fs::remove_file(repo_dir.join("readme.md"))?;
 
@@ -59,6 +59,27 @@ fn ssh() -> io::Result<()> {
     Ok(())
 }
 
+#[test]
+fn ssh_tty() -> io::Result<()> {
+    let hostname = match get_hostname() {
+        Some(h) => h,
+        None => return hostname_not_tested(),
+    };
+    let output = common::render_module("hostname")
+        .env_clear()
+        .use_config(toml::toml! {
+            [hostname]
+            ssh_only = true
+            trim_at = ""
+        })
+        .env("SSH_TTY", "/dev/pts/0")
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    let expected = format!("on {} ", style().paint(hostname));
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
 #[test]
 fn no_trim_at() -> io::Result<()> {
     let hostname = match get_hostname() {
@@ -0,0 +1,135 @@
+use std::io;
+
+use crate::common;
+
+#[test]
+fn powershell_stub() -> io::Result<()> {
+    let output = common::render_init("powershell").output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    assert!(actual.starts_with("Invoke-Expression (@(&\""));
+    assert!(actual.ends_with("init powershell --print-full-init) -join \"`n\")"));
+    Ok(())
+}
+
+#[test]
+fn powershell_full_init() -> io::Result<()> {
+    let output = common::render_init("powershell")
+        .arg("--print-full-init")
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    assert!(actual.contains("function global:prompt {"));
+    assert!(actual.contains("$origLastExitCode = $global:LASTEXITCODE"));
+    assert!(actual.contains("prompt \"--path=$current_directory\" --status=$status"));
+    assert!(actual.contains("--cmd-duration=$duration"));
+    assert!(actual.contains("$ENV:STARSHIP_SHELL = \"powershell\""));
+    assert!(!actual.contains("::STARSHIP::"));
+    Ok(())
+}
+
+#[test]
+fn nu_stub_is_full_init() -> io::Result<()> {
+    let stub = common::render_init("nu").output()?;
+    let full_init = common::render_init("nu")
+        .arg("--print-full-init")
+        .output()?;
+
+    assert_eq!(stub.stdout, full_init.stdout);
+    Ok(())
+}
+
+#[test]
+fn nu_full_init() -> io::Result<()> {
+    let output = common::render_init("nu")
+        .arg("--print-full-init")
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    assert!(actual.contains("$env.PROMPT_COMMAND = {||"));
+    assert!(actual.contains("\" prompt $\"--cmd-duration=($env.CMD_DURATION_MS)\""));
+    assert!(actual.contains("$\"--status=($env.LAST_EXIT_CODE)\""));
+    assert!(actual.contains("$env.STARSHIP_SHELL = \"nu\""));
+    assert!(!actual.contains("::STARSHIP::"));
+    Ok(())
+}
+
+#[test]
+fn elvish_stub() -> io::Result<()> {
+    let output = common::render_init("elvish").output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    assert!(actual.starts_with("eval (\""));
+    assert!(actual.ends_with("\" init elvish --print-full-init | slurp)"));
+    Ok(())
+}
+
+#[test]
+fn elvish_full_init() -> io::Result<()> {
+    let output = common::render_init("elvish")
+        .arg("--print-full-init")
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    assert!(actual.contains("set edit:prompt = {"));
+    assert!(actual.contains(
+        "\" prompt --jobs=$num-bg-jobs --cmd-duration=$cmd-duration --status=$starship-status"
+    ));
+    assert!(actual.contains("set edit:after-command = "));
+    assert!(actual.contains("set-env STARSHIP_SHELL \"elvish\""));
+    assert!(!actual.contains("::STARSHIP::"));
+    Ok(())
+}
+
+#[test]
+fn ion_stub() -> io::Result<()> {
+    let output = common::render_init("ion").output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    assert!(actual.starts_with("eval $(\""));
+    assert!(actual.ends_with("\" init ion --print-full-init)"));
+    Ok(())
+}
+
+#[test]
+fn ion_full_init() -> io::Result<()> {
+    let output = common::render_init("ion")
+        .arg("--print-full-init")
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    assert!(actual.contains("fn PROMPT\n"));
+    assert!(actual.contains("let STARSHIP_ION_STATUS = $?"));
+    assert!(actual.contains(
+        "\" prompt --status $STARSHIP_ION_STATUS --jobs $(jobs ^| wc -l) --cmd-duration $STARSHIP_ION_CMD_DURATION"
+    ));
+    assert!(actual.contains("export STARSHIP_SHELL=\"ion\""));
+    assert!(!actual.contains("::STARSHIP::"));
+    Ok(())
+}
+
+#[test]
+fn tcsh_stub() -> io::Result<()> {
+    let output = common::render_init("tcsh").output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    assert!(actual.starts_with("eval `(\""));
+    assert!(actual.ends_with("\" init tcsh --print-full-init)`"));
+    Ok(())
+}
+
+#[test]
+fn tcsh_full_init() -> io::Result<()> {
+    let output = common::render_init("tcsh")
+        .arg("--print-full-init")
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    assert!(actual.contains("set prompt = \"`$STARSHIP prompt --status=$STARSHIP_CMD_STATUS`\""));
+    assert!(actual.contains("alias precmd 'set STARSHIP_CMD_STATUS = $status;"));
+    assert!(actual.contains("setenv STARSHIP_SHELL tcsh;"));
+    assert!(!actual.contains("::STARSHIP::"));
+    // Every line has to be a complete command, since tcsh evaluates them as one
+    assert!(actual.lines().all(|line| line.ends_with(';')));
+    Ok(())
+}
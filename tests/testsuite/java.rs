@@ -0,0 +1,51 @@
+use ansi_term::Color;
+use std::fs::{self, File};
+use std::io;
+
+use crate::common;
+
+#[test]
+fn version_from_java_home_release_file() -> io::Result<()> {
+    let java_home = tempfile::tempdir()?;
+    fs::write(
+        java_home.path().join("release"),
+        "IMPLEMENTOR=\"Eclipse Adoptium\"\nJAVA_VERSION=\"17.0.1\"\n",
+    )?;
+    let project = tempfile::tempdir()?;
+    File::create(project.path().join("pom.xml"))?.sync_all()?;
+
+    let output = common::render_module("java")
+        .env("JAVA_HOME", java_home.path())
+        .arg("--path")
+        .arg(project.path())
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = format!("via {} ", Color::Red.dimmed().paint("☕ v17.0.1"));
+    assert_eq!(expected, actual);
+    project.close()?;
+    java_home.close()
+}
+
+#[test]
+fn legacy_version_from_java_home_release_file() -> io::Result<()> {
+    let java_home = tempfile::tempdir()?;
+    fs::write(
+        java_home.path().join("release"),
+        "JAVA_VERSION=\"1.8.0_292\"\nOS_NAME=\"Linux\"\n",
+    )?;
+    let project = tempfile::tempdir()?;
+    File::create(project.path().join("pom.xml"))?.sync_all()?;
+
+    let output = common::render_module("java")
+        .env("JAVA_HOME", java_home.path())
+        .arg("--path")
+        .arg(project.path())
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = format!("via {} ", Color::Red.dimmed().paint("☕ v1.8.0"));
+    assert_eq!(expected, actual);
+    project.close()?;
+    java_home.close()
+}
@@ -13,6 +13,32 @@ fn config_blank_job_0() -> io::Result<()> {
     Ok(())
 }
 
+#[test]
+fn config_0_symbol_threshold_job_0() -> io::Result<()> {
+    let output = common::render_module("jobs")
+        .use_config(toml::toml! {
+            [jobs]
+            symbol_threshold = 0
+        })
+        .arg("--jobs=0")
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = format!("{} ", Color::Blue.bold().paint("✦"));
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
+#[test]
+fn config_blank_no_jobs() -> io::Result<()> {
+    let output = common::render_module("jobs").output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = "";
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
 #[test]
 fn config_blank_job_1() -> io::Result<()> {
     let output = common::render_module("jobs").arg("--jobs=1").output()?;
@@ -33,6 +59,32 @@ fn config_blank_job_2() -> io::Result<()> {
     Ok(())
 }
 
+#[test]
+fn config_blank_job_3() -> io::Result<()> {
+    let output = common::render_module("jobs").arg("--jobs=3").output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = format!("{} ", Color::Blue.bold().paint("✦3"));
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
+#[test]
+fn config_3_symbol_threshold_job_2() -> io::Result<()> {
+    let output = common::render_module("jobs")
+        .use_config(toml::toml! {
+            [jobs]
+            symbol_threshold = 3
+        })
+        .arg("--jobs=2")
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = "";
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
 #[test]
 fn config_2_job_2() -> io::Result<()> {
     let output = common::render_module("jobs")
@@ -0,0 +1,133 @@
+use std::fs::File;
+use std::io::{self, Write};
+
+use ansi_term::Color;
+
+use crate::common::{self, TestCommand};
+
+const KUBECONFIG: &[u8] = b"
+apiVersion: v1
+clusters: []
+contexts:
+- context:
+    cluster: arn:aws:eks:us-east-1:123456789012:cluster/production
+    user: arn:aws:eks:us-east-1:123456789012:cluster/production
+    namespace: astronauts
+  name: arn:aws:eks:us-east-1:123456789012:cluster/production
+- context:
+    cluster: dev_cluster
+    user: dev_user
+  name: dev_context
+current-context: arn:aws:eks:us-east-1:123456789012:cluster/production
+kind: Config
+preferences: {}
+users: []
+";
+
+#[test]
+fn disabled_by_default() -> io::Result<()> {
+    let dir = tempfile::tempdir()?;
+    let kubeconfig_path = dir.path().join("config");
+    File::create(&kubeconfig_path)?.write_all(KUBECONFIG)?;
+
+    let output = common::render_module("kubernetes")
+        .env("KUBECONFIG", kubeconfig_path.to_string_lossy().as_ref())
+        .output()?;
+    let expected = "";
+    let actual = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(expected, actual);
+    dir.close()
+}
+
+#[test]
+fn context_and_namespace_set() -> io::Result<()> {
+    let dir = tempfile::tempdir()?;
+    let kubeconfig_path = dir.path().join("config");
+    File::create(&kubeconfig_path)?.write_all(KUBECONFIG)?;
+
+    let output = common::render_module("kubernetes")
+        .env("KUBECONFIG", kubeconfig_path.to_string_lossy().as_ref())
+        .use_config(toml::toml! {
+            [kubernetes]
+            disabled = false
+        })
+        .output()?;
+    let expected = format!(
+        "on {} ",
+        Color::Cyan
+            .bold()
+            .paint("☸ arn:aws:eks:us-east-1:123456789012:cluster/production (astronauts)")
+    );
+    let actual = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(expected, actual);
+    dir.close()
+}
+
+#[test]
+fn context_alias_set() -> io::Result<()> {
+    let dir = tempfile::tempdir()?;
+    let kubeconfig_path = dir.path().join("config");
+    File::create(&kubeconfig_path)?.write_all(KUBECONFIG)?;
+
+    let output = common::render_module("kubernetes")
+        .env("KUBECONFIG", kubeconfig_path.to_string_lossy().as_ref())
+        .use_config(toml::toml! {
+            [kubernetes]
+            disabled = false
+            [kubernetes.context_aliases]
+            "arn:aws:eks:us-east-1:123456789012:cluster/production" = "prod"
+        })
+        .output()?;
+    let expected = format!("on {} ", Color::Cyan.bold().paint("☸ prod (astronauts)"));
+    let actual = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(expected, actual);
+    dir.close()
+}
+
+#[test]
+fn context_alias_not_matching() -> io::Result<()> {
+    let dir = tempfile::tempdir()?;
+    let kubeconfig_path = dir.path().join("config");
+    File::create(&kubeconfig_path)?.write_all(KUBECONFIG)?;
+
+    let output = common::render_module("kubernetes")
+        .env("KUBECONFIG", kubeconfig_path.to_string_lossy().as_ref())
+        .use_config(toml::toml! {
+            [kubernetes]
+            disabled = false
+            format = "on [$symbol$context]($style) "
+            [kubernetes.context_aliases]
+            dev_context = "dev"
+        })
+        .output()?;
+    let expected = format!(
+        "on {} ",
+        Color::Cyan
+            .bold()
+            .paint("☸ arn:aws:eks:us-east-1:123456789012:cluster/production")
+    );
+    let actual = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(expected, actual);
+    dir.close()
+}
+
+#[test]
+fn context_truncated_without_alias() -> io::Result<()> {
+    let dir = tempfile::tempdir()?;
+    let kubeconfig_path = dir.path().join("config");
+    File::create(&kubeconfig_path)?.write_all(KUBECONFIG)?;
+
+    let output = common::render_module("kubernetes")
+        .env("KUBECONFIG", kubeconfig_path.to_string_lossy().as_ref())
+        .use_config(toml::toml! {
+            [kubernetes]
+            disabled = false
+            format = "on [$symbol$context]($style) "
+            truncation_length = 11
+        })
+        .output()?;
+    let expected = format!("on {} ", Color::Cyan.bold().paint("☸ arn:aws:eks…"));
+    let actual = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(expected, actual);
+    dir.close()
+}
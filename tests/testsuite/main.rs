@@ -5,20 +5,30 @@ mod common;
 mod conda;
 mod configuration;
 mod directory;
+mod docker_context;
 mod dotnet;
 mod env_var;
 mod gcloud;
 mod git_branch;
 mod git_commit;
+mod git_metrics;
 mod git_state;
 mod git_status;
 mod hg_branch;
 mod hostname;
+mod init;
+mod java;
 mod jobs;
+mod kubernetes;
 mod modules;
 mod nix_shell;
+mod openstack;
+mod prompt;
+mod pulumi;
 mod python;
+mod shlvl;
 mod singularity;
+mod status;
 mod terraform;
 mod time;
 mod username;
@@ -1,6 +1,6 @@
 use std::io;
 
-use crate::common;
+use crate::common::{self, TestCommand};
 
 #[test]
 fn unknown_module_name() -> io::Result<()> {
@@ -29,3 +29,43 @@ fn known_module_name() -> io::Result<()> {
     assert_eq!(expected_stderr, actual_stderr);
     Ok(())
 }
+
+#[test]
+fn disabled_module_name() -> io::Result<()> {
+    let output = common::render_module("line_break")
+        .use_config(toml::toml! {
+            [line_break]
+            disabled = true
+        })
+        .output()?;
+    let actual_stdout = String::from_utf8(output.stdout).unwrap();
+    let actual_stderr = String::from_utf8(output.stderr).unwrap();
+    assert_eq!("", actual_stdout);
+    assert_eq!("", actual_stderr);
+    Ok(())
+}
+
+#[test]
+fn no_color_strips_styling() -> io::Result<()> {
+    let dir = tempfile::tempdir()?;
+    std::fs::write(dir.path().join(".perl-version"), "5.32.0\n")?;
+
+    let output = common::render_module("perl")
+        .arg("--path")
+        .arg(dir.path())
+        .env("NO_COLOR", "1")
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    assert!(!actual.contains('\x1b'));
+    assert_eq!("via 🐪 v5.32.0 ", actual);
+
+    // An empty `NO_COLOR` doesn't disable styling
+    let output = common::render_module("perl")
+        .arg("--path")
+        .arg(dir.path())
+        .env("NO_COLOR", "")
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    assert!(actual.contains('\x1b'));
+    dir.close()
+}
@@ -1,7 +1,7 @@
 use ansi_term::Color;
 use std::io;
 
-use crate::common;
+use crate::common::{self, TestCommand};
 
 #[test]
 fn no_env_variables() -> io::Result<()> {
@@ -70,3 +70,47 @@ fn impure_shell_name() -> io::Result<()> {
     assert_eq!(expected, actual);
     Ok(())
 }
+
+#[test]
+fn legacy_impure_shell() -> io::Result<()> {
+    let output = common::render_module("nix_shell")
+        .env("IN_NIX_SHELL", "1")
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = format!("via {} ", Color::Blue.bold().paint("❄️  impure"));
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
+#[test]
+fn pure_shell_empty_name() -> io::Result<()> {
+    let output = common::render_module("nix_shell")
+        .env("IN_NIX_SHELL", "pure")
+        .env("name", "")
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = format!("via {} ", Color::Blue.bold().paint("❄️  pure"));
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
+#[test]
+fn pure_shell_custom_msg() -> io::Result<()> {
+    let output = common::render_module("nix_shell")
+        .env("IN_NIX_SHELL", "pure")
+        .use_config(toml::toml! {
+            [nix_shell]
+            format = "via [$symbol$state]($style) "
+            symbol = ""
+            pure_msg = "🧊"
+            impure_msg = "🌊"
+        })
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = format!("via {} ", Color::Blue.bold().paint("🧊"));
+    assert_eq!(expected, actual);
+    Ok(())
+}
@@ -0,0 +1,92 @@
+use ansi_term::Color;
+use std::fs;
+use std::io;
+
+use crate::common;
+
+fn clouds_yaml() -> io::Result<tempfile::TempDir> {
+    let dir = tempfile::tempdir()?;
+    fs::write(
+        dir.path().join("clouds.yaml"),
+        "\
+clouds:
+  corp:
+    auth:
+      auth_url: https://identity.example.com
+      project_name: testproject
+      username: starship
+    region_name: RegionOne
+  lab:
+    auth:
+      auth_url: https://lab.example.com
+",
+    )?;
+    Ok(dir)
+}
+
+#[test]
+fn no_cloud_set() -> io::Result<()> {
+    let output = common::render_module("openstack")
+        .env_remove("OS_CLOUD")
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    assert_eq!("", actual);
+    Ok(())
+}
+
+#[test]
+fn cloud_with_project_from_clouds_yaml() -> io::Result<()> {
+    let config_dir = clouds_yaml()?;
+    let output = common::render_module("openstack")
+        .env("OS_CLOUD", "corp")
+        .env(
+            "OS_CLIENT_CONFIG_FILE",
+            config_dir.path().join("clouds.yaml"),
+        )
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    let expected = format!(
+        "on {} ",
+        Color::Yellow.bold().paint("☁️  corp(testproject)")
+    );
+
+    assert_eq!(expected, actual);
+    config_dir.close()
+}
+
+#[test]
+fn cloud_with_clouds_yaml_in_current_dir() -> io::Result<()> {
+    let project_dir = clouds_yaml()?;
+    let output = common::render_module("openstack")
+        .env("OS_CLOUD", "corp")
+        .env_remove("OS_CLIENT_CONFIG_FILE")
+        .arg("--path")
+        .arg(project_dir.path())
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    let expected = format!(
+        "on {} ",
+        Color::Yellow.bold().paint("☁️  corp(testproject)")
+    );
+
+    assert_eq!(expected, actual);
+    project_dir.close()
+}
+
+#[test]
+fn cloud_without_project() -> io::Result<()> {
+    let config_dir = clouds_yaml()?;
+    let output = common::render_module("openstack")
+        .env("OS_CLOUD", "lab")
+        .env(
+            "OS_CLIENT_CONFIG_FILE",
+            config_dir.path().join("clouds.yaml"),
+        )
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    let expected = format!("on {} ", Color::Yellow.bold().paint("☁️  lab"));
+
+    assert_eq!(expected, actual);
+    config_dir.close()
+}
@@ -0,0 +1,243 @@
+use ansi_term::Color;
+use std::fs::File;
+use std::io::{self, Write};
+
+use crate::common::{self, TestCommand};
+
+#[test]
+fn right_prompt_is_empty_by_default() -> io::Result<()> {
+    let output = common::render_prompt().arg("--right").output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    assert_eq!("", actual);
+    Ok(())
+}
+
+#[test]
+fn left_and_right_formats_are_independent() -> io::Result<()> {
+    let config = toml::toml! {
+        format = "[left](red)"
+        add_newline = false
+        right_format = "[right](blue)"
+    };
+
+    let output = common::render_prompt()
+        .use_config(config.clone())
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(Color::Red.paint("left").to_string(), actual);
+
+    let output = common::render_prompt()
+        .arg("--right")
+        .use_config(config)
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(Color::Blue.paint("right").to_string(), actual);
+    Ok(())
+}
+
+#[test]
+fn right_prompt_renders_only_its_modules() -> io::Result<()> {
+    let config = toml::toml! {
+        format = "$jobs"
+        add_newline = false
+        right_format = "$cmd_duration"
+    };
+
+    let output = common::render_prompt()
+        .args(["--jobs", "2", "--cmd-duration", "5000"])
+        .use_config(config.clone())
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    let expected = format!("{} ", Color::Blue.bold().paint("✦2"));
+    assert_eq!(expected, actual);
+
+    let output = common::render_prompt()
+        .args(["--right", "--jobs", "2", "--cmd-duration", "5000"])
+        .use_config(config)
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    let expected = format!("took {} ", Color::Yellow.bold().paint("5s"));
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
+#[test]
+fn default_continuation_prompt() -> io::Result<()> {
+    let output = common::render_prompt().arg("--continuation").output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    let expected = format!("{} ", Color::Fixed(8).paint("∙"));
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
+#[test]
+fn configured_continuation_prompt() -> io::Result<()> {
+    let output = common::render_prompt()
+        .arg("--continuation")
+        .use_config(toml::toml! {
+            format = "$jobs"
+            continuation_prompt = "[▶▶](green) "
+        })
+        .args(["--jobs", "2"])
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    let expected = format!("{} ", Color::Green.paint("▶▶"));
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
+#[test]
+fn add_newline_prepends_a_new_line() -> io::Result<()> {
+    let output = common::render_prompt()
+        .use_config(toml::toml! {
+            format = "[left](red)"
+            add_newline = true
+        })
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(format!("\n{}", Color::Red.paint("left")), actual);
+
+    // A custom format without `add_newline` keeps the prompt it had before the option existed
+    let output = common::render_prompt()
+        .use_config(toml::toml! {
+            format = "[left](red)"
+        })
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(Color::Red.paint("left").to_string(), actual);
+
+    let output = common::render_prompt()
+        .use_config(toml::toml! {
+            format = "[left](red)"
+            add_newline = false
+        })
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(Color::Red.paint("left").to_string(), actual);
+
+    // A format that starts with a new line doesn't get a second one
+    let output = common::render_prompt()
+        .use_config(toml::toml! {
+            format = "\n[left](red)"
+        })
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(format!("\n{}", Color::Red.paint("left")), actual);
+
+    // The right prompt never starts with a new line
+    let output = common::render_prompt()
+        .arg("--right")
+        .use_config(toml::toml! {
+            right_format = "[right](blue)"
+        })
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(Color::Blue.paint("right").to_string(), actual);
+    Ok(())
+}
+
+#[test]
+fn line_break_splits_the_prompt() -> io::Result<()> {
+    let output = common::render_prompt()
+        .use_config(toml::toml! {
+            format = "top$line_break$character"
+            add_newline = false
+        })
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    let expected = format!("top\n{} ", Color::Green.bold().paint("❯"));
+    assert_eq!(expected, actual);
+
+    let output = common::render_prompt()
+        .use_config(toml::toml! {
+            format = "top$line_break$character"
+            add_newline = false
+
+            [line_break]
+            disabled = true
+        })
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    let expected = format!("top{} ", Color::Green.bold().paint("❯"));
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
+#[test]
+fn explicit_module_order_is_honored() -> io::Result<()> {
+    let output = common::render_prompt()
+        .args(["--jobs", "2", "--cmd-duration", "5000"])
+        .use_config(toml::toml! {
+            format = "$cmd_duration$jobs"
+            add_newline = false
+        })
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    let expected = format!(
+        "took {} {} ",
+        Color::Yellow.bold().paint("5s"),
+        Color::Blue.bold().paint("✦2")
+    );
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
+#[test]
+fn all_skips_explicitly_placed_modules() -> io::Result<()> {
+    let dir = tempfile::tempdir()?;
+    let config = toml::toml! {
+        format = "$character$jobs$all$line_break"
+        add_newline = false
+        right_format = "$cmd_duration"
+    };
+
+    let output = common::render_prompt()
+        .arg("--path")
+        .arg(dir.path())
+        .args(["--jobs", "2", "--cmd-duration", "5000"])
+        .use_config(config)
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let character = format!("{} ", Color::Green.bold().paint("❯"));
+    let jobs = format!("{} ", Color::Blue.bold().paint("✦2"));
+    assert!(actual.starts_with(&format!("{}{}", character, jobs)));
+    assert!(actual.ends_with('\n'));
+    assert_eq!(actual.matches(&character).count(), 1);
+    assert_eq!(actual.matches(&jobs).count(), 1);
+    assert_eq!(actual.matches('\n').count(), 1);
+    // `$all` still renders the modules that aren't placed explicitly
+    assert!(actual.contains(&dir_name(&dir)));
+    // and leaves out those placed in the right prompt
+    assert!(!actual.contains("took"));
+    dir.close()
+}
+
+fn dir_name(dir: &tempfile::TempDir) -> String {
+    dir.path()
+        .file_name()
+        .unwrap()
+        .to_string_lossy()
+        .into_owned()
+}
+
+#[test]
+fn config_flag_overrides_starship_config() -> io::Result<()> {
+    let dir = tempfile::tempdir()?;
+    let config_path = dir.path().join("profile.toml");
+    let mut file = File::create(&config_path)?;
+    file.write_all(b"format = \"[profile](red)\"\nadd_newline = false\n")?;
+    file.sync_all()?;
+
+    let output = common::render_prompt()
+        .arg("--config")
+        .arg(&config_path)
+        .use_config(toml::toml! {
+            format = "[env](blue)"
+            add_newline = false
+        })
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(Color::Red.paint("profile").to_string(), actual);
+    dir.close()
+}
@@ -0,0 +1,113 @@
+use ansi_term::Color;
+use sha1::{Digest, Sha1};
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::common;
+
+fn pulumi_project(name: &str) -> io::Result<tempfile::TempDir> {
+    let dir = tempfile::tempdir()?;
+    fs::write(
+        dir.path().join("Pulumi.yaml"),
+        format!("name: {}\nruntime: nodejs\n", name),
+    )?;
+    Ok(dir)
+}
+
+fn pulumi_home(
+    project_dir: &Path,
+    project_name: &str,
+    stack: &str,
+) -> io::Result<tempfile::TempDir> {
+    let dir = tempfile::tempdir()?;
+    let workspaces = dir.path().join("workspaces");
+    fs::create_dir_all(&workspaces)?;
+
+    let project_file = project_dir.join("Pulumi.yaml");
+    let path_hash = Sha1::digest(project_file.to_str().unwrap().as_bytes());
+    fs::write(
+        workspaces.join(format!("{}-{:x}-workspace.json", project_name, path_hash)),
+        format!(r#"{{ "stack": "{}" }}"#, stack),
+    )?;
+    Ok(dir)
+}
+
+#[test]
+fn folder_without_pulumi_project() -> io::Result<()> {
+    let project_dir = tempfile::tempdir()?;
+    let home_dir = pulumi_home(project_dir.path(), "starship", "dev")?;
+
+    let output = common::render_module("pulumi")
+        .env("PULUMI_HOME", home_dir.path())
+        .arg("--path")
+        .arg(project_dir.path())
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    assert_eq!("", actual);
+
+    home_dir.close()?;
+    project_dir.close()
+}
+
+#[test]
+fn project_without_selected_stack() -> io::Result<()> {
+    let project_dir = pulumi_project("starship")?;
+    let home_dir = tempfile::tempdir()?;
+
+    let output = common::render_module("pulumi")
+        .env("PULUMI_HOME", home_dir.path())
+        .arg("--path")
+        .arg(project_dir.path())
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    assert_eq!("", actual);
+
+    home_dir.close()?;
+    project_dir.close()
+}
+
+#[test]
+fn stack_from_workspace() -> io::Result<()> {
+    let project_dir = pulumi_project("starship")?;
+    let home_dir = pulumi_home(project_dir.path(), "starship", "dev")?;
+
+    let output = common::render_module("pulumi")
+        .env("PULUMI_HOME", home_dir.path())
+        .arg("--path")
+        .arg(project_dir.path())
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    let expected = format!("via {} ", Color::Fixed(5).bold().paint("🧊 dev"));
+    assert_eq!(expected, actual);
+
+    home_dir.close()?;
+    project_dir.close()
+}
+
+#[test]
+fn stack_with_username() -> io::Result<()> {
+    let project_dir = pulumi_project("starship")?;
+    let home_dir = pulumi_home(project_dir.path(), "starship", "dev")?;
+    fs::write(
+        home_dir.path().join("credentials.json"),
+        r#"{
+            "current": "https://api.pulumi.com",
+            "accounts": {
+                "https://api.pulumi.com": { "accessToken": "secret", "username": "astronaut" }
+            }
+        }"#,
+    )?;
+
+    let output = common::render_module("pulumi")
+        .env("PULUMI_HOME", home_dir.path())
+        .arg("--path")
+        .arg(project_dir.path())
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    let expected = format!("via {} ", Color::Fixed(5).bold().paint("🧊 astronaut@dev"));
+    assert_eq!(expected, actual);
+
+    home_dir.close()?;
+    project_dir.close()
+}
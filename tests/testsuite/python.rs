@@ -1,7 +1,8 @@
+use ansi_term::Color;
 use std::fs::File;
 use std::io;
 
-use crate::common;
+use crate::common::{self, TestCommand};
 
 // TODO - These tests should be moved into the python module when we have sorted out mocking of env
 // vars.
@@ -35,3 +36,53 @@ fn with_active_venv() -> io::Result<()> {
     assert!(actual.contains("my_venv"));
     dir.close()
 }
+
+#[test]
+fn with_virtual_env_shows_venv_name() -> io::Result<()> {
+    let dir = tempfile::tempdir()?;
+    File::create(dir.path().join("main.py"))?.sync_all()?;
+    let output = common::render_module("python")
+        .env("VIRTUAL_ENV", "/home/me/.venvs/myproj")
+        .arg("--path")
+        .arg(dir.path())
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    assert!(actual.contains("(myproj)"));
+    assert!(!actual.contains(".venvs"));
+    dir.close()
+}
+
+#[test]
+fn with_virtual_env_trailing_slash() -> io::Result<()> {
+    let dir = tempfile::tempdir()?;
+    File::create(dir.path().join("main.py"))?.sync_all()?;
+    let output = common::render_module("python")
+        .env("VIRTUAL_ENV", "/home/me/.venvs/myproj/")
+        .arg("--path")
+        .arg(dir.path())
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    assert!(actual.contains("(myproj)"));
+    dir.close()
+}
+
+#[test]
+fn with_virtual_env_custom_format() -> io::Result<()> {
+    let dir = tempfile::tempdir()?;
+    let output = common::render_module("python")
+        .env("VIRTUAL_ENV", "/home/me/.venvs/myproj")
+        .use_config(toml::toml! {
+            [python]
+            format = "[$virtualenv]($style)"
+        })
+        .arg("--path")
+        .arg(dir.path())
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = format!("{}", Color::Yellow.bold().paint("myproj"));
+    assert_eq!(expected, actual);
+    dir.close()
+}
@@ -0,0 +1,158 @@
+use ansi_term::{Color, Style};
+use std::io;
+
+use crate::common::{self, TestCommand};
+
+const SHLVL_ENV_VAR: &str = "SHLVL";
+
+fn style() -> Style {
+    // default style
+    Color::Yellow.bold()
+}
+
+#[test]
+fn empty_config() -> io::Result<()> {
+    let output = common::render_module("shlvl")
+        .env(SHLVL_ENV_VAR, "3")
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = "";
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
+#[test]
+fn enabled() -> io::Result<()> {
+    let output = common::render_module("shlvl")
+        .env(SHLVL_ENV_VAR, "3")
+        .use_config(toml::toml! {
+            [shlvl]
+            disabled = false
+        })
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = format!("{} ", style().paint("↕️ 3"));
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
+#[test]
+fn below_threshold() -> io::Result<()> {
+    let output = common::render_module("shlvl")
+        .env(SHLVL_ENV_VAR, "1")
+        .use_config(toml::toml! {
+            [shlvl]
+            disabled = false
+        })
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = "";
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
+#[test]
+fn at_threshold() -> io::Result<()> {
+    let output = common::render_module("shlvl")
+        .env(SHLVL_ENV_VAR, "2")
+        .use_config(toml::toml! {
+            [shlvl]
+            disabled = false
+        })
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = "";
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
+#[test]
+fn no_level() -> io::Result<()> {
+    let output = common::render_module("shlvl")
+        .use_config(toml::toml! {
+            [shlvl]
+            disabled = false
+        })
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = "";
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
+#[test]
+fn invalid_level() -> io::Result<()> {
+    let output = common::render_module("shlvl")
+        .env(SHLVL_ENV_VAR, "invalid")
+        .use_config(toml::toml! {
+            [shlvl]
+            disabled = false
+        })
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = "";
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
+#[test]
+fn custom_threshold() -> io::Result<()> {
+    let output = common::render_module("shlvl")
+        .env(SHLVL_ENV_VAR, "3")
+        .use_config(toml::toml! {
+            [shlvl]
+            threshold = 4
+            disabled = false
+        })
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = "";
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
+#[test]
+fn repeat() -> io::Result<()> {
+    let output = common::render_module("shlvl")
+        .env(SHLVL_ENV_VAR, "3")
+        .use_config(toml::toml! {
+            [shlvl]
+            format = "[$symbol]($style)"
+            symbol = "~"
+            repeat = true
+            disabled = false
+        })
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = format!("{}", style().paint("~~~"));
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
+#[test]
+fn repeat_up_to_limit() -> io::Result<()> {
+    let output = common::render_module("shlvl")
+        .env(SHLVL_ENV_VAR, "6")
+        .use_config(toml::toml! {
+            [shlvl]
+            format = "[$symbol$shlvl]($style)"
+            symbol = "~"
+            repeat = true
+            repeat_limit = 4
+            disabled = false
+        })
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = format!("{}", style().paint("~~~~6"));
+    assert_eq!(expected, actual);
+    Ok(())
+}
@@ -1,17 +1,32 @@
 use ansi_term::Color;
 use std::io;
 
-use crate::common;
+use crate::common::{self, TestCommand};
 
 #[test]
 fn no_env_set() -> io::Result<()> {
-    let output = common::render_module("singularity").output()?;
+    let output = common::render_module("singularity")
+        .env_remove("SINGULARITY_NAME")
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = "";
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
+#[test]
+fn empty_env_set() -> io::Result<()> {
+    let output = common::render_module("singularity")
+        .env("SINGULARITY_NAME", "")
+        .output()?;
     let actual = String::from_utf8(output.stdout).unwrap();
 
     let expected = "";
     assert_eq!(expected, actual);
     Ok(())
 }
+
 #[test]
 fn env_set() -> io::Result<()> {
     let output = common::render_module("singularity")
@@ -25,3 +40,22 @@ fn env_set() -> io::Result<()> {
     assert_eq!(expected, actual);
     Ok(())
 }
+
+#[test]
+fn env_set_with_custom_format() -> io::Result<()> {
+    let output = common::render_module("singularity")
+        .env("SINGULARITY_NAME", "centos.img")
+        .use_config(toml::toml! {
+            [singularity]
+            format = "in [$symbol$env]($style) "
+            symbol = "📦 "
+            style = "green"
+        })
+        .output()?;
+
+    let expected = format!("in {} ", Color::Green.paint("📦 centos.img"));
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    assert_eq!(expected, actual);
+    Ok(())
+}
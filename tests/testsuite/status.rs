@@ -0,0 +1,104 @@
+use ansi_term::Color;
+use std::io;
+
+use crate::common::{self, TestCommand};
+
+#[test]
+fn disabled_by_default() -> io::Result<()> {
+    let output = common::render_module("status").arg("--status=1").output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = "";
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
+#[test]
+fn success_status() -> io::Result<()> {
+    let output = common::render_module("status")
+        .use_config(toml::toml! {
+            [status]
+            disabled = false
+        })
+        .arg("--status=0")
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = "";
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
+#[test]
+fn success_status_always() -> io::Result<()> {
+    let output = common::render_module("status")
+        .use_config(toml::toml! {
+            [status]
+            disabled = false
+            always = true
+            success_symbol = "✔"
+            style = "bold green"
+        })
+        .arg("--status=0")
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = format!("{} ", Color::Green.bold().paint("✔0"));
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
+#[test]
+fn failure_status() -> io::Result<()> {
+    let exit_values = ["1", "2", "130", "-5000"];
+
+    for status in exit_values.iter() {
+        let output = common::render_module("status")
+            .use_config(toml::toml! {
+                [status]
+                disabled = false
+            })
+            .arg(format!("--status={}", status))
+            .output()?;
+        let actual = String::from_utf8(output.stdout).unwrap();
+
+        let expected = format!("{} ", Color::Red.bold().paint(format!("✖{}", status)));
+        assert_eq!(expected, actual);
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(not(target_os = "windows"))]
+fn failure_status_map_symbol() -> io::Result<()> {
+    let output = common::render_module("status")
+        .use_config(toml::toml! {
+            [status]
+            disabled = false
+            map_symbol = true
+        })
+        .arg("--status=137")
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = format!("{} ", Color::Red.bold().paint("✖SIGKILL"));
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
+#[test]
+fn failure_status_map_symbol_without_signal() -> io::Result<()> {
+    let output = common::render_module("status")
+        .use_config(toml::toml! {
+            [status]
+            disabled = false
+            map_symbol = true
+        })
+        .arg("--status=1")
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = format!("{} ", Color::Red.bold().paint("✖1"));
+    assert_eq!(expected, actual);
+    Ok(())
+}
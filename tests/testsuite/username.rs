@@ -3,9 +3,6 @@ use std::io;
 
 use crate::common::{self, TestCommand};
 
-// TODO: Add tests for if root user (UID == 0)
-// Requires mocking
-
 #[test]
 fn no_env_variables() -> io::Result<()> {
     let output = common::render_module("username").output()?;
@@ -62,6 +59,51 @@ fn ssh_connection() -> io::Result<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(not(target_os = "windows"))]
+fn root_user_style() -> io::Result<()> {
+    // The test may or may not be run as root, so the expected style follows the current user
+    let is_root = nix::unistd::Uid::effective().is_root();
+    let output = common::render_module("username")
+        .env("LOGNAME", "astronaut")
+        .env("USER", "astronaut")
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = if is_root {
+        format!("via {} ", Color::Red.bold().paint("astronaut"))
+    } else {
+        String::new()
+    };
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
+#[test]
+#[cfg(not(target_os = "windows"))]
+fn root_user_custom_style() -> io::Result<()> {
+    let is_root = nix::unistd::Uid::effective().is_root();
+    let output = common::render_module("username")
+        .env("USER", "astronaut")
+        .use_config(toml::toml! {
+            [username]
+            show_always = true
+            style_root = "bold purple"
+            style_user = "bold blue"
+        })
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let style = if is_root {
+        Color::Purple.bold()
+    } else {
+        Color::Blue.bold()
+    };
+    let expected = format!("via {} ", style.paint("astronaut"));
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
 #[test]
 fn show_always() -> io::Result<()> {
     let output = common::render_module("username")